@@ -0,0 +1,271 @@
+//! `#[derive(PuzzleInput)]`, so a day can declare what its input looks like as a struct and get
+//! the parsing, and the error messages, from `aoc_solvers::parse`.
+//!
+//! A plain struct is a whole input, one blank-line separated section per field, in order:
+//!
+//! ```ignore
+//! #[derive(PuzzleInput)]
+//! struct Manual {
+//!     rules: Vec<Rule>,         // any `FromSection`: one value per line, a `Grid<char>`...
+//!     #[puzzle(list = ",")]
+//!     updates: Vec<Vec<i32>>,   // each line split on `,`
+//! }
+//! ```
+//!
+//! `#[puzzle(repeat)]` on a `Vec` field takes as many sections as are left over once the fields
+//! after it have one each, e.g. every shape before the regions.
+//!
+//! With `#[puzzle(line = "...")]` the struct is a single line instead, matched against the
+//! pattern: each `{field}` is parsed with `FromStr` (or split into a `Vec` with
+//! `#[puzzle(list = " ")]`) and everything else has to be there as written. That gives the
+//! struct a `FromStr` impl, so a `Vec` of it is a section of those lines.
+//!
+//! ```ignore
+//! #[derive(PuzzleInput)]
+//! #[puzzle(line = "{before}|{after}")]
+//! struct Rule {
+//!     before: i32,
+//!     after: i32,
+//! }
+//! ```
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::{format_ident, quote};
+use syn::{Data, DeriveInput, Fields, Ident, LitStr, parse_macro_input};
+
+#[proc_macro_derive(PuzzleInput, attributes(puzzle))]
+pub fn derive_puzzle_input(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand(&input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// What `#[puzzle(...)]` said about a struct or one of its fields.
+#[derive(Default)]
+struct Options {
+    line: Option<LitStr>,
+    list: Option<LitStr>,
+    repeat: bool,
+}
+
+fn options(attrs: &[syn::Attribute]) -> syn::Result<Options> {
+    let mut options = Options::default();
+    for attr in attrs.iter().filter(|attr| attr.path().is_ident("puzzle")) {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("line") {
+                options.line = Some(meta.value()?.parse()?);
+            } else if meta.path.is_ident("list") {
+                options.list = Some(meta.value()?.parse()?);
+            } else if meta.path.is_ident("repeat") {
+                options.repeat = true;
+            } else {
+                return Err(meta.error("expected `line = \"...\"`, `list = \"...\"` or `repeat`"));
+            }
+            Ok(())
+        })?;
+    }
+    Ok(options)
+}
+
+struct Field<'a> {
+    name: &'a Ident,
+    options: Options,
+}
+
+fn expand(input: &DeriveInput) -> syn::Result<TokenStream2> {
+    let Data::Struct(data) = &input.data else {
+        return Err(syn::Error::new_spanned(
+            input,
+            "PuzzleInput only works on structs",
+        ));
+    };
+    let Fields::Named(named) = &data.fields else {
+        return Err(syn::Error::new_spanned(
+            input,
+            "PuzzleInput needs named fields",
+        ));
+    };
+    let fields = named
+        .named
+        .iter()
+        .map(|field| {
+            Ok(Field {
+                name: field.ident.as_ref().unwrap(),
+                options: options(&field.attrs)?,
+            })
+        })
+        .collect::<syn::Result<Vec<_>>>()?;
+
+    let options = options(&input.attrs)?;
+    match options.line {
+        Some(pattern) => expand_line(input, &fields, &pattern),
+        None => expand_sections(input, &fields),
+    }
+}
+
+/// `PuzzleInput`, taking each field's section in turn.
+fn expand_sections(input: &DeriveInput, fields: &[Field]) -> syn::Result<TokenStream2> {
+    if fields.iter().filter(|field| field.options.repeat).count() > 1 {
+        return Err(syn::Error::new_spanned(
+            input,
+            "only one field can be `repeat`",
+        ));
+    }
+    let mut steps = Vec::new();
+    for (i, field) in fields.iter().enumerate() {
+        let name = field.name;
+        let label = name.to_string();
+        let later = fields.len() - i - 1;
+        let value = match (&field.options.list, field.options.repeat) {
+            (Some(_), true) => {
+                return Err(syn::Error::new_spanned(
+                    name,
+                    "`repeat` and `list` can't be used together",
+                ));
+            }
+            (Some(sep), false) => quote! { sections.next_list(#label, #sep)? },
+            (None, true) => quote! { sections.repeat(#later)? },
+            (None, false) => quote! { sections.next(#label)? },
+        };
+        steps.push(quote! { let #name = #value; });
+    }
+
+    let ty = &input.ident;
+    let names = fields.iter().map(|field| field.name);
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    Ok(quote! {
+        impl #impl_generics ::aoc_solvers::parse::PuzzleInput for #ty #ty_generics #where_clause {
+            fn from_sections(
+                sections: &mut ::aoc_solvers::parse::SectionCursor<'_>,
+            ) -> ::core::result::Result<Self, ::aoc_solvers::parse::ParseError> {
+                #(#steps)*
+                ::core::result::Result::Ok(Self { #(#names),* })
+            }
+        }
+    })
+}
+
+/// A piece of a `line = "..."` pattern.
+enum Piece {
+    Literal(String),
+    Field(String),
+}
+
+fn pieces(pattern: &LitStr) -> syn::Result<Vec<Piece>> {
+    let text = pattern.value();
+    let mut pieces = Vec::new();
+    let mut rest = text.as_str();
+    while !rest.is_empty() {
+        match rest.find('{') {
+            Some(0) => {
+                let end = rest
+                    .find('}')
+                    .ok_or_else(|| syn::Error::new_spanned(pattern, "unclosed `{` in pattern"))?;
+                if matches!(pieces.last(), Some(Piece::Field(_))) {
+                    return Err(syn::Error::new_spanned(
+                        pattern,
+                        "fields in a pattern need some text between them",
+                    ));
+                }
+                pieces.push(Piece::Field(rest[1..end].trim().to_string()));
+                rest = &rest[end + 1..];
+            }
+            Some(start) => {
+                pieces.push(Piece::Literal(rest[..start].to_string()));
+                rest = &rest[start..];
+            }
+            None => {
+                pieces.push(Piece::Literal(rest.to_string()));
+                rest = "";
+            }
+        }
+    }
+    Ok(pieces)
+}
+
+/// `FromStr`, matching the line against the pattern.
+fn expand_line(
+    input: &DeriveInput,
+    fields: &[Field],
+    pattern: &LitStr,
+) -> syn::Result<TokenStream2> {
+    let mut pieces = pieces(pattern)?;
+    for field in fields {
+        let label = field.name.to_string();
+        if field.options.repeat {
+            return Err(syn::Error::new_spanned(
+                field.name,
+                "`repeat` is for sections, not fields of a line",
+            ));
+        }
+        let uses = pieces
+            .iter()
+            .filter(|piece| matches!(piece, Piece::Field(name) if *name == label))
+            .count();
+        if uses != 1 {
+            return Err(syn::Error::new_spanned(
+                pattern,
+                format!("the pattern must have `{{{label}}}` exactly once"),
+            ));
+        }
+    }
+    for piece in &pieces {
+        if let Piece::Field(name) = piece
+            && !fields.iter().any(|field| field.name == name)
+        {
+            return Err(syn::Error::new_spanned(
+                pattern,
+                format!("`{{{name}}}` isn't a field of this struct"),
+            ));
+        }
+    }
+
+    // Peel off the text at either end first, then each field runs up to the literal after it.
+    let mut steps = Vec::new();
+    if let Some(Piece::Literal(prefix)) = pieces.first() {
+        steps.push(quote! { let rest = ::aoc_solvers::parse::strip_literal(rest, #prefix)?; });
+        pieces.remove(0);
+    }
+    if let Some(Piece::Literal(suffix)) = pieces.last() {
+        steps.push(quote! { let rest = ::aoc_solvers::parse::strip_end(rest, #suffix)?; });
+        pieces.pop();
+    }
+    let mut pieces = pieces.iter().peekable();
+    while let Some(piece) = pieces.next() {
+        let Piece::Field(label) = piece else {
+            continue;
+        };
+        let text = format_ident!("{label}_text");
+        match pieces.peek() {
+            Some(Piece::Literal(sep)) => steps.push(quote! {
+                let (#text, rest) = ::aoc_solvers::parse::split_field(rest, #sep, #label)?;
+            }),
+            _ => steps.push(quote! { let #text = rest; }),
+        }
+    }
+
+    let values = fields.iter().map(|field| {
+        let name = field.name;
+        let label = name.to_string();
+        let text = format_ident!("{label}_text");
+        match &field.options.list {
+            Some(sep) => quote! { #name: ::aoc_solvers::parse::field_list(#text, #sep, #label)? },
+            None => quote! { #name: ::aoc_solvers::parse::field(#text, #label)? },
+        }
+    });
+
+    let ty = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    Ok(quote! {
+        impl #impl_generics ::core::str::FromStr for #ty #ty_generics #where_clause {
+            type Err = ::aoc_solvers::parse::PatternError;
+
+            fn from_str(rest: &str) -> ::core::result::Result<Self, Self::Err> {
+                #(#steps)*
+                ::core::result::Result::Ok(Self { #(#values),* })
+            }
+        }
+    })
+}
@@ -1,31 +1,53 @@
 use std::collections::BinaryHeap;
 use std::collections::HashMap;
-use tokio::io;
+use crate::io;
+use crate::stream::{LineSolver, solve_lines};
 
 pub async fn solve_a(
     lines: &Vec<String>,
 )-> io::Result<()>{
     println!("Solving Day 1, Part A");
-    let mut pq1 = BinaryHeap::new();
-    let mut pq2 = BinaryHeap::new();
-    for line in lines {
-        let parts: Vec<&str> = line.split_whitespace().collect();
-        if let (Some(&num1_str), Some(&num2_str)) = (parts.get(0), parts.get(1)) {
-            if let (Ok(num1), Ok(num2)) = (num1_str.parse::<i32>(), num2_str.parse::<i32>()) {
-                pq1.push(num1);
-                pq2.push(num2);
-            } else {
-                eprintln!("Error parsing numbers in line: {}", line);
-            }
+    solve_lines(&mut PartA::default(), lines.iter().map(String::as_str))?;
+    Ok(())
+
+}
+
+/// The two numbers on a line, or `None` (after complaining) if it doesn't have them.
+fn parse_pair(line: &str) -> Option<(i32, i32)> {
+    let parts: Vec<&str> = line.split_whitespace().collect();
+    if let (Some(&num1_str), Some(&num2_str)) = (parts.first(), parts.get(1)) {
+        if let (Ok(num1), Ok(num2)) = (num1_str.parse::<i32>(), num2_str.parse::<i32>()) {
+            return Some((num1, num2));
         } else {
-            eprintln!("Error splitting line: {}", line);
+            eprintln!("Error parsing numbers in line: {}", line);
         }
+    } else {
+        eprintln!("Error splitting line: {}", line);
     }
-    let total_diff = calculate_sum_of_abs_differences(&pq1, &pq2);
+    None
+}
 
-    println!("Total sum of absolute differences: {}", total_diff);
-    Ok(())
+#[derive(Default)]
+pub struct PartA {
+    pq1: BinaryHeap<i32>,
+    pq2: BinaryHeap<i32>,
+}
 
+impl LineSolver for PartA {
+    fn line(&mut self, line: &str) -> io::Result<()> {
+        if let Some((num1, num2)) = parse_pair(line) {
+            self.pq1.push(num1);
+            self.pq2.push(num2);
+        }
+        Ok(())
+    }
+
+    fn finish(&mut self) -> io::Result<String> {
+        let total_diff = calculate_sum_of_abs_differences(&self.pq1, &self.pq2);
+
+        println!("Total sum of absolute differences: {}", total_diff);
+        Ok(total_diff.to_string())
+    }
 }
 
 fn calculate_sum_of_abs_differences(heap1: &BinaryHeap<i32>, heap2: &BinaryHeap<i32>) -> u64 {
@@ -44,36 +66,39 @@ pub async fn solve_b(
     input_lines: &Vec<String>,
 ) -> io::Result<()>{
     println!("Solving Day 1, Part A");
-    let lines = input_lines.to_vec();
-   
-    let mut first_numbers: Vec<i32> = Vec::new();
-    let mut second_number_counts: HashMap<i32, u32> = HashMap::new();
-
-    for line in lines {
-        let parts: Vec<&str> = line.split_whitespace().collect();
-        if let (Some(&num1_str), Some(&num2_str)) = (parts.get(0), parts.get(1)) {
-            if let (Ok(num1), Ok(num2)) = (num1_str.parse::<i32>(), num2_str.parse::<i32>()) {
-                first_numbers.push(num1);
-
-                *second_number_counts.entry(num2).or_insert(0) += 1;
-            } else {
-                eprintln!("Error parsing numbers in line: {}", line);
-            }
-        } else {
-            eprintln!("Error splitting line: {}", line);
+    solve_lines(&mut PartB::default(), input_lines.iter().map(String::as_str))?;
+    Ok(())
+
+}
+
+#[derive(Default)]
+pub struct PartB {
+    first_numbers: Vec<i32>,
+    second_number_counts: HashMap<i32, u32>,
+}
+
+impl LineSolver for PartB {
+    fn line(&mut self, line: &str) -> io::Result<()> {
+        if let Some((num1, num2)) = parse_pair(line) {
+            self.first_numbers.push(num1);
+
+            *self.second_number_counts.entry(num2).or_insert(0) += 1;
         }
-    }
-     let mut total: u64 = 0;
-
-     for &item in &first_numbers {
-         if let Some(&count) = second_number_counts.get(&item) {
-             total += (item as u64) * (count as u64);
-         }
-     }
- 
-     println!("Total: {}", total);
         Ok(())
+    }
 
+    fn finish(&mut self) -> io::Result<String> {
+        let mut total: u64 = 0;
+
+        for &item in &self.first_numbers {
+            if let Some(&count) = self.second_number_counts.get(&item) {
+                total += (item as u64) * (count as u64);
+            }
+        }
+
+        println!("Total: {}", total);
+        Ok(total.to_string())
+    }
 }
 
 #[cfg(test)]
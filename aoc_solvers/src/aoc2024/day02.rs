@@ -1,9 +1,20 @@
-use tokio::io;
+use crate::io;
+use crate::stream::{LineSolver, solve_lines};
 
 pub async fn solve_a(lines: &Vec<String>) -> io::Result<()> {
     println!("Solving Day 2, Part A");
-    let mut goodlines: u32 = 0;
-    for line in lines {
+    solve_lines(&mut PartA::default(), lines.iter().map(String::as_str))?;
+
+    Ok(())
+}
+
+#[derive(Default)]
+pub struct PartA {
+    goodlines: u32,
+}
+
+impl LineSolver for PartA {
+    fn line(&mut self, line: &str) -> io::Result<()> {
         let parts: Vec<&str> = line.split_whitespace().collect();
         let mut is_valid: bool = true;
         let first: u32 = parts[0].parse().unwrap();
@@ -23,23 +34,35 @@ pub async fn solve_a(lines: &Vec<String>) -> io::Result<()> {
             }
         }
         if is_valid {
-            goodlines += 1;
+            self.goodlines += 1;
             // print!("Good line: {}", line);
         }
         else {
             // print!("Bad line: {}", line);
         }
-        println!();
+        Ok(())
     }
-    print!("Good lines: {}", goodlines);
 
-    Ok(())
+    fn finish(&mut self) -> io::Result<String> {
+        println!("Good lines: {}", self.goodlines);
+        Ok(self.goodlines.to_string())
+    }
 }
 
 pub async fn solve_b(lines: &Vec<String>) -> io::Result<()> {
     println!("Solving Day 2, Part A");
-    let mut goodlines: u32 = 0;
-    for line in lines {
+    solve_lines(&mut PartB::default(), lines.iter().map(String::as_str))?;
+
+    Ok(())
+}
+
+#[derive(Default)]
+pub struct PartB {
+    goodlines: u32,
+}
+
+impl LineSolver for PartB {
+    fn line(&mut self, line: &str) -> io::Result<()> {
         let parts: Vec<&str> = line.split_whitespace().collect();
         let mut is_valid: bool = true;
         let first: u32 = parts[0].parse().unwrap();
@@ -60,7 +83,7 @@ pub async fn solve_b(lines: &Vec<String>) -> io::Result<()> {
             // print!("{} {} {} {} ", prev, curr, is_valid, ascending);
         }
         if is_valid {
-            goodlines += 1;
+            self.goodlines += 1;
             // print!("Good line: {}", line);
         }
         else {
@@ -86,7 +109,7 @@ pub async fn solve_b(lines: &Vec<String>) -> io::Result<()> {
                     // print!("{} {} {} {} ", prev, curr, is_valid, ascending);
                 }
                 if is_valid {
-                    goodlines += 1;
+                    self.goodlines += 1;
                     break;
                     // print!("Good line: {}", line);
                 }
@@ -95,11 +118,15 @@ pub async fn solve_b(lines: &Vec<String>) -> io::Result<()> {
                 }
             }
         }
+        Ok(())
     }
-    print!("Good lines: {}", goodlines);
 
-    Ok(())
+    fn finish(&mut self) -> io::Result<String> {
+        println!("Good lines: {}", self.goodlines);
+        Ok(self.goodlines.to_string())
+    }
 }
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1,5 +1,5 @@
 use regex::Regex;
-use tokio::io;
+use crate::io;
 
 pub async fn solve_a(lines: &Vec<String>) -> io::Result<()> {
     println!("Solving Day 3, Part A");
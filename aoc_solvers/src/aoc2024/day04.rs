@@ -1,4 +1,5 @@
-use tokio::io;
+use crate::grid::{Grid, NEIGHBOURS8};
+use crate::io;
 
 pub async fn solve_a(lines: &Vec<String>) -> io::Result<()> {
     println!("Solving Day 4, Part A");
@@ -10,28 +11,15 @@ pub async fn solve_a(lines: &Vec<String>) -> io::Result<()> {
     Ok(())
 }
 fn count_occurrences(matrix: &Vec<String>, target: &str) -> usize {
-    let rows = matrix.len();
-    let cols = matrix[0].len();
-    let directions = [
-        (0, 1),   // Right
-        (1, 0),   // Down
-        (0, -1),  // Left
-        (-1, 0),  // Up
-        (1, 1),   // Down-right
-        (1, -1),  // Down-left
-        (-1, 1),  // Up-right
-        (-1, -1), // Up-left
-    ];
+    let grid: Grid<char> = Grid::parse(matrix, |c| c);
 
     let mut count = 0;
 
-    for row in 0..rows {
-        for col in 0..cols {
-            if matrix[row].as_bytes()[col] == b'X' {
-                for &(dx, dy) in &directions {
-                    if check_direction(matrix, target, row as isize, col as isize, dx, dy) {
-                        count += 1;
-                    }
+    for (start, &cell) in grid.iter() {
+        if cell == 'X' {
+            for step in NEIGHBOURS8 {
+                if check_direction(&grid, target, start, step) {
+                    count += 1;
                 }
             }
         }
@@ -41,32 +29,13 @@ fn count_occurrences(matrix: &Vec<String>, target: &str) -> usize {
 }
 
 fn check_direction(
-    matrix: &Vec<String>,
+    grid: &Grid<char>,
     target: &str,
-    start_row: isize,
-    start_col: isize,
-    dx: isize,
-    dy: isize,
+    start: (usize, usize),
+    step: (isize, isize),
 ) -> bool {
-    let rows = matrix.len() as isize;
-    let cols = matrix[0].len() as isize;
-    let target_bytes = target.as_bytes();
-
-    for (i, &ch) in target_bytes.iter().enumerate() {
-        let new_row = start_row + i as isize * dx;
-        let new_col = start_col + i as isize * dy;
-
-        if new_row < 0
-            || new_row >= rows
-            || new_col < 0
-            || new_col >= cols
-            || matrix[new_row as usize].as_bytes()[new_col as usize] != ch
-        {
-            return false;
-        }
-    }
-
-    true
+    let word = grid.ray(start, step).map(|pos| grid[pos]).take(target.len());
+    word.eq(target.chars())
 }
 
 pub async fn solve_b(lines: &Vec<String>) -> io::Result<()> {
@@ -79,8 +48,7 @@ pub async fn solve_b(lines: &Vec<String>) -> io::Result<()> {
 }
 
 fn find_pattern(matrix: &Vec<String>) -> usize {
-    let rows = matrix.len();
-    let cols = matrix[0].len();
+    let grid: Grid<char> = Grid::parse(matrix, |c| c);
     let mut count = 0;
 
     let diagonals = [
@@ -90,31 +58,21 @@ fn find_pattern(matrix: &Vec<String>) -> usize {
         (1, 1),   // Bottom-right
     ];
 
-    for row in 0..rows {
-        for col in 0..cols {
-            if matrix[row].as_bytes()[col] == b'A' {
-                let mut diag_chars = Vec::new();
-                for &(dx, dy) in &diagonals {
-                    let new_row = row as isize + dx;
-                    let new_col = col as isize + dy;
-
-                    if new_row >= 0
-                        && new_row < rows as isize
-                        && new_col >= 0
-                        && new_col < cols as isize
-                    {
-                        diag_chars.push(matrix[new_row as usize].as_bytes()[new_col as usize]);
-                    }
-                }
-                if diag_chars.len() == 4 {
-                    let m_count = diag_chars.iter().filter(|&&c| c == b'M').count();
-                    let s_count = diag_chars.iter().filter(|&&c| c == b'S').count();
-                    if m_count == 2
-                        && s_count == 2
-                        && diag_chars[0] != diag_chars[3] // Top-left != Bottom-left
-                    {
-                        count += 1;
-                    }
+    for (pos, &cell) in grid.iter() {
+        if cell == 'A' {
+            let diag_chars: Vec<char> = diagonals
+                .iter()
+                .filter_map(|&step| grid.offset(pos, step))
+                .map(|corner| grid[corner])
+                .collect();
+            if diag_chars.len() == 4 {
+                let m_count = diag_chars.iter().filter(|&&c| c == 'M').count();
+                let s_count = diag_chars.iter().filter(|&&c| c == 'S').count();
+                if m_count == 2
+                    && s_count == 2
+                    && diag_chars[0] != diag_chars[3] // Top-left != Bottom-left
+                {
+                    count += 1;
                 }
             }
         }
@@ -128,6 +86,7 @@ mod tests {
     use super::*;
     use crate::get_input_for_puzzle;
     use crate::Puzzle;
+    use crate::testing::lines;
     /// Determines the day name (e.g., "Day01") based on the module path
     fn get_day_name() -> String {
         let module_path = module_path!(); // e.g., "puzzles::day01"
@@ -144,6 +103,24 @@ mod tests {
         }
     }
 
+    const SAMPLE: &str = "MMMSXXMASM\n\
+                          MSAMXMSMSA\n\
+                          AMXSXMAAMM\n\
+                          MSAMASMSMX\n\
+                          XMASAMXAMM\n\
+                          XXAMMXXAMA\n\
+                          SMSMSASXSS\n\
+                          SAXAMASAAA\n\
+                          MAMMMXMMMM\n\
+                          MXMXAXMASX";
+
+    #[test]
+    fn sample_answers() {
+        let input = lines(SAMPLE);
+        assert_eq!(count_occurrences(&input, "XMAS"), 18);
+        assert_eq!(find_pattern(&input), 9);
+    }
+
     // #[test]
     // fn test_solve_a_with_real_input() {
     //     let puzzle = get_puzzle('A');
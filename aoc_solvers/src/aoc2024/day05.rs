@@ -0,0 +1,182 @@
+use crate::graph::Graph;
+use crate::io;
+use crate::parse::PuzzleInput;
+
+/// Page `before` has to be printed before page `after`.
+#[derive(PuzzleInput)]
+#[puzzle(line = "{before}|{after}")]
+pub struct Rule {
+    before: i32,
+    after: i32,
+}
+
+/// The ordering rules, then the updates, each a list of pages.
+#[derive(PuzzleInput)]
+pub struct Manual {
+    rules: Vec<Rule>,
+    #[puzzle(list = ",")]
+    updates: Vec<Vec<i32>>,
+}
+
+pub async fn solve_a(lines: &Vec<String>) -> io::Result<()> {
+    println!("Solving Day 5, Part A");
+
+    let manual = Manual::parse_input(lines)?;
+
+    let rules = populate_rules(&manual.rules);
+    let result = calculate_results_from_input(&manual.updates, &rules);
+
+    println!("Result: {}", result);
+
+    Ok(())
+}
+
+/// Each rule is an edge from its `before` page to its `after` page.
+pub fn populate_rules(input: &[Rule]) -> Graph<i32> {
+    let mut rules = Graph::new();
+
+    for rule in input {
+        rules.add_edge(&rule.before, &rule.after);
+    }
+    rules
+}
+
+pub fn calculate_results_from_input(second_part: &[Vec<i32>], rules: &Graph<i32>) -> i32 {
+    let (valid_lines, _) = split_lines(second_part, rules);
+
+    valid_lines
+        .iter()
+        .map(|line| calculate_middle(line))
+        .sum()
+}
+pub fn split_lines(second_part: &[Vec<i32>], rules: &Graph<i32>) -> (Vec<Vec<i32>>, Vec<Vec<i32>>) {
+    let mut valid_lines = Vec::new();
+    let mut invalid_lines = Vec::new();
+
+    for numbers in second_part {
+        if check_validity(numbers, rules) {
+            valid_lines.push(numbers.clone());
+        } else {
+            invalid_lines.push(numbers.clone());
+        }
+    }
+
+    (valid_lines, invalid_lines)
+}
+
+/// Whether no page in the update has a rule saying it should have come before an earlier one.
+pub fn check_validity(numbers: &[i32], rules: &Graph<i32>) -> bool {
+    // Pages no rule mentions can go anywhere.
+    let ids: Vec<usize> = numbers.iter().filter_map(|num| rules.id(num)).collect();
+
+    for (i, &earlier) in ids.iter().enumerate() {
+        for &later in &ids[i + 1..] {
+            if rules.has_edge(later, earlier) {
+                return false;
+            }
+        }
+    }
+    true
+}
+pub fn calculate_middle(numbers: &[i32]) -> i32 {
+    if numbers.is_empty() {
+        0
+    } else {
+        let middle_index = numbers.len() / 2;
+        numbers[middle_index]
+    }
+}
+
+
+pub async fn solve_b(lines: &Vec<String>) -> io::Result<()> {
+    println!("Solving Day 5, Part B");
+
+    let manual = Manual::parse_input(lines)?;
+
+    let rules = populate_rules(&manual.rules);
+
+    let (_, invalid_lines) = split_lines(&manual.updates, &rules);
+
+    let result = fix_invalid_lines(&invalid_lines, &rules)?;
+
+    println!("Result: {}", result);
+
+    Ok(())
+}
+
+pub fn fix_invalid_lines(invalid_lines: &[Vec<i32>], rules: &Graph<i32>) -> io::Result<i32> {
+    let mut results = 0;
+
+    for numbers in invalid_lines {
+        // The full rule set loops back on itself; only the rules between this update's own
+        // pages have to give an order.
+        let update = rules.subgraph(|page| numbers.contains(page));
+        let order = update.topological_sort().map_err(io::Error::other)?;
+        let corrected_line: Vec<i32> = order.iter().map(|&id| *update.label(id)).collect();
+
+        results += calculate_middle(&corrected_line);
+    }
+
+    Ok(results)
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::get_input_for_puzzle;
+    use crate::Puzzle;
+    use crate::testing::lines;
+    /// Determines the day name (e.g., "Day01") based on the module path
+    fn get_day_name() -> String {
+        let module_path = module_path!(); // e.g., "puzzles::day01"
+        let module_name = module_path.split("::").last().unwrap_or("Unknown");
+        module_name.to_string().replace("day", "Day")
+    }
+
+    fn get_puzzle(part: char) -> Puzzle {
+        let day = get_day_name().replace("Day", "").parse::<u8>().unwrap_or(1);
+        match part {
+            'A' => Puzzle::from_day_part(day, 'A'),
+            'B' => Puzzle::from_day_part(day, 'B'),
+            _ => panic!("Invalid part"),
+        }
+    }
+
+    const SAMPLE: &str = "47|53\n97|13\n97|61\n97|47\n75|29\n61|13\n75|53\n29|13\n97|29\n53|29\n\
+                          61|53\n97|53\n61|29\n47|13\n75|47\n97|75\n47|61\n75|61\n47|29\n75|13\n\
+                          53|13\n\n75,47,61,53,29\n97,61,53,29,13\n75,29,13\n75,97,47,61,53\n\
+                          61,13,29\n97,13,75,29,47";
+
+    #[test]
+    fn sample_answers() {
+        let manual = Manual::parse_input(&lines(SAMPLE)).unwrap();
+        let rules = populate_rules(&manual.rules);
+        assert_eq!(calculate_results_from_input(&manual.updates, &rules), 143);
+        let (_, invalid_lines) = split_lines(&manual.updates, &rules);
+        assert_eq!(fix_invalid_lines(&invalid_lines, &rules).unwrap(), 123);
+    }
+
+    // #[test]
+    // fn test_solve_a_with_real_input() {
+    //     let puzzle = get_puzzle('A');
+    //     if let Some(input) = get_input_for_puzzle(&puzzle) {
+    //         solve_a(&input);
+    //         assert!(true, "Add your assertions here");
+    //     } else {
+    //         panic!("Input file not found for {:?}", puzzle);
+    //     }
+    // }
+
+    // #[test]
+    // #[ignore]
+    // fn test_solve_b_with_real_input() {
+    //     let puzzle = get_puzzle('B');
+    //     if let Some(input) = get_input_for_puzzle(&puzzle) {
+    //         solve_b(&input);
+    //         assert!(true, "Add your assertions here");
+    //     } else {
+    //         panic!("Input file not found for {:?}", puzzle);
+    //     }
+    // }
+}
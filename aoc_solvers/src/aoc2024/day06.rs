@@ -1,37 +1,33 @@
 use std::collections::HashSet;
-use tokio::io;
+use crate::geometry::Dir4;
+use crate::grid::Grid;
+use crate::io;
 
 pub async fn solve_a(lines: &Vec<String>) -> io::Result<()> {
     println!("Solving Day 6, Part A");
+    println!("Visited locations: {}", visited_count(lines));
+    Ok(())
+}
 
-    let mut grid: Vec<Vec<char>> = lines.iter().map(|line| line.chars().collect()).collect();
-    let mut start_pos = (0, 0);
-    for (y, row) in grid.iter().enumerate() {
-        if let Some(x) = row.iter().position(|&c| c == '^') {
-            start_pos = (x, y);
-            break;
-        }
-    }
+fn visited_count(lines: &[String]) -> usize {
+    let mut grid: Grid<char> = Grid::parse(lines, |c| c);
+    let (start_y, start_x) = grid.find(&'^').unwrap_or((0, 0));
 
-    let directions = [(0, -1), (1, 0), (0, 1), (-1, 0)];
-    let mut dir_index = 0; 
+    let mut direction = Dir4::North;
 
     let mut visited = HashSet::new();
-    let (mut x, mut y) = start_pos;
+    let (mut x, mut y) = (start_x, start_y);
 
-    while y < grid.len() && x < grid[0].len() {
+    loop {
         visited.insert((x, y));
         grid[y][x] = 'X';
 
-        let (dx, dy) = directions[dir_index];
-        let (next_x, next_y) = ((x as isize + dx) as usize, (y as isize + dy) as usize);
-
-        if next_x >= grid[0].len() || next_y >= grid.len() {
+        let Some((next_y, next_x)) = grid.offset((y, x), direction.step()) else {
             break;
-        }
+        };
 
         if grid[next_y][next_x] == '#' {
-            dir_index = (dir_index + 1) % 4;
+            direction = direction.turn_right();
         } else {
             x = next_x;
             y = next_y;
@@ -39,68 +35,53 @@ pub async fn solve_a(lines: &Vec<String>) -> io::Result<()> {
     }
     println!();
     println!("Final location: ({}, {})", x, y);
-    for row in &grid {
-        println!("{}", row.iter().collect::<String>());
-    }
+    print!("{}", grid);
 
-    println!("Visited locations: {}", visited.len());
-    Ok(())
+    visited.len()
 }
 
 pub async fn solve_b(lines: &Vec<String>) -> io::Result<()> {
     println!("Solving Day 6, Part B");
+    println!("Number of loop-inducing positions: {}", loop_positions(lines));
+    Ok(())
+}
 
-    let mut grid: Vec<Vec<char>> = lines.iter().map(|line| line.chars().collect()).collect();
+fn loop_positions(lines: &[String]) -> usize {
+    let mut grid: Grid<char> = Grid::parse(lines, |c| c);
     let mut start_pos = (0, 0);
-    for (y, row) in grid.iter().enumerate() {
-        if let Some(x) = row.iter().position(|&c| c == '^') {
-            start_pos = (x, y);
-            grid[y][x] = 'X';
-            break;
-        }
+    if let Some((y, x)) = grid.find(&'^') {
+        start_pos = (x, y);
+        grid[y][x] = 'X';
     }
 
-    let directions = [(0, -1), (1, 0), (0, 1), (-1, 0)];
-
     #[derive(Clone, Copy, PartialEq, Eq)]
     struct Position {
         x: usize,
         y: usize,
-        dir_index: usize,
+        direction: Dir4,
     }
 
     use std::collections::HashSet;
 
     fn loop_check(
-        grid: &Vec<Vec<char>>,
+        grid: &Grid<char>,
         mut pos: Position,
-        directions: &[(isize, isize)],
     ) -> bool {
         let mut visited_positions = HashSet::new();
     
         loop {
-            let (dx, dy) = directions[pos.dir_index];
-            let next_x = pos.x as isize + dx;
-            let next_y = pos.y as isize + dy;
-    
-            if next_x < 0
-                || next_y < 0
-                || next_x >= grid[0].len() as isize
-                || next_y >= grid.len() as isize
-            {
+            let Some((ny, nx)) = grid.offset((pos.y, pos.x), pos.direction.step()) else {
                 return false;
-            }
-    
-            let (nx, ny) = (next_x as usize, next_y as usize);
+            };
     
-            if visited_positions.contains(&(nx, ny, pos.dir_index)) {
+            if visited_positions.contains(&(nx, ny, pos.direction)) {
                 return true;
             }
     
-            visited_positions.insert((nx, ny, pos.dir_index));
+            visited_positions.insert((nx, ny, pos.direction));
     
             if grid[ny][nx] == '#' {
-                pos.dir_index = (pos.dir_index + 1) % 4; // Turn 90° right
+                pos.direction = pos.direction.turn_right();
             } else {
                 pos.x = nx;
                 pos.y = ny;
@@ -112,25 +93,15 @@ pub async fn solve_b(lines: &Vec<String>) -> io::Result<()> {
     let mut pos = Position {
         x: start_pos.0,
         y: start_pos.1,
-        dir_index: 0,
+        direction: Dir4::North,
     };
 
     loop {
         grid[pos.y][pos.x] = 'X';
 
-        let (dx, dy) = directions[pos.dir_index];
-        let next_x = pos.x as isize + dx;
-        let next_y = pos.y as isize + dy;
-
-        if next_x < 0
-            || next_y < 0
-            || next_x >= grid[0].len() as isize
-            || next_y >= grid.len() as isize
-        {
+        let Some((ny, nx)) = grid.offset((pos.y, pos.x), pos.direction.step()) else {
             break;
-        }
-
-        let (nx, ny) = (next_x as usize, next_y as usize);
+        };
 
         if grid[ny][nx] == '.' {
             grid[ny][nx] = '#'; 
@@ -139,9 +110,8 @@ pub async fn solve_b(lines: &Vec<String>) -> io::Result<()> {
                 Position {
                     x: pos.x,
                     y: pos.y,
-                    dir_index: pos.dir_index,
+                    direction: pos.direction,
                 },
-                &directions,
             ) {
                 result += 1;
             }
@@ -149,15 +119,14 @@ pub async fn solve_b(lines: &Vec<String>) -> io::Result<()> {
         }
 
         if grid[ny][nx] == '#' {
-            pos.dir_index = (pos.dir_index + 1) % 4; // Turn 90° right
+            pos.direction = pos.direction.turn_right();
         } else {
             pos.x = nx;
             pos.y = ny;
         }
     }
 
-    println!("Number of loop-inducing positions: {}", result);
-    Ok(())
+    result
 }
 
 
@@ -166,6 +135,7 @@ mod tests {
     use super::*;
     use crate::get_input_for_puzzle;
     use crate::Puzzle;
+    use crate::testing::lines;
     /// Determines the day name (e.g., "Day01") based on the module path
     fn get_day_name() -> String {
         let module_path = module_path!(); // e.g., "puzzles::day01"
@@ -182,6 +152,24 @@ mod tests {
         }
     }
 
+    const SAMPLE: &str = "....#.....\n\
+                          .........#\n\
+                          ..........\n\
+                          ..#.......\n\
+                          .......#..\n\
+                          ..........\n\
+                          .#..^.....\n\
+                          ........#.\n\
+                          #.........\n\
+                          ......#...";
+
+    #[test]
+    fn sample_answers() {
+        let input = lines(SAMPLE);
+        assert_eq!(visited_count(&input), 41);
+        assert_eq!(loop_positions(&input), 6);
+    }
+
     // #[test]
     // fn test_solve_a_with_real_input() {
     //     let puzzle = get_puzzle('A');
@@ -1,74 +1,27 @@
-use std::collections::{HashMap, HashSet, VecDeque};
+use std::collections::{HashMap, VecDeque};
 
-use tokio::io;
+use crate::disjoint_set::DisjointSet;
+use crate::grid::Grid;
+use crate::io;
 
 pub async fn solve_a(lines: &Vec<String>) -> io::Result<()> {
     println!("Solving Day 12, Part A");
+    println!("Total Price of Fencing: {}", fencing_price(lines));
 
-    let mut grid: Vec<Vec<char>> = lines.iter().map(|line| line.chars().collect()).collect();
-    let rows = grid.len();
-    let cols = grid[0].len();
+    Ok(())
+}
 
-    #[derive(Default, PartialEq, Debug)]
-    struct Segment {
-        n: bool,
-        s: bool,
-        e: bool,
-        w: bool,
-    }
+fn fencing_price(lines: &[String]) -> i32 {
+    let grid: Grid<char> = Grid::parse(lines, |c| c);
 
     let mut total_price = 0;
 
-    let mut queue = VecDeque::new();
-
-    let mut bfs = |grid: &mut Vec<Vec<char>>, start: (usize, usize), plant: char| -> i32 {
+    for region in regions(&grid) {
+        let plant = grid[region[0]];
         println!("Start: {:?}", plant);
 
-        let mut area = 0;
-        let mut visited: HashSet<(usize, usize)> = HashSet::new();
-        visited.insert(start);
-        let mut segments: HashMap<(usize, usize), Segment> = HashMap::new();
-        let directions = [(-1, 0, 's'), (1, 0, 'n'), (0, -1, 'w'), (0, 1, 'e')];
-
-        queue.push_back(start);
-
-        while let Some((x, y)) = queue.pop_front() {
-            let mut seg: Segment = Segment::default();
-            grid[x][y] = '.';
-            area += 1;
-            for &(dx, dy, dir) in &directions {
-                let nx = x as isize + dx;
-                let ny = y as isize + dy;
-
-                if nx < 0 || nx >= rows as isize || ny < 0 || ny >= cols as isize {
-                    match dir {
-                        'n' => seg.n = true,
-                        's' => seg.s = true,
-                        'e' => seg.e = true,
-                        'w' => seg.w = true,
-                        _ => {}
-                    }
-                } else {
-                    let nx = nx as usize;
-                    let ny = ny as usize;
-                    if visited.contains(&(nx, ny)) {
-                        continue;
-                    } else if grid[nx][ny] == plant {
-                        queue.push_back((nx, ny));
-                        visited.insert((nx, ny));
-                    } else {
-                        match dir {
-                            'n' => seg.n = true,
-                            's' => seg.s = true,
-                            'e' => seg.e = true,
-                            'w' => seg.w = true,
-                            _ => {}
-                        }
-                    }
-                }
-            }
-            segments.insert((x, y), seg);
-        }
+        let area = region.len() as i32;
+        let mut segments = fence_segments(&grid, &region);
 
         let calculate_perimeter = |segments: &HashMap<(usize, usize), Segment>| -> i32 {
             let mut perimeter = 0;
@@ -91,91 +44,33 @@ pub async fn solve_a(lines: &Vec<String>) -> io::Result<()> {
             perimeter
         };
         let perimeter = calculate_perimeter(&mut segments);
-        let result = area * perimeter;
-        result
-    };
-
-    for x in 0..rows {
-        for y in 0..cols {
-            if grid[x][y] != '.' {
-                let plant = grid[x][y];
-                let region = bfs(&mut grid, (x, y), plant);
-                total_price += region;
-            }
-        }
+        total_price += area * perimeter;
     }
 
-    println!("Total Price of Fencing: {}", total_price);
-
-    Ok(())
+    total_price
 }
 
 pub async fn solve_b(lines: &Vec<String>) -> io::Result<()> {
     println!("Solving Day 12, Part A");
+    println!("Total Price of Fencing: {}", bulk_fencing_price(lines));
 
-    let mut grid: Vec<Vec<char>> = lines.iter().map(|line| line.chars().collect()).collect();
-    let rows = grid.len();
-    let cols = grid[0].len();
+    Ok(())
+}
 
-    #[derive(Default, PartialEq, Debug)]
-    struct Segment {
-        n: bool,
-        s: bool,
-        e: bool,
-        w: bool,
-    }
-    let mut total_price = 0;
+/// The price with each straight run of fence counted once, however long it is.
+fn bulk_fencing_price(lines: &[String]) -> i32 {
+    let grid: Grid<char> = Grid::parse(lines, |c| c);
+    let rows = grid.height();
+    let cols = grid.width();
 
-    let mut queue = VecDeque::new();
+    let mut total_price = 0;
 
-    let mut bfs = |grid: &mut Vec<Vec<char>>, start: (usize, usize), plant: char| -> i32 {
+    for region in regions(&grid) {
+        let plant = grid[region[0]];
         println!("Start: {:?}", plant);
 
-        let mut area = 0;
-        let mut visited: HashSet<(usize, usize)> = HashSet::new();
-        visited.insert(start);
-        let mut segments: HashMap<(usize, usize), Segment> = HashMap::new();
-        let directions = [(-1, 0, 's'), (1, 0, 'n'), (0, -1, 'w'), (0, 1, 'e')];
-
-        queue.push_back(start);
-
-        while let Some((x, y)) = queue.pop_front() {
-            let mut seg: Segment = Segment::default();
-            grid[x][y] = '.';
-            area += 1;
-            for &(dx, dy, dir) in &directions {
-                let nx = x as isize + dx;
-                let ny = y as isize + dy;
-
-                if nx < 0 || nx >= rows as isize || ny < 0 || ny >= cols as isize {
-                    match dir {
-                        'n' => seg.n = true,
-                        's' => seg.s = true,
-                        'e' => seg.e = true,
-                        'w' => seg.w = true,
-                        _ => {}
-                    }
-                } else {
-                    let nx = nx as usize;
-                    let ny = ny as usize;
-                    if visited.contains(&(nx, ny)) {
-                        continue;
-                    } else if grid[nx][ny] == plant {
-                        queue.push_back((nx, ny));
-                        visited.insert((nx, ny));
-                    } else {
-                        match dir {
-                            'n' => seg.n = true,
-                            's' => seg.s = true,
-                            'e' => seg.e = true,
-                            'w' => seg.w = true,
-                            _ => {}
-                        }
-                    }
-                }
-            }
-            segments.insert((x, y), seg);
-        }
+        let area = region.len() as i32;
+        let mut segments = fence_segments(&grid, &region);
 
         fn no_walls(seg: &mut Segment) -> bool {
             !seg.n && !seg.s && !seg.e && !seg.w
@@ -344,23 +239,64 @@ pub async fn solve_b(lines: &Vec<String>) -> io::Result<()> {
             perimeter
         };
         let perimeter = calculate_perimeter(&mut segments);
-        let result = area * perimeter;
-        result
-    };
-
-    for x in 0..rows {
-        for y in 0..cols {
-            if grid[x][y] != '.' {
-                let plant = grid[x][y];
-                let region = bfs(&mut grid, (x, y), plant);
-                total_price += region;
+        total_price += area * perimeter;
+    }
+
+    total_price
+}
+
+#[derive(Default, PartialEq, Debug)]
+struct Segment {
+    n: bool,
+    s: bool,
+    e: bool,
+    w: bool,
+}
+
+/// The plots of each region, a region being plots of the same plant joined up and down or
+/// side to side. In the order their top-left plot comes, reading row by row.
+fn regions(grid: &Grid<char>) -> Vec<Vec<(usize, usize)>> {
+    let cols = grid.width();
+    let mut plots = DisjointSet::new(grid.width() * grid.height());
+    for ((x, y), &plant) in grid.iter() {
+        for (nx, ny) in [(x + 1, y), (x, y + 1)] {
+            if grid.get((nx, ny)) == Some(&plant) {
+                plots.union(x * cols + y, nx * cols + ny);
             }
         }
     }
+    plots
+        .components()
+        .map(|region| region.iter().map(|&plot| (plot / cols, plot % cols)).collect())
+        .collect()
+}
 
-    println!("Total Price of Fencing: {}", total_price);
-
-    Ok(())
+/// Which sides of each plot in `region` have a fence.
+fn fence_segments(
+    grid: &Grid<char>,
+    region: &[(usize, usize)],
+) -> HashMap<(usize, usize), Segment> {
+    let plant = grid[region[0]];
+    let directions = [(-1, 0, 's'), (1, 0, 'n'), (0, -1, 'w'), (0, 1, 'e')];
+    let mut segments = HashMap::new();
+    for &(x, y) in region {
+        let mut seg = Segment::default();
+        for &(dx, dy, dir) in &directions {
+            if grid.offset((x, y), (dx, dy)).is_some_and(|next| grid[next] == plant) {
+                continue;
+            }
+            // Off the edge or next to another plant, so this side has a fence.
+            match dir {
+                'n' => seg.n = true,
+                's' => seg.s = true,
+                'e' => seg.e = true,
+                'w' => seg.w = true,
+                _ => {}
+            }
+        }
+        segments.insert((x, y), seg);
+    }
+    segments
 }
 
 #[cfg(test)]
@@ -368,6 +304,7 @@ mod tests {
     use super::*;
     use crate::get_input_for_puzzle;
     use crate::Puzzle;
+    use crate::testing::lines;
     /// Determines the day name (e.g., "Day01") based on the module path
     fn get_day_name() -> String {
         let module_path = module_path!(); // e.g., "puzzles::day01"
@@ -384,6 +321,24 @@ mod tests {
         }
     }
 
+    const SAMPLE: &str = "RRRRIICCFF\n\
+                          RRRRIICCCF\n\
+                          VVRRRCCFFF\n\
+                          VVRCCCJFFF\n\
+                          VVVVCJJCFE\n\
+                          VVIVCCJJEE\n\
+                          VVIIICJJEE\n\
+                          MIIIIIJJEE\n\
+                          MIIISIJEEE\n\
+                          MMMISSJEEE";
+
+    #[test]
+    fn sample_answers() {
+        let input = lines(SAMPLE);
+        assert_eq!(fencing_price(&input), 1930);
+        assert_eq!(bulk_fencing_price(&input), 1206);
+    }
+
     // #[test]
     // fn test_solve_a_with_real_input() {
     //     let puzzle = get_puzzle('A');
@@ -1,17 +1,10 @@
-use tokio::io;
+use crate::io;
+use crate::parse;
 
 pub async fn solve_a(lines: &Vec<String>) -> io::Result<()> {
     println!("Solving Day 13, Part A");
 
-    let machines = parse_input(lines);
-
-    let total_cost: i64 = machines
-        .iter()
-        .map(|machine| {
-            let (n, m) = solve_machine(machine, 0);
-            n * 3 + m * 1
-        })
-        .sum();
+    let total_cost = total_cost(lines, 0)?;
 
     println!("Total cost for Part B: {}", total_cost);
 
@@ -28,56 +21,44 @@ struct Machine {
     target_y: i64,
 }
 
-fn parse_input(lines: &Vec<String>) -> Vec<Machine> {
-    lines
-        .chunks(4)
-        .map(|chunk| {
-            let button_a = parse_button(&chunk[0]);
-            let button_b = parse_button(&chunk[1]);
-            let prize = parse_prize(&chunk[2]);
-            Machine {
-                button_a_x: button_a.0,
-                button_a_y: button_a.1,
-                button_b_x: button_b.0,
-                button_b_y: button_b.1,
-                target_x: prize.0,
-                target_y: prize.1,
-            }
-        })
-        .collect()
-}
-
-fn parse_button(line: &str) -> (i64, i64) {
-    let parts: Vec<_> = line.split_whitespace().collect();
-    let x = parts[2].replace("X+", "").replace(",", "").parse().unwrap();
-    let y = parts[3].replace("Y+", "").parse().unwrap();
-    (x, y)
-}
-
-fn parse_prize(line: &str) -> (i64, i64) {
-    let parts: Vec<_> = line.split_whitespace().collect();
-    let x = parts[1].replace("X=", "").replace(",", "").parse().unwrap();
-    let y = parts[2].replace("Y=", "").parse().unwrap();
-    (x, y)
+fn parse_input(lines: &[String]) -> io::Result<Vec<Machine>> {
+    let mut machines = Vec::new();
+    for machine in parse::sections(lines) {
+        let [button_a_x, button_a_y] = machine.line(0)?.int_array()?;
+        let [button_b_x, button_b_y] = machine.line(1)?.int_array()?;
+        let [target_x, target_y] = machine.line(2)?.int_array()?;
+        machines.push(Machine {
+            button_a_x,
+            button_a_y,
+            button_b_x,
+            button_b_y,
+            target_x,
+            target_y,
+        });
+    }
+    Ok(machines)
 }
 
-
 pub async fn solve_b(lines: &Vec<String>) -> io::Result<()> {
     println!("Solving Day 13, Part B");
 
-    let machines = parse_input(lines);
+    let total_cost = total_cost(lines, 10000000000000)?;
 
-    let total_cost: i64 = machines
+    println!("Total cost for Part B: {}", total_cost);
+
+    Ok(())
+}
+
+/// Tokens to win every prize that can be won, with `offset` added to each prize's position.
+fn total_cost(lines: &[String], offset: i64) -> io::Result<i64> {
+    let machines = parse_input(lines)?;
+    Ok(machines
         .iter()
         .map(|machine| {
-            let (n, m) = solve_machine(machine, 10000000000000);
+            let (n, m) = solve_machine(machine, offset);
             n * 3 + m * 1
         })
-        .sum();
-
-    println!("Total cost for Part B: {}", total_cost);
-
-    Ok(())
+        .sum())
 }
 /// Diophantine equation
 fn solve_machine(machine: &Machine, factor: i64) -> (i64, i64) {
@@ -106,6 +87,7 @@ mod tests {
     use super::*;
     use crate::get_input_for_puzzle;
     use crate::Puzzle;
+    use crate::testing::lines;
     fn get_day_name() -> String {
         let module_path = module_path!(); 
         let module_name = module_path.split("::").last().unwrap_or("Unknown");
@@ -121,6 +103,26 @@ mod tests {
         }
     }
 
+    const SAMPLE: &str = "Button A: X+94, Y+34\n\
+                          Button B: X+22, Y+67\n\
+                          Prize: X=8400, Y=5400\n\n\
+                          Button A: X+26, Y+66\n\
+                          Button B: X+67, Y+21\n\
+                          Prize: X=12748, Y=12176\n\n\
+                          Button A: X+17, Y+86\n\
+                          Button B: X+84, Y+37\n\
+                          Prize: X=7870, Y=6450\n\n\
+                          Button A: X+69, Y+23\n\
+                          Button B: X+27, Y+71\n\
+                          Prize: X=18641, Y=10279";
+
+    #[test]
+    fn sample_answers() {
+        let input = lines(SAMPLE);
+        assert_eq!(total_cost(&input, 0).unwrap(), 480);
+        assert_eq!(total_cost(&input, 10000000000000).unwrap(), 875318608908);
+    }
+
     // #[test]
     // fn test_solve_a_with_real_input() {
     //     let puzzle = get_puzzle('A');
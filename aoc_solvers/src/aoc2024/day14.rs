@@ -1,25 +1,21 @@
-use tokio::io::{self};
+use crate::io;
+use crate::parse;
 
 pub async fn solve_a(lines: &Vec<String>) -> io::Result<()> {
-    let width = 101;
-    let height = 103;
+    println!("Safety Factor: {}", safety_factor(lines, 101, 103)?);
+
+    Ok(())
+}
+
+/// Product of the robots in each quadrant after 100 seconds on a `width` by `height` floor.
+fn safety_factor(lines: &[String], width: usize, height: usize) -> io::Result<i32> {
     let mid_x = width / 2;
     let mid_y = height / 2;
 
     let mut quadrant_counts = [0; 4]; 
 
-    for line in lines {
-        let parts: Vec<&str> = line.split_whitespace().collect();
-        let p: Vec<i32> = parts[0][2..]
-            .split(',')
-            .map(|s| s.parse().unwrap())
-            .collect();
-        let v: Vec<i32> = parts[1][2..]
-            .split(',')
-            .map(|s| s.parse().unwrap())
-            .collect();
-        let (p_x, p_y) = (p[0], p[1]);
-        let (v_x, v_y) = (v[0], v[1]);
+    for line in parse::numbered(lines) {
+        let [p_x, p_y, v_x, v_y]: [i32; 4] = line.int_array()?;
 
         let x_new = (p_x + 100 * v_x).rem_euclid(width as i32);
         let y_new = (p_y + 100 * v_y).rem_euclid(height as i32);
@@ -39,31 +35,19 @@ pub async fn solve_a(lines: &Vec<String>) -> io::Result<()> {
         }
     }
 
-    let safety_factor: i32 = quadrant_counts.iter().product();
-    println!("Safety Factor: {}", safety_factor);
-
-    Ok(())
+    Ok(quadrant_counts.iter().product())
 }
 
 pub async fn solve_b(lines: &Vec<String>) -> io::Result<()> {
     let width = 101;
     let height = 103;
 
-    let mut robots: Vec<((i32, i32), (i32, i32))> = lines
-        .iter()
+    let mut robots: Vec<((i32, i32), (i32, i32))> = parse::numbered(lines)
         .map(|line| {
-            let parts: Vec<&str> = line.split_whitespace().collect();
-            let p: Vec<i32> = parts[0][2..]
-                .split(',')
-                .map(|s| s.parse().unwrap())
-                .collect();
-            let v: Vec<i32> = parts[1][2..]
-                .split(',')
-                .map(|s| s.parse().unwrap())
-                .collect();
-            ((p[0], p[1]), (v[0], v[1]))
+            let [p_x, p_y, v_x, v_y] = line.int_array()?;
+            Ok(((p_x, p_y), (v_x, v_y)))
         })
-        .collect();
+        .collect::<Result<_, parse::ParseError>>()?;
 
     let mut seconds = 0;
 
@@ -98,6 +82,7 @@ mod tests {
     use super::*;
     use crate::get_input_for_puzzle;
     use crate::Puzzle;
+    use crate::testing::lines;
     /// Determines the day name (e.g., "Day01") based on the module path
     fn get_day_name() -> String {
         let module_path = module_path!(); // e.g., "puzzles::day01"
@@ -114,6 +99,16 @@ mod tests {
         }
     }
 
+    const SAMPLE: &str = "p=0,4 v=3,-3\np=6,3 v=-1,-3\np=10,3 v=-1,2\np=2,0 v=2,-1\np=0,0 v=1,3\n\
+                          p=3,0 v=-2,-2\np=7,6 v=-1,-3\np=3,0 v=-1,-2\np=9,3 v=2,3\np=7,3 v=-1,2\n\
+                          p=2,4 v=2,-3\np=9,5 v=-3,-3";
+
+    /// The sample's floor is only 11 wide and 7 tall.
+    #[test]
+    fn sample_answers() {
+        assert_eq!(safety_factor(&lines(SAMPLE), 11, 7).unwrap(), 12);
+    }
+
     // #[test]
     // fn test_solve_a_with_real_input() {
     //     let puzzle = get_puzzle('A');
@@ -1,45 +1,40 @@
-use tokio::io;
+use crate::geometry::Dir4;
+use crate::grid::Grid;
+use crate::io;
+use crate::parse::PuzzleInput;
+
+/// The map, then the robot's moves, which wrap over several lines.
+#[derive(PuzzleInput)]
+struct Warehouse {
+    map: Grid<char>,
+    moves: String,
+}
 
 pub async fn solve_a(lines: &Vec<String>) -> io::Result<()> {
     println!("Solving Day 15, Part A");
 
-    let mut parts = lines.split(|line| line.trim().is_empty());
-    let map_input = parts.next().unwrap();
-    let moves_input = parts.next().unwrap();
+    let gps_sum = gps_sum(lines)?;
+    println!("Sum of GPS coordinates: {}", gps_sum);
 
-    let mut transformed_grid: Vec<Vec<char>> = map_input
-        .iter()
-        .map(|line| line.chars().collect())
-        .collect();
+    Ok(())
+}
 
-    let moves: String = moves_input.join("").replace('\n', "");
+/// Where the boxes end up once the robot has made all its moves.
+fn gps_sum(lines: &[String]) -> io::Result<usize> {
+    let warehouse = Warehouse::parse_input(lines)?;
 
-    let height = transformed_grid.len();
-    let width = transformed_grid[0].len();
+    let mut transformed_grid = warehouse.map;
 
-    let mut robot_pos = (0, 0);
-    for (row, line) in transformed_grid.iter().enumerate() {
-        if let Some(col) = line.iter().position(|&c| c == '@') {
-            robot_pos = (row, col);
-            break;
-        }
-    }
+    let moves = warehouse.moves;
 
-    let directions = vec![('<', (0, -1)), ('>', (0, 1)), ('^', (-1, 0)), ('v', (1, 0))];
+    let mut robot_pos = transformed_grid.find(&'@').unwrap_or((0, 0));
 
     for mov in moves.chars() {
-        let (dr, dc) = directions.iter().find(|&&(d, _)| d == mov).unwrap().1;
-        let (new_r, new_c) = (
-            robot_pos.0 as isize + dr as isize,
-            robot_pos.1 as isize + dc as isize,
-        );
-
-        if new_r < 0 || new_c < 0 || new_r >= height as isize || new_c >= width as isize {
+        let dir = Dir4::from_char(mov).unwrap();
+        let (dr, dc) = dir.step();
+        let Some((new_r, new_c)) = transformed_grid.offset(robot_pos, (dr, dc)) else {
             continue;
-        }
-
-        let new_r = new_r as usize;
-        let new_c = new_c as usize;
+        };
 
         match transformed_grid[new_r][new_c] {
             '.' => {
@@ -52,9 +47,9 @@ pub async fn solve_a(lines: &Vec<String>) -> io::Result<()> {
                 let mut c = new_c as isize;
                 let mut found = false;
                 loop {
-                    r += dr as isize;
-                    c += dc as isize;
-                    if r < 0 || c < 0 || r >= height as isize || c >= width as isize {
+                    r += dr;
+                    c += dc;
+                    if !transformed_grid.in_bounds(r, c) {
                         break;
                     }
                     let ur = r as usize;
@@ -87,63 +82,42 @@ pub async fn solve_a(lines: &Vec<String>) -> io::Result<()> {
     }
 
     let mut gps_sum = 0;
-    for (row, line) in transformed_grid.iter().enumerate() {
-        for (col, &cell) in line.iter().enumerate() {
-            if cell == 'O' {
-                gps_sum += 100 * row + col;
-            }
+    for ((row, col), &cell) in transformed_grid.iter() {
+        if cell == 'O' {
+            gps_sum += 100 * row + col;
         }
     }
 
-    println!("Sum of GPS coordinates: {}", gps_sum);
-
-    Ok(())
+    Ok(gps_sum)
 }
 
 pub async fn solve_b(lines: &Vec<String>) -> io::Result<()> {
     println!("Solving Day 15, Part B");
 
-    let mut parts = lines.split(|line| line.trim().is_empty());
-    let map_input = parts.next().unwrap();
-    let moves_input = parts.next().unwrap();
+    let gps_sum = wide_gps_sum(lines)?;
+    println!("Sum of GPS coordinates: {}", gps_sum);
 
-    let grid: Vec<Vec<char>> = map_input
-        .iter()
-        .map(|line| line.chars().collect())
-        .collect();
+    Ok(())
+}
 
-    let mut transformed_grid = transform_map(grid);
+/// `gps_sum` with the warehouse twice as wide, so each box is two cells.
+fn wide_gps_sum(lines: &[String]) -> io::Result<usize> {
+    let warehouse = Warehouse::parse_input(lines)?;
 
-    let height = transformed_grid.len();
-    let width = transformed_grid[0].len();
+    let mut transformed_grid = transform_map(&warehouse.map);
 
-    let mut robot_pos = (0, 0);
-    for (row, line) in transformed_grid.iter().enumerate() {
-        if let Some(col) = line.iter().position(|&c| c == '@') {
-            robot_pos = (row, col);
-            break;
-        }
-    }
+    let width = transformed_grid.width();
 
-    let moves: String = moves_input.join("").replace('\n', "");
-    for row in &transformed_grid {
-        println!("{}", row.iter().collect::<String>());
-    }
-    let directions = vec![('<', (0, -1)), ('>', (0, 1)), ('^', (-1, 0)), ('v', (1, 0))];
+    let mut robot_pos = transformed_grid.find(&'@').unwrap_or((0, 0));
 
+    let moves = warehouse.moves;
+    print!("{}", transformed_grid);
     for mov in moves.chars() {
-        let (dr, dc) = directions.iter().find(|&&(d, _)| d == mov).unwrap().1;
-        let (new_r, new_c) = (
-            robot_pos.0 as isize + dr as isize,
-            robot_pos.1 as isize + dc as isize,
-        );
-
-        if new_r < 0 || new_c < 0 || new_r >= height as isize || new_c >= width as isize {
+        let dir = Dir4::from_char(mov).unwrap();
+        let (dr, dc) = dir.step();
+        let Some((new_r, new_c)) = transformed_grid.offset(robot_pos, (dr, dc)) else {
             continue;
-        }
-
-        let new_r = new_r as usize;
-        let new_c = new_c as usize;
+        };
 
         match transformed_grid[new_r][new_c] {
             '.' => {
@@ -151,8 +125,8 @@ pub async fn solve_b(lines: &Vec<String>) -> io::Result<()> {
                 robot_pos = (new_r, new_c);
                 transformed_grid[robot_pos.0][robot_pos.1] = '@';
             }
-            '[' | ']' => match mov {
-                '<' | '>' => {
+            '[' | ']' => match dir {
+                Dir4::West | Dir4::East => {
                     let mut r = new_r as isize;
                     let mut c = new_c as isize;
                     let mut found = false;
@@ -192,7 +166,7 @@ pub async fn solve_b(lines: &Vec<String>) -> io::Result<()> {
                         transformed_grid[robot_pos.0][robot_pos.1] = '@';
                     }
                 }
-                '^' | 'v' => {
+                Dir4::North | Dir4::South => {
                     let mut queue = vec![];
                     let mut found = true;
                     let mut box_parts = std::collections::HashSet::new();
@@ -205,7 +179,7 @@ pub async fn solve_b(lines: &Vec<String>) -> io::Result<()> {
                         let next_r = r + dr as isize;
                         let next_c = c + dc as isize;
                     
-                        if next_r < 0 || next_c < 0 || next_r >= height as isize || next_c >= width as isize {
+                        if !transformed_grid.in_bounds(next_r, next_c) {
                             continue;
                         }
                     
@@ -247,9 +221,7 @@ pub async fn solve_b(lines: &Vec<String>) -> io::Result<()> {
                             transformed_grid[ur][uc] = '.';
                         }
 
-                        for row in &transformed_grid {
-                            println!("{}", row.iter().collect::<String>());
-                        }
+                        print!("{}", transformed_grid);
 
                         let updated_parts: Vec<((usize, usize), char)> = box_parts
                             .into_iter()
@@ -270,7 +242,6 @@ pub async fn solve_b(lines: &Vec<String>) -> io::Result<()> {
                         transformed_grid[robot_pos.0][robot_pos.1] = '@';
                     }
                 }
-                _ => {}
             },
 
             '#' => {
@@ -281,48 +252,39 @@ pub async fn solve_b(lines: &Vec<String>) -> io::Result<()> {
     }
 
     //print map
-    for row in &transformed_grid {
-        println!("{}", row.iter().collect::<String>());
-    }
+    print!("{}", transformed_grid);
 
     let mut gps_sum = 0;
 
-    for (row, line) in transformed_grid.iter().enumerate() {
-        for (col, &cell) in line.iter().enumerate() {
-            if cell == '[' {
-                gps_sum += 100 * row + col; // Use the top-left corner of the box for GPS
-            }
+    for ((row, col), &cell) in transformed_grid.iter() {
+        if cell == '[' {
+            gps_sum += 100 * row + col; // Use the top-left corner of the box for GPS
         }
     }
-    
-    println!("Sum of GPS coordinates: {}", gps_sum);
-    Ok(())
-}
 
-fn transform_map(transformed_grid: Vec<Vec<char>>) -> Vec<Vec<char>> {
-    let mut new_transformed_grid = Vec::new();
-
-    for row in transformed_grid {
-        let mut new_row = Vec::new();
-        for cell in row {
-            match cell {
-                '#' => new_row.extend(vec!['#', '#']),
-                'O' => new_row.extend(vec!['[', ']']),
-                '.' => new_row.extend(vec!['.', '.']),
-                '@' => new_row.extend(vec!['@', '.']),
-                _ => {}
-            }
-        }
-        new_transformed_grid.push(new_row);
-    }
+    Ok(gps_sum)
+}
 
-    new_transformed_grid
+fn transform_map(map: &Grid<char>) -> Grid<char> {
+    let wide_lines = map.rows().map(|row| {
+        row.iter()
+            .map(|cell| match cell {
+                '#' => "##",
+                'O' => "[]",
+                '.' => "..",
+                '@' => "@.",
+                _ => "",
+            })
+            .collect::<String>()
+    });
+    Grid::parse(wide_lines, |c| c)
 }
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::get_input_for_puzzle;
     use crate::Puzzle;
+    use crate::testing::lines;
     /// Determines the day name (e.g., "Day01") based on the module path
     fn get_day_name() -> String {
         let module_path = module_path!(); // e.g., "puzzles::day01"
@@ -339,6 +301,34 @@ mod tests {
         }
     }
 
+    const SAMPLE: &str = "##########\n\
+                          #..O..O.O#\n\
+                          #......O.#\n\
+                          #.OO..O.O#\n\
+                          #..O@..O.#\n\
+                          #O#..O...#\n\
+                          #O..O..O.#\n\
+                          #.OO.O.OO#\n\
+                          #....O...#\n\
+                          ##########\n\n\
+                          <vv>^<v^>v>^vv^v>v<>v^v<v<^vv<<<^><<><>>v<vvv<>^v^>^<<<><<v<<<v^vv^v>^\n\
+                          vvv<<^>^v^^><<>>><>^<<><^vv^^<>vvv<>><^^v>^>vv<>v<<<<v<^v>^<^^>>>^<v<v\n\
+                          ><>vv>v^v^<>><>>>><^^>vv>v<^^^>>v^v^<^^>v^^>v^<^v>v<>>v^v^<v>v^^<^^vv<\n\
+                          <<v<^>>^^^^>>>v^<>vvv^><v<<<>^^^vv^<vvv>^>v<^^^^v<>^>vvvv><>>v^<<^^^^^\n\
+                          ^><^><>>><>^^<<^^v>>><^<v>^<vv>>v>>>^v><>^v><<<<v>>v<v<v>vvv>^<><<>^><\n\
+                          ^>><>^v<><^vvv<^^<><v<<<<<><^v<<<><<<^^<v<^^^><^>>^<v^><<<^>>^v<v^v<v^\n\
+                          >^>>^v>vv>^<<^v<>><<><<v<<v><>v<^vv<<<>^^v^>^^>>><<^v>>v^v><^^>>^<>vv^\n\
+                          <><^^>^^^<><vvvvv^v<v<<>^v<v>v<<^><<><<><<<^^<<<^<<>><<><^^^>^^<>^>v<>\n\
+                          ^^>vv<^v^v<vv>^<><v<^v>^^^>>>^^vvv^>vvv<>>>^<^>>>>>^<<^v>^vvv<>^<><<v>\n\
+                          v^^>>><<^^<>>^v^<v^vv<>v^<<>^<^v^v><^<<<><<^<v><v<>vv>>v><v^<vv<>v^<<^";
+
+    #[test]
+    fn sample_answers() {
+        let input = lines(SAMPLE);
+        assert_eq!(gps_sum(&input).unwrap(), 10092);
+        assert_eq!(wide_gps_sum(&input).unwrap(), 9021);
+    }
+
     // #[test]
     // fn test_solve_a_with_real_input() {
     //     let puzzle = get_puzzle('A');
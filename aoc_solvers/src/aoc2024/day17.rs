@@ -1,21 +1,19 @@
-use tokio::io;
+use crate::io;
+use crate::parse;
+
+/// Register A's starting value and the program, from the `Register A:` line at the top and
+/// the `Program:` line after the blank one.
+fn parse_input(lines: &[String]) -> io::Result<(i64, Vec<u8>)> {
+    let [registers, program] = parse::expect_sections(lines)?;
+    let (_, register_a) = registers.line(0)?.key_value(":")?;
+    let (_, program) = program.line(0)?.key_value(":")?;
+    Ok((register_a.parse()?, program.list(",")?))
+}
 
 pub async fn solve_a(lines: &Vec<String>) -> io::Result<()> {
     println!("Solving Day 17, Part A");
 
-    let program: Vec<u8> = lines
-        .iter()
-        .filter(|line| line.starts_with("Program:"))
-        .flat_map(|line| line["Program: ".len()..].split(','))
-        .map(|x| x.parse::<u8>().unwrap())
-        .collect();
-    let register_a = lines[0]
-        .split(':')
-        .nth(1)
-        .unwrap()
-        .trim()
-        .parse::<i64>()
-        .unwrap();
+    let (register_a, program) = parse_input(lines)?;
 
     let output = execute_program(register_a, &program);
     println!("Output: {:?}", output);
@@ -68,14 +66,18 @@ fn get_value(operand: u8, registers: &[i64; 3]) -> i64 {
 pub async fn solve_b(lines: &Vec<String>) -> io::Result<()> {
     println!("Solving Day 17, Part B");
 
-    let program: Vec<u8> = lines
-        .iter()
-        .filter(|line| line.starts_with("Program:"))
-        .flat_map(|line| line["Program: ".len()..].split(','))
-        .map(|x| x.parse::<u8>().unwrap())
-        .collect();
+    let (_, program) = parse_input(lines)?;
+
+    let result = self_printing_a(&program);
+
+    println!("Result: {:?}", result);
+
+    Ok(())
+}
 
-    let test = binary_search_length(&program);
+/// The lowest value of register A that makes the program print itself.
+fn self_printing_a(program: &Vec<u8>) -> i64 {
+    let test = binary_search_length(program);
     // println!("Test: {:?}", test);
     // let mut low: i64 = 135184372088838;
     // let mut hight: i64 = 185184372088838;
@@ -84,7 +86,7 @@ pub async fn solve_b(lines: &Vec<String>) -> io::Result<()> {
 
     let mut result = 0;
     for i in (0..program.len()).rev() {
-        let step = scan_and_reduce(low, hight, i, &program, 200);
+        let step = scan_and_reduce(low, hight, i, program, 200);
         // println!("Step: {:?}", step);
         if step.0 == step.1{
             result = step.0;
@@ -94,9 +96,7 @@ pub async fn solve_b(lines: &Vec<String>) -> io::Result<()> {
         hight = step.1;
     }
 
-    println!("Result: {:?}", result);
-
-    Ok(())
+    result
 }
 
 fn binary_search_length(program: &Vec<u8>) -> (i64, i64) {
@@ -184,6 +184,7 @@ mod tests {
     use super::*;
     use crate::get_input_for_puzzle;
     use crate::Puzzle;
+    use crate::testing::lines;
     /// Determines the day name (e.g., "Day01") based on the module path
     fn get_day_name() -> String {
         let module_path = module_path!(); // e.g., "puzzles::day01"
@@ -200,6 +201,23 @@ mod tests {
         }
     }
 
+    const SAMPLE_A: &str = "Register A: 729\n\
+                            Register B: 0\n\
+                            Register C: 0\n\n\
+                            Program: 0,1,5,4,3,0";
+    const SAMPLE_B: &str = "Register A: 2024\n\
+                            Register B: 0\n\
+                            Register C: 0\n\n\
+                            Program: 0,3,5,4,3,0";
+
+    #[test]
+    fn sample_answers() {
+        let (register_a, program) = parse_input(&lines(SAMPLE_A)).unwrap();
+        assert_eq!(execute_program(register_a, &program), [4, 6, 3, 5, 6, 3, 5, 2, 1, 0]);
+        let (_, program) = parse_input(&lines(SAMPLE_B)).unwrap();
+        assert_eq!(self_printing_a(&program), 117440);
+    }
+
     // #[test]
     // fn test_solve_a_with_real_input() {
     //     let puzzle = get_puzzle('A');
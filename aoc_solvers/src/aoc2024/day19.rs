@@ -1,4 +1,4 @@
-use tokio::io;
+use crate::io;
 use std::collections::{HashSet, HashMap};
 
 pub async fn solve_a(lines: &Vec<String>) -> io::Result<()> {
@@ -1,20 +1,17 @@
 use std::collections::HashMap;
-use tokio::io;
+use crate::geometry::Point2;
+use crate::io;
 
-#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
-struct Position {
-    row: i32,
-    col: i32,
-}
-impl Position {
-    fn new(row: i32, col: i32) -> Self {
-        Position { row, col }
-    }
-}
 
 pub async fn solve_a(lines: &Vec<String>) -> io::Result<()> {
     println!("Solving Day 21, Part A");
 
+    println!("Total Sum: {}", complexity_sum(lines));
+    Ok(())
+}
+
+/// Each code's shortest button presses through two robot keypads, times its number.
+fn complexity_sum(lines: &[String]) -> i64 {
     let (numeric_keypad_map, directional_keypad_map) = build_maps();
 
     let north_west_a = "^<A".to_string();
@@ -68,8 +65,7 @@ pub async fn solve_a(lines: &Vec<String>) -> io::Result<()> {
     let gentest = keypad_move(&"37".to_string(), &numeric_keypad_map, false);
     println!("{}: {}", gentest.len(), gentest);
 
-    println!("Total Sum: {}", total_sum);
-    Ok(())
+    total_sum
 }
 
 // "<^"
@@ -146,17 +142,17 @@ fn sort_movement(movement: &str, invert: bool) -> String {
     chars.iter().collect()
 }
 
-fn calculate_movement(start: Position, end: Position, is_directional: bool) -> String {
+fn calculate_movement(start: Point2, end: Point2, is_directional: bool) -> String {
     let mut movement = String::new();
 
-    let vertical_moves = end.row - start.row;
-    let horizontal_moves = end.col - start.col;
+    let vertical_moves = end.y - start.y;
+    let horizontal_moves = end.x - start.x;
 
     let mut test_pos = start;
     if horizontal_moves < 0 { // Moving left
-        test_pos.col += horizontal_moves;
+        test_pos.x += horizontal_moves;
         if is_directional {
-            if test_pos == Position::new(0, 0) {
+            if test_pos == Point2::new(0, 0) {
                 if vertical_moves < 0 {
                     movement.push_str(&"^".repeat(-vertical_moves as usize));
                 } else {
@@ -174,7 +170,7 @@ fn calculate_movement(start: Position, end: Position, is_directional: bool) -> S
             }
         } else {
             println!("vertical moves: {}", vertical_moves);
-            if test_pos == Position::new(3, 0) {
+            if test_pos == Point2::new(0, 3) {
                 if vertical_moves < 0 {
                     movement.push_str(&"^".repeat(-vertical_moves as usize));
                 } else {
@@ -191,9 +187,9 @@ fn calculate_movement(start: Position, end: Position, is_directional: bool) -> S
             }
         }
     } else if vertical_moves < 0 { 
-        test_pos.row += vertical_moves;
+        test_pos.y += vertical_moves;
         if is_directional {
-            if test_pos == Position::new(0, 0) {
+            if test_pos == Point2::new(0, 0) {
                 if horizontal_moves < 0 {
                     movement.push_str(&"<".repeat(-horizontal_moves as usize));
                 } else {
@@ -217,7 +213,7 @@ fn calculate_movement(start: Position, end: Position, is_directional: bool) -> S
             }            
         }
     } else if vertical_moves > 0 { 
-        test_pos.row += vertical_moves;
+        test_pos.y += vertical_moves;
         if is_directional {
             movement.push_str(&"v".repeat(vertical_moves as usize));
             if horizontal_moves < 0 {
@@ -226,7 +222,7 @@ fn calculate_movement(start: Position, end: Position, is_directional: bool) -> S
                 movement.push_str(&">".repeat(horizontal_moves as usize));
             }
         } else {
-            if test_pos == Position::new(3, 0) {
+            if test_pos == Point2::new(0, 3) {
                 if horizontal_moves < 0 {
                     movement.push_str(&"<".repeat(-horizontal_moves as usize));
                 } else {
@@ -272,9 +268,8 @@ pub fn build_maps() -> (HashMap<(char, char), String>, HashMap<(char, char), Str
                     for (end_col, &end_key) in end_row_vec.iter().enumerate() {
                         if let Some(end_key_literal) = end_key {
                             if start_key_literal != end_key_literal {
-                                let start_position =
-                                    Position::new(start_row as i32, start_col as i32);
-                                let end_position = Position::new(end_row as i32, end_col as i32);
+                                let start_position = Point2::from_grid((start_row, start_col));
+                                let end_position = Point2::from_grid((end_row, end_col));
                                 let movement =
                                     calculate_movement(start_position, end_position, false);
                                 numeric_keypad_map
@@ -296,9 +291,8 @@ pub fn build_maps() -> (HashMap<(char, char), String>, HashMap<(char, char), Str
                     for (end_col, &end_key) in end_row_vec.iter().enumerate() {
                         if let Some(end_key_literal) = end_key {
                             if start_key_literal != end_key_literal {
-                                let start_position =
-                                    Position::new(start_row as i32, start_col as i32);
-                                let end_position = Position::new(end_row as i32, end_col as i32);
+                                let start_position = Point2::from_grid((start_row, start_col));
+                                let end_position = Point2::from_grid((end_row, end_col));
                                 let movement =
                                     calculate_movement(start_position, end_position, true);
                                 directional_keypad_map
@@ -404,6 +398,7 @@ mod tests {
     use super::*;
     use crate::get_input_for_puzzle;
     use crate::Puzzle;
+    use crate::testing::lines;
     fn get_day_name() -> String {
         let module_path = module_path!();
         let module_name = module_path.split("::").last().unwrap_or("Unknown");
@@ -419,6 +414,17 @@ mod tests {
         }
     }
 
+    const SAMPLE: &str = "029A\n\
+                          980A\n\
+                          179A\n\
+                          456A\n\
+                          379A";
+
+    #[test]
+    fn sample_answers() {
+        assert_eq!(complexity_sum(&lines(SAMPLE)), 126384);
+    }
+
     // #[test]
     // fn test_solve_a_with_real_input() {
     //     let puzzle = get_puzzle('A');
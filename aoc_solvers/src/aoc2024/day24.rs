@@ -1,4 +1,4 @@
-use tokio::io;
+use crate::io;
 use std::collections::{HashMap, HashSet};
 
 #[derive(Debug, PartialEq, Eq, Hash)]
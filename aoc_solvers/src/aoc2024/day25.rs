@@ -1,10 +1,23 @@
-use tokio::io;
+use crate::io;
+use crate::parse;
 use std::error::Error;
 
 pub async fn solve_a(lines: &Vec<String>) -> Result<(), Box<dyn Error>> {
     println!("Solving Day 25, Part A");
 
-    let schematics = split_schematics(lines)?;
+    let valid_pairs = fitting_pairs(lines);
+
+    println!(
+        "Number of unique lock/key pairs that fit: {}",
+        valid_pairs
+    );
+
+    Ok(())
+}
+
+/// How many lock and key pairs don't overlap in any column.
+fn fitting_pairs(lines: &[String]) -> usize {
+    let schematics = parse::sections(lines);
 
     let mut locks = Vec::new();
     let mut keys = Vec::new();
@@ -43,34 +56,7 @@ pub async fn solve_a(lines: &Vec<String>) -> Result<(), Box<dyn Error>> {
         }
     }
 
-    println!(
-        "Number of unique lock/key pairs that fit: {}",
-        valid_pairs
-    );
-
-    Ok(())
-}
-
-fn split_schematics(lines: &Vec<String>) -> Result<Vec<Vec<String>>, Box<dyn Error>> {
-    let mut schematics = Vec::new();
-    let mut current = Vec::new();
-
-    for line in lines {
-        if line.trim().is_empty() {
-            if !current.is_empty() {
-                schematics.push(current.clone());
-                current.clear();
-            }
-        } else {
-            current.push(line.clone());
-        }
-    }
-
-    if !current.is_empty() {
-        schematics.push(current);
-    }
-
-    Ok(schematics)
+    valid_pairs
 }
 
 fn parse_lock_heights(schematic: &[String]) -> Vec<usize> {
@@ -128,6 +114,7 @@ mod tests {
     use super::*;
     use crate::get_input_for_puzzle;
     use crate::Puzzle;
+    use crate::testing::lines;
     fn get_day_name() -> String {
         let module_path = module_path!();
         let module_name = module_path.split("::").last().unwrap_or("Unknown");
@@ -143,6 +130,47 @@ mod tests {
         }
     }
 
+    const SAMPLE: &str = "#####\n\
+                          .####\n\
+                          .####\n\
+                          .####\n\
+                          .#.#.\n\
+                          .#...\n\
+                          .....\n\n\
+                          #####\n\
+                          ##.##\n\
+                          .#.##\n\
+                          ...##\n\
+                          ...#.\n\
+                          ...#.\n\
+                          .....\n\n\
+                          .....\n\
+                          #....\n\
+                          #....\n\
+                          #...#\n\
+                          #.#.#\n\
+                          #.###\n\
+                          #####\n\n\
+                          .....\n\
+                          .....\n\
+                          #.#..\n\
+                          ###..\n\
+                          ###.#\n\
+                          ###.#\n\
+                          #####\n\n\
+                          .....\n\
+                          .....\n\
+                          .....\n\
+                          #....\n\
+                          #.#..\n\
+                          #.#.#\n\
+                          #####";
+
+    #[test]
+    fn sample_answers() {
+        assert_eq!(fitting_pairs(&lines(SAMPLE)), 3);
+    }
+
     // #[test]
     // fn test_solve_a_with_real_input() {
     //     let puzzle = get_puzzle('A');
@@ -1,4 +1,4 @@
-use tokio::io;
+use crate::io;
 
 pub async fn solve_a(lines: &Vec<String>) -> io::Result<()> {
     println!("Solving Day 21, Part A");
@@ -0,0 +1,50 @@
+#![allow(dead_code)]
+#[cfg(feature = "std")]
+pub mod day01;
+#[cfg(feature = "std")]
+pub mod day02;
+#[cfg(feature = "std")]
+pub mod day03;
+#[cfg(feature = "std")]
+pub mod day04;
+#[cfg(feature = "std")]
+pub mod day05;
+#[cfg(feature = "std")]
+pub mod day06;
+pub mod day07;
+#[cfg(feature = "std")]
+pub mod day08;
+#[cfg(feature = "std")]
+pub mod day09;
+#[cfg(any(feature = "std", feature = "fixed"))]
+pub mod day10;
+#[cfg(feature = "std")]
+pub mod day11;
+#[cfg(feature = "std")]
+pub mod day12;  
+#[cfg(feature = "std")]
+pub mod day13;
+#[cfg(feature = "std")]
+pub mod day14;
+#[cfg(feature = "std")]
+pub mod day15;
+#[cfg(feature = "std")]
+pub mod day16;
+#[cfg(feature = "std")]
+pub mod day17;
+#[cfg(any(feature = "std", feature = "fixed"))]
+pub mod day18;
+#[cfg(feature = "std")]
+pub mod day19;
+#[cfg(feature = "std")]
+pub mod day20;
+#[cfg(feature = "std")]
+pub mod day21;
+#[cfg(feature = "std")]
+pub mod day22;
+#[cfg(feature = "std")]
+pub mod day23;
+#[cfg(feature = "std")]
+pub mod day24;
+#[cfg(feature = "std")]
+pub mod day25;
\ No newline at end of file
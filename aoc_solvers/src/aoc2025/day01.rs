@@ -1,14 +1,17 @@
-use tokio::io;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use crate::io;
 use crate::solver::DaySolver;
+use crate::stream::{LineSolver, solve_lines};
 
 pub struct Day;
 
 impl DaySolver for Day {
-    async fn solve_a(&self, lines: &Vec<String>) -> io::Result<()> {
+    async fn solve_a(&self, lines: &Vec<String>) -> io::Result<String> {
         solve_a(lines).await
     }
 
-    async fn solve_b(&self, lines: &Vec<String>) -> io::Result<()> {
+    async fn solve_b(&self, lines: &Vec<String>) -> io::Result<String> {
         solve_b(lines).await
     }
 
@@ -22,57 +25,93 @@ impl DaySolver for Day {
 }
 
 
-pub async fn solve_a(lines: &Vec<String>) -> io::Result<()> {
+pub async fn solve_a(lines: &Vec<String>) -> io::Result<String> {
     println!("Solving Day 1, Part A");
-    let mut position = 50;
-    let mut result = 0;
+    solve_lines(&mut PartA::default(), lines.iter().map(String::as_str))
+}
+
+pub async fn solve_b(lines: &Vec<String>) -> io::Result<String> {
+    println!("Solving Day 1, Part B");
+    solve_lines(&mut PartB::default(), lines.iter().map(String::as_str))
+}
+
+pub struct PartA {
+    position: i32,
+    result: i32,
+}
+
+impl Default for PartA {
+    fn default() -> Self {
+        PartA {
+            position: 50,
+            result: 0,
+        }
+    }
+}
+
+impl LineSolver for PartA {
     //mod result by 100 for each rotation to see if % 100 == 0
-    for line in lines {
+    fn line(&mut self, line: &str) -> io::Result<()> {
         //parse line L14 or R12 where L should decrement and right should increment
         let (turn, dist) = line.split_at(1);
         let dist: i32 = dist.parse().unwrap();
         match turn {
             "L" => {
-                position -= dist;
+                self.position -= dist;
             }
             "R" => {
-                position += dist;
+                self.position += dist;
             }
             _ => {
             }
         }
-        if position % 100 == 0 {
-            result += 1;
+        if self.position % 100 == 0 {
+            self.result += 1;
         }
+        Ok(())
     }
-    println!("Final position: {}, Result: {}", position, result);
 
-    Ok(())
+    fn finish(&mut self) -> io::Result<String> {
+        println!("Final position: {}, Result: {}", self.position, self.result);
+        Ok(self.result.to_string())
+    }
 }
 
-pub async fn solve_b(lines: &Vec<String>) -> io::Result<()> {
-    println!("Solving Day 1, Part B");
-    let mut position = 50;
-    let mut last_position = 50;
-    let mut result = 0;
-    for line in lines {
+pub struct PartB {
+    position: i32,
+    result: i32,
+}
+
+impl Default for PartB {
+    fn default() -> Self {
+        PartB {
+            position: 50,
+            result: 0,
+        }
+    }
+}
+
+impl LineSolver for PartB {
+    fn line(&mut self, line: &str) -> io::Result<()> {
         let (turn, dist) = line.split_at(1);
         let dist: i32 = dist.parse().unwrap();
+        let last_position = self.position;
+        let mut position = last_position;
         match turn {
             "L" => {
                 position -= dist;
                 let last_hundreds = last_position / 100;
                 let current_hundreds = position / 100;
                 if last_hundreds == current_hundreds && position < 0 && last_position != 0 {
-                    result += 1;
+                    self.result += 1;
                 }
                 if last_hundreds != current_hundreds {
                     let extra = if last_position!=0 { 1 } else { 0 };
                     let diff = (current_hundreds - last_hundreds).abs() + extra;
-                    result += diff;
+                    self.result += diff;
                 }
                 if position == 0 {
-                    result += 1;
+                    self.result += 1;
                 }
             }
             "R" => {
@@ -82,7 +121,7 @@ pub async fn solve_b(lines: &Vec<String>) -> io::Result<()> {
                 let current_hundreds = position / 100;
                 if last_hundreds != current_hundreds {
                     let diff = (current_hundreds - last_hundreds).abs();
-                    result += diff;
+                    self.result += diff;
                 }
             }
             _ => {
@@ -93,12 +132,14 @@ pub async fn solve_b(lines: &Vec<String>) -> io::Result<()> {
         if position < 0 {
             position += 100;
         }
-        last_position = position;
+        self.position = position;
+        Ok(())
     }
 
-    println!("Final position: {}, Result: {}", position, result);
-
-    Ok(())
+    fn finish(&mut self) -> io::Result<String> {
+        println!("Final position: {}, Result: {}", self.position, self.result);
+        Ok(self.result.to_string())
+    }
 }
 
 #[cfg(test)]
@@ -0,0 +1,131 @@
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use crate::io;
+use crate::solver::DaySolver;
+use crate::stream::{LineSolver, solve_lines};
+
+pub struct Day;
+
+impl DaySolver for Day {
+    fn get_day(&self) -> u8 {
+        2
+    }
+    fn get_year(&self) -> u16 {
+        2025
+    }
+    async fn solve_a(&self, lines: &Vec<String>) -> io::Result<String> {
+        super::day02::solve_a(lines).await
+    }
+    async fn solve_b(&self, lines: &Vec<String>) -> io::Result<String> {
+        super::day02::solve_b(lines).await
+    }
+}
+
+pub async fn solve_a(lines: &Vec<String>) -> io::Result<String> {
+    println!("Solving Day 2, Part A");
+    solve_lines(&mut PartA::default(), lines.iter().map(String::as_str))
+}
+
+pub async fn solve_b(lines: &Vec<String>) -> io::Result<String> {
+    println!("Solving Day 2, Part B");
+    solve_lines(&mut PartB::default(), lines.iter().map(String::as_str))
+}
+
+/// The ranges are all on one line, so a blank one (a trailing newline) is skipped.
+fn ranges(line: &str) -> impl Iterator<Item = (i64, i64)> + '_ {
+    line.split(',')
+        .map(str::trim)
+        .filter(|range| !range.is_empty())
+        .map(|range| {
+            //parge range into lower and upper i64 via '-'
+            let bounds: Vec<&str> = range.split('-').collect();
+            (bounds[0].parse().unwrap(), bounds[1].parse().unwrap())
+        })
+}
+
+#[derive(Default)]
+pub struct PartA {
+    result: i64,
+}
+
+impl LineSolver for PartA {
+    fn line(&mut self, line: &str) -> io::Result<()> {
+        for (lower, upper) in ranges(line) {
+            for num in lower..=upper {
+                let num_as_string = num.to_string();
+                if num_as_string.len() % 2 != 0 {
+                    continue;
+                }
+                let first_half = &num_as_string[..&num_as_string.len() / 2];
+                let second_half = &num_as_string[&num_as_string.len() / 2..];
+                if first_half == second_half {
+                    self.result += num;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn finish(&mut self) -> io::Result<String> {
+        println!("Result: {}", self.result);
+        Ok(self.result.to_string())
+    }
+}
+
+#[derive(Default)]
+pub struct PartB {
+    result: i64,
+}
+
+impl LineSolver for PartB {
+    fn line(&mut self, line: &str) -> io::Result<()> {
+        for (lower, upper) in ranges(line) {
+            for num in lower..=upper {
+                let num_as_string = num.to_string();
+                for len in 1..=num_as_string.len() / 2 {
+                    let mut pattern_found = true;
+                    let pattern = &num_as_string[..len];
+                    let mut index = len;
+                    while index + len <= num_as_string.len() {
+                        if &num_as_string[index..index + len] != pattern {
+                            pattern_found = false;
+                            break;
+                        }
+                        index += len;
+                    }
+                    if pattern_found && index == num_as_string.len() {
+                        self.result += num;
+                        break;
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn finish(&mut self) -> io::Result<String> {
+        println!("Result: {}", self.result);
+        Ok(self.result.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::get_input_for_puzzle;
+    use crate::Puzzle;
+    fn get_day_name() -> String {
+        let module_path = module_path!();
+        let module_name = module_path.split("::").last().unwrap_or("Unknown");
+        module_name.to_string().replace("day", "Day")
+    }
+
+    fn get_puzzle(part: char) -> Puzzle {
+        let day = get_day_name().replace("Day", "").parse::<u8>().unwrap_or(1);
+        match part {
+            'A' => Puzzle::from_day_part(day, 'A'),
+            'B' => Puzzle::from_day_part(day, 'B'),
+            _ => panic!("Invalid part"),
+        }
+    }
+}
@@ -1,14 +1,17 @@
-use tokio::io;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use crate::io;
 use crate::solver::DaySolver;
+use crate::stream::{LineSolver, solve_lines};
 
 pub struct Day;
 
 impl DaySolver for Day {
-    async fn solve_a(&self, lines: &Vec<String>) -> io::Result<()> {
+    async fn solve_a(&self, lines: &Vec<String>) -> io::Result<String> {
         solve_a(lines).await
     }
 
-    async fn solve_b(&self, lines: &Vec<String>) -> io::Result<()> {
+    async fn solve_b(&self, lines: &Vec<String>) -> io::Result<String> {
         solve_b(lines).await
     }
 
@@ -21,11 +24,25 @@ impl DaySolver for Day {
     }
 }
 
-pub async fn solve_a(lines: &Vec<String>) -> io::Result<()> {
+pub async fn solve_a(lines: &Vec<String>) -> io::Result<String> {
     println!("Solving Day 3, Part A");
-    let mut stack: Vec<u32> = Vec::new();
-    let mut result: i64 = 0;
-    for line in lines{
+    solve_lines(&mut PartA::default(), lines.iter().map(String::as_str))
+}
+
+pub async fn solve_b(lines: &Vec<String>) -> io::Result<String> {
+    println!("Solving Day 3, Part B");
+    solve_lines(&mut PartB::default(), lines.iter().map(String::as_str))
+}
+
+#[derive(Default)]
+pub struct PartA {
+    stack: Vec<u32>,
+    result: i64,
+}
+
+impl LineSolver for PartA {
+    fn line(&mut self, line: &str) -> io::Result<()> {
+        let stack = &mut self.stack;
         for i in 0..line.len()-1{        
             if let Some(digit) = line.chars().nth(i).and_then(|c| c.to_digit(10)) {
                 while !stack.is_empty() && *stack.last().unwrap() < digit {
@@ -50,17 +67,25 @@ pub async fn solve_a(lines: &Vec<String>) -> io::Result<()> {
         while !stack.is_empty() {
             current.insert_str(0, &stack.pop().unwrap().to_string());
         }
-        result += current.parse::<i64>().unwrap();
+        self.result += current.parse::<i64>().unwrap();
+        Ok(())
+    }
+
+    fn finish(&mut self) -> io::Result<String> {
+        println!("Result: {}", self.result);
+        Ok(self.result.to_string())
     }
-    println!("Result: {}", result);
-    Ok(())
 }
 
-pub async fn solve_b(lines: &Vec<String>) -> io::Result<()> {
-    println!("Solving Day 3, Part B");
-    let mut stack: Vec<u32> = Vec::new();
-    let mut result: i64 = 0;
-    for line in lines{
+#[derive(Default)]
+pub struct PartB {
+    stack: Vec<u32>,
+    result: i64,
+}
+
+impl LineSolver for PartB {
+    fn line(&mut self, line: &str) -> io::Result<()> {
+        let stack = &mut self.stack;
         for i in 0..line.len(){        
             if let Some(digit) = line.chars().nth(i).and_then(|c| c.to_digit(10)) {
                 while !stack.is_empty() && *stack.last().unwrap() < digit && stack.len()+line.len()-i >12 {
@@ -78,10 +103,14 @@ pub async fn solve_b(lines: &Vec<String>) -> io::Result<()> {
         while !stack.is_empty() {
             current.insert_str(0, &stack.pop().unwrap().to_string());
         }
-        result += current.parse::<i64>().unwrap();
+        self.result += current.parse::<i64>().unwrap();
+        Ok(())
+    }
+
+    fn finish(&mut self) -> io::Result<String> {
+        println!("Result: {}", self.result);
+        Ok(self.result.to_string())
     }
-    println!("Result: {}", result);
-    Ok(())
 }
 
 #[cfg(test)]
@@ -0,0 +1,132 @@
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use crate::byte_lines::ByteLines;
+use crate::io;
+use crate::solver::DaySolver;
+#[cfg(feature = "fixed")]
+use crate::fixed::{BoundedVec, FixedGrid};
+
+// Inputs are a bit under 140 square, so a cell fits in a pair of bytes.
+#[cfg(feature = "fixed")]
+type Grid<T> = FixedGrid<T, { 144 * 144 }>;
+#[cfg(not(feature = "fixed"))]
+type Grid<T> = crate::grid::Grid<T>;
+#[cfg(feature = "fixed")]
+type Stack = BoundedVec<(u8, u8), { 144 * 144 }>;
+#[cfg(not(feature = "fixed"))]
+type Stack = Vec<(u8, u8)>;
+
+pub struct Day;
+
+impl DaySolver for Day {
+    async fn solve_a(&self, lines: &Vec<String>) -> io::Result<String> {
+        solve_a(&ByteLines::new(lines.join("\n").as_bytes())?).await
+    }
+
+    async fn solve_b(&self, lines: &Vec<String>) -> io::Result<String> {
+        solve_b(&ByteLines::new(lines.join("\n").as_bytes())?).await
+    }
+
+    async fn solve_a_bytes(&self, input: &ByteLines<'_>) -> io::Result<String> {
+        solve_a(input).await
+    }
+
+    async fn solve_b_bytes(&self, input: &ByteLines<'_>) -> io::Result<String> {
+        solve_b(input).await
+    }
+
+    fn get_day(&self) -> u8{
+        4
+    }
+
+    fn get_year(&self) -> u16 {
+        2025
+    }
+}
+
+pub async fn solve_a(input: &ByteLines<'_>) -> io::Result<String> {
+    println!("Solving Day 4, Part A");
+    let rolls: Grid<bool> = Grid::from_bytes(input, |c| c == b'@');
+    let mut result = 0;
+    for (pos, &roll) in rolls.iter() {
+        if roll && rolls.neighbours8(pos).filter(|&around| rolls[around]).count() < 4 {
+            result += 1;
+        }
+    }
+    println!("Result is {}", result);
+    Ok(result.to_string())
+}
+
+pub async fn solve_b(input: &ByteLines<'_>) -> io::Result<String> {
+    println!("Solving Day 4, Part B");
+    let mut rolls: Grid<bool> = Grid::from_bytes(input, |c| c == b'@');
+    let mut counts: Grid<u8> = Grid::from_fn(rolls.width(), rolls.height(), |pos| {
+        rolls.neighbours8(pos).filter(|&around| rolls[around]).count() as u8
+    });
+
+    // A roll goes on the stack once: at the start if it is already loose, otherwise when its
+    // count drops from 4 to 3. Removing rolls in any order ends up in the same place.
+    let mut stack = Stack::new();
+    for ((i, j), &roll) in rolls.iter() {
+        if roll && counts[i][j] < 4 {
+            stack.push((i as u8, j as u8));
+        }
+    }
+    let mut result = 0;
+    while let Some((i, j)) = stack.pop() {
+        let (i, j) = (i as usize, j as usize);
+        rolls[i][j] = false;
+        result += 1;
+        for (ni, nj) in rolls.neighbours8((i, j)) {
+            if rolls[ni][nj] {
+                counts[ni][nj] -= 1;
+                if counts[ni][nj] == 3 {
+                    stack.push((ni as u8, nj as u8));
+                }
+            }
+        }
+    }
+
+    println!("Result is {}", result);
+    Ok(result.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::get_input_for_puzzle;
+    use crate::Puzzle;
+    use crate::testing::block_on;
+    fn get_day_name() -> String {
+        let module_path = module_path!();
+        let module_name = module_path.split("::").last().unwrap_or("Unknown");
+        module_name.to_string().replace("day", "Day")
+    }
+
+    fn get_puzzle(part: char) -> Puzzle {
+        let day = get_day_name().replace("Day", "").parse::<u8>().unwrap_or(1);
+        match part {
+            'A' => Puzzle::from_day_part(day, 'A'),
+            'B' => Puzzle::from_day_part(day, 'B'),
+            _ => panic!("Invalid part"),
+        }
+    }
+
+    const SAMPLE: &str = "..@@.@@@@.\n\
+                          @@@.@.@.@@\n\
+                          @@@@@.@.@@\n\
+                          @.@@@@..@.\n\
+                          @@.@@@@.@@\n\
+                          .@@@@@@@.@\n\
+                          .@.@.@.@@@\n\
+                          @.@@@.@@@@\n\
+                          .@@@@@@@@.\n\
+                          @.@.@@@.@.";
+
+    #[test]
+    fn sample_answers() {
+        let input = ByteLines::new(SAMPLE.as_bytes()).unwrap();
+        assert_eq!(block_on(solve_a(&input)).unwrap(), "13");
+        assert_eq!(block_on(solve_b(&input)).unwrap(), "43");
+    }
+}
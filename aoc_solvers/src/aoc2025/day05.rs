@@ -0,0 +1,98 @@
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use crate::interval_set::IntervalSet;
+use crate::io;
+use crate::parse::{self, Section};
+use crate::solver::DaySolver;
+
+pub struct Day;
+
+impl DaySolver for Day {
+    async fn solve_a(&self, lines: &Vec<String>) -> io::Result<String> {
+        solve_a(lines).await
+    }
+
+    async fn solve_b(&self, lines: &Vec<String>) -> io::Result<String> {
+        solve_b(lines).await
+    }
+
+    fn get_day(&self) -> u8{
+        5
+    }
+
+    fn get_year(&self) -> u16 {
+        2025
+    }
+}
+
+/// The fresh ingredient ranges before the blank line, and the rest of the lines after them.
+fn fresh_ranges(lines: &[String]) -> io::Result<(IntervalSet<u64>, Section<'_>)> {
+    let [ranges, ids] = parse::expect_sections(lines)?;
+    let mut fresh = IntervalSet::new();
+    for range in ranges.lines() {
+        let (start, end) = range.pair('-')?;
+        fresh.insert(start..=end);
+    }
+    Ok((fresh, ids))
+}
+
+pub async fn solve_a(lines: &Vec<String>) -> io::Result<String> {
+    println!("Solving Day {}, Part A", Day.get_day());
+    let (fresh, ids) = fresh_ranges(lines)?;
+    let mut result: u32 = 0;
+    for id in ids.lines() {
+        if fresh.contains(id.parse()?) {
+            result += 1;
+        }
+    }
+    println!("Result: {}", result);
+    Ok(result.to_string())
+}
+
+pub async fn solve_b(lines: &Vec<String>) -> io::Result<String> {
+    println!("Solving Day {}, Part B", Day.get_day());
+    let (fresh, _) = fresh_ranges(lines)?;
+    let result = fresh.total_len();
+    println!("Result: {}", result);
+    Ok(result.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::get_input_for_puzzle;
+    use crate::Puzzle;
+    use crate::testing::{block_on, lines};
+    fn get_day_name() -> String {
+        let module_path = module_path!();
+        let module_name = module_path.split("::").last().unwrap_or("Unknown");
+        module_name.to_string().replace("day", "Day")
+    }
+
+    fn get_puzzle(part: char) -> Puzzle {
+        let day = get_day_name().replace("Day", "").parse::<u8>().unwrap_or(1);
+        match part {
+            'A' => Puzzle::from_day_part(day, 'A'),
+            'B' => Puzzle::from_day_part(day, 'B'),
+            _ => panic!("Invalid part"),
+        }
+    }
+
+    const SAMPLE: &str = "3-5\n\
+                          10-14\n\
+                          16-20\n\
+                          12-18\n\n\
+                          1\n\
+                          5\n\
+                          8\n\
+                          11\n\
+                          17\n\
+                          32";
+
+    #[test]
+    fn sample_answers() {
+        let input = lines(SAMPLE);
+        assert_eq!(block_on(solve_a(&input)).unwrap(), "3");
+        assert_eq!(block_on(solve_b(&input)).unwrap(), "14");
+    }
+}
@@ -1,14 +1,16 @@
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
 use crate::solver::DaySolver;
-use tokio::io;
+use crate::io;
 
 pub struct Day;
 
 impl DaySolver for Day {
-    async fn solve_a(&self, lines: &Vec<String>) -> io::Result<()> {
+    async fn solve_a(&self, lines: &Vec<String>) -> io::Result<String> {
         solve_a(lines).await
     }
 
-    async fn solve_b(&self, lines: &Vec<String>) -> io::Result<()> {
+    async fn solve_b(&self, lines: &Vec<String>) -> io::Result<String> {
         solve_b(lines).await
     }
 
@@ -21,7 +23,7 @@ impl DaySolver for Day {
     }
 }
 
-pub async fn solve_a(lines: &Vec<String>) -> io::Result<()> {
+pub async fn solve_a(lines: &Vec<String>) -> io::Result<String> {
     println!("Solving Day {}, Part A", Day.get_day());
     let ops_line = lines.last().unwrap().as_bytes();
     let mut ops = Vec::with_capacity(
@@ -70,11 +72,11 @@ pub async fn solve_a(lines: &Vec<String>) -> io::Result<()> {
         }
     }
     println!("Result: {}", result);
-    Ok(())
+    Ok(result.to_string())
 }
 
 
-pub async fn solve_b(lines: &Vec<String>) -> io::Result<()> {
+pub async fn solve_b(lines: &Vec<String>) -> io::Result<String> {
     println!("Solving Day {}, Part B", Day.get_day());
 
     let ops_line = lines.last().unwrap().as_bytes();
@@ -139,7 +141,7 @@ pub async fn solve_b(lines: &Vec<String>) -> io::Result<()> {
         }
     }
     println!("Result: {}", result + sub_result);
-    Ok(())
+    Ok((result + sub_result).to_string())
 }
 
 #[cfg(test)]
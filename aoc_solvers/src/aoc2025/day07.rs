@@ -0,0 +1,178 @@
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use crate::byte_lines::ByteLines;
+use crate::solver::DaySolver;
+#[cfg(not(feature = "fixed"))]
+use std::collections::HashMap;
+use crate::io;
+#[cfg(feature = "fixed")]
+use crate::fixed::{BoundedMap, FixedGrid};
+
+// The manifold is 141 wide and 142 tall, with well under 2000 splitters.
+#[cfg(feature = "fixed")]
+type Grid = FixedGrid<u8, { 144 * 144 }>;
+#[cfg(not(feature = "fixed"))]
+type Grid = crate::grid::Grid<u8>;
+#[cfg(feature = "fixed")]
+type Timelines = BoundedMap<(usize, usize), u64, 4096>;
+#[cfg(not(feature = "fixed"))]
+type Timelines = HashMap<(usize, usize), u64>;
+
+pub struct Day;
+
+impl DaySolver for Day {
+    async fn solve_a(&self, lines: &Vec<String>) -> io::Result<String> {
+        solve_a(&ByteLines::new(lines.join("\n").as_bytes())?).await
+    }
+
+    async fn solve_b(&self, lines: &Vec<String>) -> io::Result<String> {
+        solve_b(&ByteLines::new(lines.join("\n").as_bytes())?).await
+    }
+
+    async fn solve_a_bytes(&self, input: &ByteLines<'_>) -> io::Result<String> {
+        solve_a(input).await
+    }
+
+    async fn solve_b_bytes(&self, input: &ByteLines<'_>) -> io::Result<String> {
+        solve_b(input).await
+    }
+
+    fn get_day(&self) -> u8 {
+        7
+    }
+
+    fn get_year(&self) -> u16 {
+        2025
+    }
+}
+
+pub async fn solve_a(input: &ByteLines<'_>) -> io::Result<String> {
+    println!("Solving Day {}, Part A", Day.get_day());
+    let mut grid = Grid::from_bytes(input, |c| c);
+    let mut result: u32 = 0;
+    for i in 0..grid.height() - 1 {
+        for j in 0..grid.width() {
+            match grid[i][j] {
+                b'S' => {
+                    grid[i + 1][j] = b'|';
+                }
+                b'|' => {
+                    if grid[i + 1][j] == b'^' {
+                        result += 1;
+                        if j > 0 {
+                            grid[i + 1][j - 1] = b'|';
+                        }
+                        if j < grid.width() - 1 {
+                            grid[i + 1][j + 1] = b'|';
+                        }
+                    } else {
+                        grid[i + 1][j] = b'|';
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+    println!("Result: {}", result);
+    Ok(result.to_string())
+}
+
+pub async fn solve_b(input: &ByteLines<'_>) -> io::Result<String> {
+    println!("Solving Day {}, Part B", Day.get_day());
+    let mut result: u64 = 0;
+    let mut map = Timelines::new();
+    let mut start_x = 0;
+    let start_y = input[0].iter().position(|&c| c == b'S').unwrap();
+    loop {
+        if input.at(start_x, start_y) == b'^' {
+            map.insert((start_x, start_y), 1);
+            break;
+        }
+        start_x += 1;
+    }
+    for x in start_x..input.len() {
+        for y in 0..input.width() {
+            if !map.contains_key(&(x, y)) {
+                continue;
+            }
+            let currnt_value = *map.get(&(x, y)).unwrap();
+            if y > 0 {
+                let mut nx = x + 1;
+                loop {
+                    if input.at(nx, y - 1) == b'^' {
+                        let entry = map.entry((nx, y - 1)).or_insert(0);
+                        *entry += currnt_value;
+                        break;
+                    } else if nx + 1 >= input.len() {
+                        result += currnt_value as u64;
+                        break;
+                    }
+                    nx += 1;
+                }
+            }
+            if y + 1 < input.width() {
+                let mut nx = x + 1;
+                loop {
+                    if input.at(nx, y + 1) == b'^' {
+                        let entry = map.entry((nx, y + 1)).or_insert(0);
+                        *entry += currnt_value;
+                        break;
+                    } else if nx + 1 >= input.len() {
+                        result += currnt_value as u64;
+                        break;
+                    }
+                    nx += 1;
+                }
+            }
+        }
+    }
+
+    println!("Result: {}", result);
+    Ok(result.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::get_input_for_puzzle;
+    use crate::Puzzle;
+    use crate::testing::block_on;
+    fn get_day_name() -> String {
+        let module_path = module_path!();
+        let module_name = module_path.split("::").last().unwrap_or("Unknown");
+        module_name.to_string().replace("day", "Day")
+    }
+
+    fn get_puzzle(part: char) -> Puzzle {
+        let day = get_day_name().replace("Day", "").parse::<u8>().unwrap_or(1);
+        match part {
+            'A' => Puzzle::from_day_part(day, 'A'),
+            'B' => Puzzle::from_day_part(day, 'B'),
+            _ => panic!("Invalid part"),
+        }
+    }
+
+    const SAMPLE: &str = ".......S.......\n\
+                          ...............\n\
+                          .......^.......\n\
+                          ...............\n\
+                          ......^.^......\n\
+                          ...............\n\
+                          .....^.^.^.....\n\
+                          ...............\n\
+                          ....^.^...^....\n\
+                          ...............\n\
+                          ...^.^...^.^...\n\
+                          ...............\n\
+                          ..^...^.....^..\n\
+                          ...............\n\
+                          .^.^.^.^.^...^.\n\
+                          ...............";
+
+    #[test]
+    fn sample_answers() {
+        let input = ByteLines::new(SAMPLE.as_bytes()).unwrap();
+        assert_eq!(block_on(solve_a(&input)).unwrap(), "21");
+        assert_eq!(block_on(solve_b(&input)).unwrap(), "40");
+    }
+}
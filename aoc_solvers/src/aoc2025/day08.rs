@@ -1,16 +1,16 @@
 use crate::solver::DaySolver;
-use tokio::io;
+use crate::io;
+use crate::disjoint_set::DisjointSet;
 use std::collections::BinaryHeap;
-use std::collections::HashMap;
 
 pub struct Day;
 
 impl DaySolver for Day {
-    async fn solve_a(&self, lines: &Vec<String>) -> io::Result<()> {
+    async fn solve_a(&self, lines: &Vec<String>) -> io::Result<String> {
         solve_a(lines).await
     }
 
-    async fn solve_b(&self, lines: &Vec<String>) -> io::Result<()> {
+    async fn solve_b(&self, lines: &Vec<String>) -> io::Result<String> {
         solve_b(lines).await
     }
 
@@ -31,10 +31,16 @@ struct Space3D {
     z: i64,
 }
 
-pub async fn solve_a(lines: &Vec<String>) -> io::Result<()> {
+pub async fn solve_a(lines: &Vec<String>) -> io::Result<String> {
     println!("Solving Day {}, Part A", Day.get_day());
-    let result = 0;
+    let result = largest_circuits_product(lines, 1000);
+    println!("Result: {}", result);
+    Ok(result.to_string())
+}
 
+/// Links the closest pairs, up to `target_connections` of them, and multiplies together the
+/// sizes of the three biggest circuits that makes.
+fn largest_circuits_product(lines: &[String], target_connections: usize) -> usize {
     let mut points: Vec<Space3D> = Vec::new();
     for (i, line) in lines.iter().enumerate() {
         let coords: Vec<i64> = line
@@ -64,19 +70,22 @@ pub async fn solve_a(lines: &Vec<String>) -> io::Result<()> {
         }
     }
 
-    let mut connections: HashMap<usize, Vec<usize>> = HashMap::new();
-    let target_connections = 1000;
+    let mut circuits = DisjointSet::new(points.len());
+    let mut links: Vec<usize> = vec![0; points.len()];
+    let mut linked_points = 0;
     let mut current_connections = 0;
     while let Some((_neg_dist, i, j)) = heap.pop() {
-        if connections.get(&i).map_or(0, |v| v.len()) >=
-            target_connections ||
-            connections.get(&j).map_or(0, |v| v.len()) >= target_connections
-        {
+        if links[i] >= target_connections || links[j] >= target_connections {
             continue;
         }
-        connections.entry(i).or_insert(Vec::new()).push(j);
-        connections.entry(j).or_insert(Vec::new()).push(i);
-        if connections.len() >= points.len() {
+        for point in [i, j] {
+            if links[point] == 0 {
+                linked_points += 1;
+            }
+            links[point] += 1;
+        }
+        circuits.union(i, j);
+        if linked_points >= points.len() {
             break;
         }
         current_connections += 1;
@@ -84,45 +93,19 @@ pub async fn solve_a(lines: &Vec<String>) -> io::Result<()> {
             break;
         }
     }
-    println!("Connections established: {}", connections.len());
+    println!("Connections established: {}", linked_points);
 
-    let mut visited: Vec<bool> = vec![false; points.len()];
-    let mut group_sizes: Vec<usize> = Vec::new();
-    for i in 0..points.len() {
-        if visited[i] {
-            continue;
-        }
-        let mut stack: Vec<usize> = vec![i];
-        let mut group_size = 0;
-        while let Some(node) = stack.pop() {
-            if visited[node] {
-                continue;
-            }
-            visited[node] = true;
-            group_size += 1;
-            if let Some(neighbors) = connections.get(&node) {
-                for &neighbor in neighbors {
-                    if !visited[neighbor] {
-                        stack.push(neighbor);
-                    }
-                }
-            }
-        }
-        group_sizes.push(group_size);
-    }
+    let mut group_sizes: Vec<usize> = circuits.components().map(|circuit| circuit.len()).collect();
     group_sizes.sort_unstable_by(|a, b| b.cmp(a));
     let top3_product: usize = group_sizes.iter().take(3).product();
     println!("Top 3 group sizes product: {}", top3_product);
-   
-
-    println!("Result: {}", result);
-    Ok(())
+    top3_product
 }
 
 
-pub async fn solve_b(lines: &Vec<String>) -> io::Result<()> {
+pub async fn solve_b(lines: &Vec<String>) -> io::Result<String> {
     println!("Solving Day {}, Part B", Day.get_day());
-    let result = 0;
+    let mut result = 0;
     let mut points: Vec<Space3D> = Vec::new();
     for (i, line) in lines.iter().enumerate() {
         let coords: Vec<i64> = line
@@ -149,39 +132,20 @@ pub async fn solve_b(lines: &Vec<String>) -> io::Result<()> {
             heap.push((-dist, i, j));
         }
     }
-    let mut parent: Vec<usize> = (0..points.len()).collect();
-    fn find(parent: &mut Vec<usize>, x: usize) -> usize {
-        if parent[x] != x {
-            parent[x] = find(parent, parent[x]);
-        }
-        parent[x]
-    }
-    fn union(parent: &mut Vec<usize>, x: usize, y: usize)
-    {
-        let root_x = find(parent, x);
-        let root_y = find(parent, y);
-        if root_x != root_y {
-            parent[root_y] = root_x;
-        }
-    }
+    let mut circuits = DisjointSet::new(points.len());
     while let Some((neg_dist, i, j)) = heap.pop() {
-        if find(&mut parent, i) == find(&mut parent, j) {
+        if !circuits.union(i, j) {
             continue;
         }
-        union(&mut parent, i, j);
-        let mut unique_parents: HashMap<usize, usize> = HashMap::new();
-        for k in 0..points.len() {
-            let root = find(&mut parent, k);
-            *unique_parents.entry(root).or_insert(0) += 1;
-        }
-        if unique_parents.len() <= 1 {
+        if circuits.count() <= 1 {
             let product = points[i].x * points[j].x;
             println!("Last connection between point {} and point {} with distance {}. Product of x coords: {}", i, j, -neg_dist, product);
+            result = product;
             break;
         }       
     }
     println!("Result: {}", result);
-    Ok(())
+    Ok(result.to_string())
 }
 
 #[cfg(test)]
@@ -189,6 +153,7 @@ mod tests {
     use super::*;
     use crate::get_input_for_puzzle;
     use crate::Puzzle;
+    use crate::testing::{block_on, lines};
     fn get_day_name() -> String {
         let module_path = module_path!();
         let module_name = module_path.split("::").last().unwrap_or("Unknown");
@@ -203,4 +168,16 @@ mod tests {
             _ => panic!("Invalid part"),
         }
     }
+
+    const SAMPLE: &str = "162,817,812\n57,618,57\n906,360,560\n592,479,940\n352,342,300\n\
+                          466,668,158\n542,29,236\n431,825,988\n739,650,466\n52,470,668\n\
+                          216,146,977\n819,987,18\n117,168,530\n805,96,715\n346,949,466\n\
+                          970,615,88\n941,993,340\n862,61,35\n984,92,344\n425,690,689";
+
+    #[test]
+    fn sample_answers() {
+        let input = lines(SAMPLE);
+        assert_eq!(largest_circuits_product(&input, 10), 40);
+        assert_eq!(block_on(solve_b(&input)).unwrap(), "25272");
+    }
 }
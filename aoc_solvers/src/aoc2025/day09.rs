@@ -1,5 +1,8 @@
 use crate::solver::DaySolver;
-use tokio::io;
+use crate::geometry::Point2;
+use crate::grid::Grid;
+use crate::io;
+use crate::pathfinding::bfs;
 
 use image::{Rgb, RgbImage};
 use imageproc::drawing::draw_line_segment_mut;
@@ -7,11 +10,11 @@ use imageproc::drawing::draw_line_segment_mut;
 pub struct Day;
 
 impl DaySolver for Day {
-    async fn solve_a(&self, lines: &Vec<String>) -> io::Result<()> {
+    async fn solve_a(&self, lines: &Vec<String>) -> io::Result<String> {
         solve_a(lines).await
     }
 
-    async fn solve_b(&self, lines: &Vec<String>) -> io::Result<()> {
+    async fn solve_b(&self, lines: &Vec<String>) -> io::Result<String> {
         solve_b(lines).await
     }
 
@@ -23,34 +26,29 @@ impl DaySolver for Day {
         2025
     }
 }
-#[derive(Clone, Copy, Debug, PartialEq)]
-struct Point {
-    x: i32,
-    y: i32,
-}
 #[derive(Debug, Clone, Copy)]
 struct Line {
-    start: Point,
-    end: Point,
+    start: Point2,
+    end: Point2,
     len_sq: i64, // squared length — exact, sortable, no overflow
 }
 
-pub async fn solve_a(lines: &Vec<String>) -> io::Result<()> {
+pub async fn solve_a(lines: &Vec<String>) -> io::Result<String> {
     println!("Solving Day {}, Part A", Day.get_day());
     let mut result = 0;
     //input is x,y
     for i in 0..lines.len() {
         for j in i + 1..lines.len() {
-            let pointa: Point = {
+            let pointa: Point2 = {
                 let parts: Vec<&str> = lines[i].split(',').collect();
-                Point {
+                Point2 {
                     x: parts[0].parse().unwrap(),
                     y: parts[1].parse().unwrap(),
                 }
             };
-            let pointb: Point = {
+            let pointb: Point2 = {
                 let parts: Vec<&str> = lines[j].split(',').collect();
-                Point {
+                Point2 {
                     x: parts[0].parse().unwrap(),
                     y: parts[1].parse().unwrap(),
                 }
@@ -65,7 +63,7 @@ pub async fn solve_a(lines: &Vec<String>) -> io::Result<()> {
     }
 
     println!("Result: {}", result);
-    Ok(())
+    Ok(result.to_string())
 }
 
 pub fn draw_grid(grid: &Vec<Vec<char>>) {
@@ -77,14 +75,14 @@ pub fn draw_grid(grid: &Vec<Vec<char>>) {
     }
 }
 
-pub async fn solve_b(lines: &Vec<String>) -> io::Result<()> {
+pub async fn solve_b(lines: &Vec<String>) -> io::Result<String> {
     println!("Solving Day {}, Part B", Day.get_day());
     let mut lines_vec: Vec<Line> = Vec::new();
-    let mut points_vec: Vec<Point> = Vec::new();
+    let mut points_vec: Vec<Point2> = Vec::new();
 
     for line in lines {
         let parts: Vec<&str> = line.split(',').collect();
-        points_vec.push(Point {
+        points_vec.push(Point2 {
             x: parts[0].parse().unwrap(),
             y: parts[1].parse().unwrap(),
         });
@@ -92,16 +90,16 @@ pub async fn solve_b(lines: &Vec<String>) -> io::Result<()> {
 
     // create all teh lines
     for i in 0..lines.len() - 1 {
-        let start_point: Point = {
+        let start_point: Point2 = {
             let parts: Vec<&str> = lines[i].split(',').collect();
-            Point {
+            Point2 {
                 x: parts[0].parse().unwrap(),
                 y: parts[1].parse().unwrap(),
             }
         };
-        let end_point: Point = {
+        let end_point: Point2 = {
             let parts: Vec<&str> = lines[i + 1].split(',').collect();
-            Point {
+            Point2 {
                 x: parts[0].parse().unwrap(),
                 y: parts[1].parse().unwrap(),
             }
@@ -116,16 +114,16 @@ pub async fn solve_b(lines: &Vec<String>) -> io::Result<()> {
             len_sq, 
         });
     }
-    let first_point: Point = {
+    let first_point: Point2 = {
         let parts: Vec<&str> = lines[0].split(',').collect();
-        Point {
+        Point2 {
             x: parts[0].parse().unwrap(),
             y: parts[1].parse().unwrap(),
         }
     };
-    let last_point: Point = {
+    let last_point: Point2 = {
         let parts: Vec<&str> = lines[lines.len() - 1].split(',').collect();
-        Point {
+        Point2 {
             x: parts[0].parse().unwrap(),
             y: parts[1].parse().unwrap(),
         }
@@ -140,176 +138,80 @@ pub async fn solve_b(lines: &Vec<String>) -> io::Result<()> {
     });
     // let (min_x, max_x, min_y, max_y) = build_boundary(&lines_vec);
 
-    lines_vec.sort_by(|a, b| b.len_sq.cmp(&a.len_sq));
-    let big_north_line = &lines_vec[1];
-    let north_right_point = if big_north_line.start.x > big_north_line.end.x {
-        big_north_line.start
-    } else {
-        big_north_line.end
-    };
-    let big_south_line = &lines_vec[0];
-    let south_right_point = if big_south_line.start.x > big_south_line.end.x {
-        big_south_line.start
-    } else {
-        big_south_line.end
-    };
-
-    
-    let points_north_west = points_vec
-        .iter()
-        .filter(|p| p.x < north_right_point.x)
-        .filter(|p| p.y > north_right_point.y)
-        
-        .collect::<Vec<&Point>>();
-    let points_south_west = points_vec
-        .iter()
-        .filter(|p| p.x < south_right_point.x)
-        .filter(|p| p.y < south_right_point.y)
-        .collect::<Vec<&Point>>();
-
-
-    // let data_w = max_x - min_x + 1;
-    // let data_h = max_y - min_y + 1;
-
-    // let scale_x = 990.0 / data_w as f32;
-    // let scale_y = 990.0 / data_h as f32;
-    // let scale = scale_x.min(scale_y);
-
-    // let offset_x = (1000.0 - data_w as f32 * scale) / 2.0;
-    // let offset_y = (1000.0 - data_h as f32 * scale) / 2.0;
-
-    // let mut img = RgbImage::new(1000, 1000);
-    // for p in img.pixels_mut() {
-    //     *p = Rgb([30, 30, 40]);
-    // }
-    // for line in &lines_vec {
-    //     draw_line(&mut img, line, min_x, min_y, scale, offset_x, offset_y);
-    // }
-    // draw_circle(
-    //     &mut img,
-    //     north_right_point,
-    //     10,
-    //     min_x,
-    //     min_y,
-    //     scale,
-    //     offset_x,
-    //     offset_y,
-    //     Rgb([255, 0, 0]),
-    // );
-    // draw_circle(
-    //     &mut img,
-    //     south_right_point,
-    //     10,
-    //     min_x,
-    //     min_y,
-    //     scale,
-    //     offset_x,
-    //     offset_y,
-    //     Rgb([0, 255, 255]),
-    // );
-    let mut largest_area: u64 = 0;
-    let mut largest_point: Option<Point> = None;
-    for p in &points_north_west {
-        let mut is_valid = true;
-        //check if valid ie no other points inside the rectangle
-        for op in &points_north_west {
-            if **op == **p {
-                continue;
+    // Shrink the floor down to the columns and rows that matter, mark the loop on it and fill
+    // in everything outside; a rectangle is good if none of the tiles it covers are outside.
+    let xs = key_coordinates(points_vec.iter().map(|p| p.x));
+    let ys = key_coordinates(points_vec.iter().map(|p| p.y));
+    let col = |x: isize| xs.binary_search(&x).unwrap();
+    let row = |y: isize| ys.binary_search(&y).unwrap();
+    let mut on_loop: Grid<bool> = Grid::filled(xs.len(), ys.len(), false);
+    for line in &lines_vec {
+        let (x1, x2) = (col(line.start.x.min(line.end.x)), col(line.start.x.max(line.end.x)));
+        let (y1, y2) = (row(line.start.y.min(line.end.y)), row(line.start.y.max(line.end.y)));
+        for y in y1..=y2 {
+            for x in x1..=x2 {
+                on_loop[(y, x)] = true;
             }
-            if op.x >= p.x && op.x <= north_right_point.x && op.y <= p.y && op.y >= north_right_point.y
-            {
-                is_valid = false;
-                break;
-            }
-        }
-        if !is_valid {
-            continue;
         }
+    }
+    let outside = bfs((0, 0), |&pos| {
+        on_loop
+            .neighbours4(pos)
+            .filter(|&next| !on_loop[next])
+            .collect::<Vec<_>>()
+    });
 
-        let current_area: u64 = ((north_right_point.x - p.x).abs() as u64 + 1)
-            * ((north_right_point.y - p.y).abs() as u64 + 1);
-        if current_area > largest_area {
-            largest_area = current_area;
-            largest_point = Some(**p);
+    // outside_above[y][x]: outside tiles in the rows above y and the columns left of x.
+    let mut outside_above = vec![vec![0u32; xs.len() + 1]; ys.len() + 1];
+    for y in 0..ys.len() {
+        for x in 0..xs.len() {
+            outside_above[y + 1][x + 1] = outside_above[y][x + 1] + outside_above[y + 1][x]
+                - outside_above[y][x]
+                + outside.contains_key(&(y, x)) as u32;
         }
     }
-    //draw larger north west rectangle
-    if let Some(_p) = largest_point {
-        // draw_rectangle(
-        //     &mut img,
-        //     p,
-        //     north_right_point,
-        //     min_x,
-        //     min_y,
-        //     scale,
-        //     offset_x,
-        //     offset_y,
-        //     Rgb([255, 100, 100]),
-        // );
-        // println!(
-        //     "Largest North-West rectangle corner at ({}, {}) with area {}",
-        //     p.x, p.y, largest_area
-        // );
-    }
-    let mut result = largest_area;
-
-    //now we check the south west points
-    let mut largest_area: u64 = 0;
-    let mut largest_point: Option<Point> = None;
-    for p in &points_south_west {
-        let mut is_valid = true;
-        //check if valid ie no other points inside the rectangle
-        for op in &points_south_west {
-            if **op == **p {
-                continue;
-            }
-            if op.x >= p.x && op.x <= south_right_point.x && op.y >= p.y && op.y <= south_right_point.y
-            {
-                is_valid = false;
-                break;
+
+    let mut result: u64 = 0;
+    for (i, a) in points_vec.iter().enumerate() {
+        for b in &points_vec[i + 1..] {
+            let (x1, x2) = (col(a.x.min(b.x)), col(a.x.max(b.x)) + 1);
+            let (y1, y2) = (row(a.y.min(b.y)), row(a.y.max(b.y)) + 1);
+            let outside_tiles = outside_above[y2][x2] + outside_above[y1][x1]
+                - outside_above[y1][x2]
+                - outside_above[y2][x1];
+            if outside_tiles == 0 {
+                let area = ((b.x - a.x).unsigned_abs() as u64 + 1)
+                    * ((b.y - a.y).unsigned_abs() as u64 + 1);
+                result = result.max(area);
             }
         }
-        if !is_valid {
-            continue;
-        }
-        let current_area: u64 = ((south_right_point.x - p.x).abs() as u64 + 1)
-            * ((south_right_point.y - p.y).abs() as u64 + 1);
-        if current_area > largest_area {
-            largest_area = current_area;
-            largest_point = Some(**p);
-        }
     }
-    //draw larger south west rectangle
-    if let Some(_p) = largest_point {
-        // draw_rectangle(
-        //     &mut img,
-        //     p,
-        //     south_right_point,
-        //     min_x,
-        //     min_y,
-        //     scale,
-        //     offset_x,
-        //     offset_y,
-        //     Rgb([100, 255, 255]),
-        // );
-        // println!(
-        //     "Largest South-West rectangle corner at ({}, {}) with area {}",
-        //     p.x, p.y, largest_area
-        // );
-    }
-    if largest_area > result {
-        result = largest_area;
-    }
-    
-    // img.save("day09_partb_output.png").unwrap();
 
     println!("Result: {}", result);
-    Ok(())
+    Ok(result.to_string())
+}
+
+/// The columns (or rows) worth keeping: every one with a red tile, one to stand for each run
+/// of columns between those, and one past either end so the outside goes all the way round.
+fn key_coordinates(values: impl Iterator<Item = isize>) -> Vec<isize> {
+    let mut values: Vec<isize> = values.collect();
+    values.sort_unstable();
+    values.dedup();
+    let mut keys = vec![values[0] - 1];
+    for pair in values.windows(2) {
+        keys.push(pair[0]);
+        if pair[1] - pair[0] > 1 {
+            keys.push(pair[0] + 1);
+        }
+    }
+    keys.push(values[values.len() - 1]);
+    keys.push(values[values.len() - 1] + 1);
+    keys
 }
 
-fn build_boundary(lines: &Vec<Line>) -> (i32, i32, i32, i32) {
+fn build_boundary(lines: &[Line]) -> (isize, isize, isize, isize) {
     let (min_x, max_x, min_y, max_y) = lines.iter().fold(
-        (i32::MAX, i32::MIN, i32::MAX, i32::MIN),
+        (isize::MAX, isize::MIN, isize::MAX, isize::MIN),
         |(mx,m_x, my, m_y), line| {
             let x1 = line.start.x.min(line.end.x);
             let x2 = line.start.x.max(line.end.x);
@@ -329,13 +231,13 @@ fn build_boundary(lines: &Vec<Line>) -> (i32, i32, i32, i32) {
 fn draw_line(
     img: &mut RgbImage,
     line: &Line,
-    min_x: i32,
-    min_y: i32,
+    min_x: isize,
+    min_y: isize,
     scale: f32,
     offset_x: f32,
     offset_y: f32,
 ) {
-    let to_img = |p: Point| -> (f32, f32) {
+    let to_img = |p: Point2| -> (f32, f32) {
         let sx = ((p.x - min_x) as f32) * scale + offset_x;
         let sy = ((p.y - min_y) as f32) * scale + offset_y;
         (sx, 999.999 - sy)  // keep as f32, no early truncate
@@ -348,10 +250,10 @@ fn draw_line(
 
 fn draw_circle(
     img: &mut RgbImage,
-    point: Point,
+    point: Point2,
     radius: i32,
-    min_x: i32,
-    min_y: i32,
+    min_x: isize,
+    min_y: isize,
     scale: f32,
     offset_x: f32,
     offset_y: f32,
@@ -369,16 +271,16 @@ fn draw_circle(
 
 fn draw_rectangle(
     img: &mut RgbImage,
-    p1: Point,
-    p2: Point,
-    min_x: i32,
-    min_y: i32,
+    p1: Point2,
+    p2: Point2,
+    min_x: isize,
+    min_y: isize,
     scale: f32,
     offset_x: f32,
     offset_y: f32,
     color: Rgb<u8>,
 ) {
-    let to_img = |p: Point| {
+    let to_img = |p: Point2| {
         let sx = ((p.x - min_x) as f32) * scale + offset_x;
         let sy = ((p.y - min_y) as f32) * scale + offset_y;
         (sx.round() as i32, (999.999 - sy).round() as i32)
@@ -400,11 +302,11 @@ fn draw_rectangle(
 }
 
 fn draw_lines(
-    lines: &Vec<Line>,
-    min_x: i32,
-    max_x: i32,
-    min_y: i32,
-    max_y: i32,
+    lines: &[Line],
+    min_x: isize,
+    max_x: isize,
+    min_y: isize,
+    max_y: isize,
     filename: &str,
 ) -> RgbImage {
     let data_w = max_x - min_x + 1;
@@ -447,7 +349,7 @@ fn draw_lines(
     } else {
         south_line.end
     };
-    let to_img = |p: Point| -> (i32, i32) {
+    let to_img = |p: Point2| -> (i32, i32) {
         let sx = ((p.x - min_x) as f32) * scale + offset_x;
         let sy = ((p.y - min_y) as f32) * scale + offset_y;
         (sx as i32, 999 - sy as i32) // flip Y and clamp to 0..999
@@ -474,6 +376,7 @@ mod tests {
     use super::*;
     use crate::get_input_for_puzzle;
     use crate::Puzzle;
+    use crate::testing::{block_on, lines};
     fn get_day_name() -> String {
         let module_path = module_path!();
         let module_name = module_path.split("::").last().unwrap_or("Unknown");
@@ -488,4 +391,13 @@ mod tests {
             _ => panic!("Invalid part"),
         }
     }
+
+    const SAMPLE: &str = "7,1\n11,1\n11,7\n9,7\n9,5\n2,5\n2,3\n7,3";
+
+    #[test]
+    fn sample_answers() {
+        let input = lines(SAMPLE);
+        assert_eq!(block_on(solve_a(&input)).unwrap(), "50");
+        assert_eq!(block_on(solve_b(&input)).unwrap(), "24");
+    }
 }
@@ -1,6 +1,7 @@
 use crate::solver::DaySolver;
 use std::collections::{HashMap, VecDeque};
-use tokio::io;
+use crate::io;
+use crate::pathfinding::bfs_to;
 use z3::{
     Config, Context, Optimize, SatResult,
     ast::{Ast, Int},
@@ -9,11 +10,11 @@ use z3::{
 pub struct Day;
 
 impl DaySolver for Day {
-    async fn solve_a(&self, lines: &Vec<String>) -> io::Result<()> {
+    async fn solve_a(&self, lines: &Vec<String>) -> io::Result<String> {
         solve_a(lines).await
     }
 
-    async fn solve_b(&self, lines: &Vec<String>) -> io::Result<()> {
+    async fn solve_b(&self, lines: &Vec<String>) -> io::Result<String> {
         solve_b(lines).await
     }
 
@@ -85,7 +86,7 @@ fn build_machines(input: &Vec<String>) -> Vec<Machine> {
     machines
 }
 
-pub async fn solve_a(lines: &Vec<String>) -> io::Result<()> {
+pub async fn solve_a(lines: &Vec<String>) -> io::Result<String> {
     println!("Solving Day {}, Part A", Day.get_day());
     let mut result = 0;
     let machines: Vec<Machine> = build_machines(lines);
@@ -100,36 +101,22 @@ pub async fn solve_a(lines: &Vec<String>) -> io::Result<()> {
         let m = &machines[i];
         // print_machine(m);
         let base_state: Vec<bool> = vec![false; m.ind_lights.len()];
-        let mut state_map: HashMap<Vec<bool>, u32> = HashMap::new();
-        let mut queue: VecDeque<(Vec<bool>, u32)> = VecDeque::new();
-        queue.push_back((base_state.clone(), 0));
-        while let Some((state, presses)) = queue.pop_front() {
-            if let Some(&existing_presses) = state_map.get(&state) {
-                if presses >= existing_presses {
-                    continue;
-                }
-            }
-            state_map.insert(state.clone(), presses);
-            if state == m.ind_lights {
-                // println!(
-                //     "Machine {} solved in {} presses",
-                //     m.id,
-                //     presses
-                // );
-                result += presses;
-                break;
-            }
-            for button in &m.buttons {
+        let next_states = |state: &Vec<bool>| {
+            let presses = m.buttons.iter().map(|button| {
                 let mut new_state = state.clone();
                 press_button(&mut new_state, button, &m.joltage);
-                // println!("Pressing button {:?} leads to state {:?}", button, new_state);
-                queue.push_back((new_state, presses + 1));
-            }
+                new_state
+            });
+            presses.collect::<Vec<_>>()
+        };
+        let solved = bfs_to(base_state, next_states, |state| *state == m.ind_lights);
+        if let Some((_, presses)) = solved {
+            result += presses;
         }
     }
 
     println!("Result: {}", result);
-    Ok(())
+    Ok(result.to_string())
 }
 //1337458128196 too high
 //77918 too high
@@ -142,7 +129,7 @@ pub async fn solve_a(lines: &Vec<String>) -> io::Result<()> {
 //     buttons: Vec<Vec<u8>>,
 //     joltage: Vec<u16>,
 // }
-pub async fn solve_b(lines: &Vec<String>) -> io::Result<()> {
+pub async fn solve_b(lines: &Vec<String>) -> io::Result<String> {
     let machines = build_machines(lines); // you already have this
 
     let cfg = Config::new();
@@ -198,7 +185,7 @@ pub async fn solve_b(lines: &Vec<String>) -> io::Result<()> {
 
     let total = result;
     println!("Result: {}", total);
-    Ok(())
+    Ok(total.to_string())
 }
 
 pub async fn solve_b_incorrect_could_work(lines: &Vec<String>) -> io::Result<()> {
@@ -410,6 +397,7 @@ pub async fn solve_b_incorrect_could_work(lines: &Vec<String>) -> io::Result<()>
 mod tests {
     use super::*;
     use crate::Puzzle;
+    use crate::testing::{block_on, lines};
     use crate::get_input_for_puzzle;
     fn get_day_name() -> String {
         let module_path = module_path!();
@@ -425,4 +413,14 @@ mod tests {
             _ => panic!("Invalid part"),
         }
     }
+
+    const SAMPLE: &str = "[.##.] (3) (1,3) (2) (2,3) (0,2) (0,1) {3,5,4,7}\n\
+                          [...#.] (0,2,3,4) (2,3) (0,4) (0,1,2) (1,2,3,4) {7,5,12,7,2}\n\
+                          [.###.#] (0,1,2,3,4) (0,3,4) (0,1,2,4,5) (1,2) {10,11,11,5,10,5}";
+
+    /// Only part A: part B hands the presses to z3.
+    #[test]
+    fn sample_answers() {
+        assert_eq!(block_on(solve_a(&lines(SAMPLE))).unwrap(), "7");
+    }
 }
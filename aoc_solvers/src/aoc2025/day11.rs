@@ -0,0 +1,112 @@
+use crate::solver::DaySolver;
+use crate::graph::Graph;
+use crate::io;
+
+pub struct Day;
+
+impl DaySolver for Day {
+    async fn solve_a(&self, lines: &Vec<String>) -> io::Result<String> {
+        solve_a(lines).await
+    }
+
+    async fn solve_b(&self, lines: &Vec<String>) -> io::Result<String> {
+        solve_b(lines).await
+    }
+
+    fn get_day(&self) -> u8 {
+        11
+    }
+
+    fn get_year(&self) -> u16 {
+        2025
+    }
+}
+
+/// The wiring as a graph: each line is a device and the devices its outputs go to, like
+/// "xeq: mqb gzn".
+fn build_wiring(lines: &[String]) -> Graph {
+    let mut wiring = Graph::new();
+    for line in lines {
+        let (name, outputs) = line.split_once(": ").unwrap();
+        for output in outputs.split(' ') {
+            wiring.add_edge(name, output);
+        }
+    }
+    wiring
+}
+
+/// Paths from `from` to `to`, or 0 if either device isn't in the wiring.
+fn count_paths(wiring: &Graph, from: &str, to: &str) -> io::Result<u64> {
+    let (Some(from), Some(to)) = (wiring.id(from), wiring.id(to)) else {
+        return Ok(0);
+    };
+    wiring
+        .count_paths(from, to)
+        .map_err(io::Error::other)
+}
+
+pub async fn solve_a(lines: &Vec<String>) -> io::Result<String> {
+    println!("Solving Day {}, Part A", Day.get_day());
+    let wiring = build_wiring(lines);
+    //start is conn "you" end is "out" find the number of ways for you to out
+    let result = count_paths(&wiring, "you", "out")?;
+
+    println!("Result: {}", result);
+    Ok(result.to_string())
+}
+
+// 9392593633280 low?
+// 9392593633280
+//331837854931968
+pub async fn solve_b(lines: &Vec<String>) -> io::Result<String> {
+    println!("Solving Day {}, Part B", Day.get_day());
+    let wiring = build_wiring(lines);
+
+    // The wiring has no loops, so a path can only pass dac and fft one way round or the other.
+    let via = |first: &str, second: &str| -> io::Result<u128> {
+        let legs = [("svr", first), (first, second), (second, "out")];
+        let mut total = 1;
+        for (from, to) in legs {
+            total *= count_paths(&wiring, from, to)? as u128;
+        }
+        Ok(total)
+    };
+    let result = via("dac", "fft")? + via("fft", "dac")?;
+
+    println!("Result: {}", result);
+    Ok(result.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Puzzle;
+    use crate::get_input_for_puzzle;
+    use crate::testing::{block_on, lines};
+    fn get_day_name() -> String {
+        let module_path = module_path!();
+        let module_name = module_path.split("::").last().unwrap_or("Unknown");
+        module_name.to_string().replace("day", "Day")
+    }
+
+    fn get_puzzle(part: char) -> Puzzle {
+        let day = get_day_name().replace("Day", "").parse::<u8>().unwrap_or(1);
+        match part {
+            'A' => Puzzle::from_day_part(day, 'A'),
+            'B' => Puzzle::from_day_part(day, 'B'),
+            _ => panic!("Invalid part"),
+        }
+    }
+
+    const SAMPLE_A: &str = "aaa: you hhh\nyou: bbb ccc\nbbb: ddd eee\nccc: ddd eee fff\nddd: ggg\n\
+                            eee: out\nfff: out\nggg: out\nhhh: ccc fff iii\niii: out";
+    const SAMPLE_B: &str = "svr: aaa bbb\naaa: fft\nfft: ccc\nbbb: tty\ntty: ccc\nccc: ddd eee\n\
+                            ddd: hub\nhub: fff\neee: dac\ndac: fff\nfff: ggg hhh\nggg: out\nhhh: out";
+
+    /// Part B has its own sample, since the first one has no `svr`.
+    #[test]
+    fn sample_answers() {
+        assert_eq!(block_on(solve_a(&lines(SAMPLE_A))).unwrap(), "5");
+        assert_eq!(block_on(solve_b(&lines(SAMPLE_B))).unwrap(), "2");
+    }
+}
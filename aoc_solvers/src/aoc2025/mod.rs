@@ -5,9 +5,15 @@ pub mod day03;
 pub mod day04;
 pub mod day05;
 pub mod day06;
+#[cfg(any(feature = "std", feature = "fixed"))]
 pub mod day07;
+#[cfg(feature = "std")]
 pub mod day08;
+#[cfg(feature = "std")]
 pub mod day09;
+#[cfg(feature = "std")]
 pub mod day10;
+#[cfg(feature = "std")]
 pub mod day11;
+#[cfg(feature = "std")]
 pub mod day12;  
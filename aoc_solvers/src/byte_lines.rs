@@ -0,0 +1,148 @@
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::ops::Index;
+use core::str::Utf8Error;
+
+use crate::io;
+
+/// A whole input as one byte buffer, split into lines by offset rather than copied into a
+/// `String` per line. Lines and fields borrow from the buffer, and a grid day can read
+/// `input.at(row, col)` without walking the line like `chars().nth(col)` does.
+///
+/// Lines are split the same way as `str::lines`: on `\n`, with a trailing `\r` dropped and no
+/// empty line after a final newline. The buffer is checked to be UTF-8 up front, so lines can
+/// be handed out as `str` without failing.
+pub struct ByteLines<'a> {
+    bytes: &'a [u8],
+    /// Where each line starts, then one past where a newline after the last line would be.
+    starts: Vec<usize>,
+}
+
+impl<'a> ByteLines<'a> {
+    /// Fails with `InvalidData` if `bytes` isn't UTF-8, e.g. a corrupt or binary upload.
+    pub fn new(bytes: &'a [u8]) -> io::Result<Self> {
+        core::str::from_utf8(bytes).map_err(not_utf8)?;
+        let mut starts = Vec::new();
+        let mut start = 0;
+        while start < bytes.len() {
+            starts.push(start);
+            start += match bytes[start..].iter().position(|&b| b == b'\n') {
+                Some(newline) => newline + 1,
+                None => bytes.len() - start + 1,
+            };
+        }
+        starts.push(start);
+        Ok(ByteLines { bytes, starts })
+    }
+
+    pub fn len(&self) -> usize {
+        self.starts.len() - 1
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Line `row`, without its line ending.
+    pub fn line(&self, row: usize) -> &'a [u8] {
+        let line = &self.bytes[self.starts[row]..self.starts[row + 1] - 1];
+        line.strip_suffix(b"\r").unwrap_or(line)
+    }
+
+    /// Line `row` as text.
+    pub fn str(&self, row: usize) -> &'a str {
+        // Lines end just before a `\n` or `\r`, never inside a character.
+        core::str::from_utf8(self.line(row)).expect("checked in `new`")
+    }
+
+    pub fn lines(&self) -> impl Iterator<Item = &'a [u8]> + '_ {
+        (0..self.len()).map(|row| self.line(row))
+    }
+
+    /// The whitespace-separated fields of line `row`.
+    pub fn fields(&self, row: usize) -> impl Iterator<Item = &'a str> {
+        self.str(row).split_ascii_whitespace()
+    }
+
+    /// The byte at `col` in line `row`. Panics outside the input, like indexing a `Vec<Vec<_>>`.
+    pub fn at(&self, row: usize, col: usize) -> u8 {
+        self.line(row)[col]
+    }
+
+    /// `at` for coordinates that may be off the edge (or off the end of a short line).
+    pub fn get(&self, row: usize, col: usize) -> Option<u8> {
+        if row >= self.len() {
+            return None;
+        }
+        self.line(row).get(col).copied()
+    }
+
+    /// Length of the first line, i.e. the width of a grid.
+    pub fn width(&self) -> usize {
+        if self.is_empty() {
+            0
+        } else {
+            self.line(0).len()
+        }
+    }
+
+    /// The input as the `Vec<String>` the line-based solvers take.
+    pub fn to_lines(&self) -> Vec<String> {
+        (0..self.len()).map(|row| self.str(row).into()).collect()
+    }
+}
+
+#[cfg(feature = "std")]
+fn not_utf8(err: Utf8Error) -> io::Error {
+    io::Error::new(
+        io::ErrorKind::InvalidData,
+        alloc::format!("input isn't UTF-8: {}", err),
+    )
+}
+
+#[cfg(not(feature = "std"))]
+fn not_utf8(err: Utf8Error) -> io::Error {
+    io::Error::new(alloc::format!("input isn't UTF-8: {}", err))
+}
+
+impl<'a> Index<usize> for ByteLines<'a> {
+    type Output = [u8];
+
+    fn index(&self, row: usize) -> &[u8] {
+        self.line(row)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::aoc2025::day04;
+    use crate::testing::block_on;
+
+    #[test]
+    fn lines_split_like_str_lines() {
+        for text in ["12 34\r\n\n#.#\n", "12 34\n\n#.#", "12 34\r\n\r\n#.#\r\n"] {
+            let input = ByteLines::new(text.as_bytes()).unwrap();
+            assert_eq!(input.to_lines(), text.lines().collect::<Vec<_>>());
+            assert_eq!(input.fields(0).collect::<Vec<_>>(), ["12", "34"]);
+            assert_eq!(
+                (input.width(), &input[1], input.at(2, 1)),
+                (5, &b""[..], b'.')
+            );
+            assert_eq!((input.get(2, 3), input.get(3, 0)), (None, None));
+        }
+        assert!(ByteLines::new(b"").unwrap().is_empty());
+        assert_eq!(ByteLines::new(b"\n").unwrap().to_lines(), [""]);
+        let err = ByteLines::new(b"12\n\xff34\n").err().unwrap();
+        assert_eq!(err.kind(), std::io::ErrorKind::InvalidData);
+    }
+
+    #[test]
+    fn grid_days_solve_straight_from_bytes() {
+        let sample = "..@@.@@@@.\n@@@.@.@.@@\n@@@@@.@.@@\n@.@@@@..@.\n@@.@@@@.@@\n\
+                      .@@@@@@@.@\n.@.@.@.@@@\n@.@@@.@@@@\n.@@@@@@@@.\n@.@.@@@.@.\n";
+        let input = ByteLines::new(sample.as_bytes()).unwrap();
+        assert_eq!(block_on(day04::solve_a(&input)).unwrap(), "13");
+        assert_eq!(block_on(day04::solve_b(&input)).unwrap(), "43");
+    }
+}
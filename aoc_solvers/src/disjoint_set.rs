@@ -0,0 +1,120 @@
+//! Union-find over the numbers `0..n`, for days that keep merging things into groups: circuits
+//! of junction boxes, regions of garden plots.
+//!
+//! Callers number their items themselves, e.g. by index in the input or `row * width + col`.
+
+use alloc::vec;
+use alloc::vec::Vec;
+
+#[derive(Clone, Debug)]
+pub struct DisjointSet {
+    parent: Vec<usize>,
+    size: Vec<usize>,
+    count: usize,
+}
+
+impl DisjointSet {
+    /// `len` items, each in a group of its own.
+    pub fn new(len: usize) -> Self {
+        DisjointSet {
+            parent: (0..len).collect(),
+            size: vec![1; len],
+            count: len,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.parent.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.parent.is_empty()
+    }
+
+    /// How many separate groups there are.
+    pub fn count(&self) -> usize {
+        self.count
+    }
+
+    /// The item standing for `item`'s group. Every item on the way gets pointed straight at
+    /// it, so the next lookup is quick.
+    pub fn find(&mut self, item: usize) -> usize {
+        let mut root = item;
+        while self.parent[root] != root {
+            root = self.parent[root];
+        }
+        let mut item = item;
+        while self.parent[item] != root {
+            item = core::mem::replace(&mut self.parent[item], root);
+        }
+        root
+    }
+
+    /// Merges the groups of `a` and `b`, the smaller into the larger. `false` if they were
+    /// already the same group.
+    pub fn union(&mut self, a: usize, b: usize) -> bool {
+        let (mut a, mut b) = (self.find(a), self.find(b));
+        if a == b {
+            return false;
+        }
+        if self.size[a] < self.size[b] {
+            core::mem::swap(&mut a, &mut b);
+        }
+        self.parent[b] = a;
+        self.size[a] += self.size[b];
+        self.count -= 1;
+        true
+    }
+
+    pub fn same(&mut self, a: usize, b: usize) -> bool {
+        self.find(a) == self.find(b)
+    }
+
+    /// How many items are in `item`'s group, itself included.
+    pub fn size_of(&mut self, item: usize) -> usize {
+        let root = self.find(item);
+        self.size[root]
+    }
+
+    /// Every group with its items in order, the groups ordered by their lowest item.
+    pub fn components(&mut self) -> impl Iterator<Item = Vec<usize>> {
+        let mut slot = vec![usize::MAX; self.len()];
+        let mut groups: Vec<Vec<usize>> = Vec::with_capacity(self.count);
+        for item in 0..self.len() {
+            let root = self.find(item);
+            if slot[root] == usize::MAX {
+                slot[root] = groups.len();
+                groups.push(Vec::with_capacity(self.size[root]));
+            }
+            groups[slot[root]].push(item);
+        }
+        groups.into_iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn unions_merge_groups() {
+        let mut set = DisjointSet::new(6);
+        assert_eq!((set.len(), set.count()), (6, 6));
+        assert!(set.union(0, 3) && set.union(4, 3) && set.union(1, 5));
+        assert!(!set.union(0, 4));
+        assert!(set.same(4, 0) && !set.same(1, 2));
+        assert_eq!((set.count(), set.size_of(3), set.size_of(2)), (3, 3, 1));
+        let groups: Vec<Vec<usize>> = set.components().collect();
+        assert_eq!(groups, [vec![0, 3, 4], vec![1, 5], vec![2]]);
+    }
+
+    #[test]
+    fn long_chains_stay_correct() {
+        let mut set = DisjointSet::new(1000);
+        for item in 1..1000 {
+            set.union(item - 1, item);
+        }
+        assert_eq!((set.count(), set.size_of(0)), (1, 1000));
+        assert_eq!(set.find(999), set.find(0));
+    }
+}
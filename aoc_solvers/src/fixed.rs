@@ -0,0 +1,411 @@
+//! Fixed-capacity stand-ins for `Vec`, `Grid`, `VecDeque`, `HashSet` and `HashMap`.
+//! Everything is stored inline with its capacity fixed at compile time, so a day built on them
+//! has a known memory footprint and never touches the heap. They use the std method names the
+//! days already call, so a day opts in by swapping a type alias.
+//!
+//! Going over capacity panics: capacities are picked per day to fit that day's input.
+
+use core::fmt;
+use core::hash::{Hash, Hasher};
+use core::ops::{Deref, DerefMut};
+
+use crate::grid::Grid;
+
+/// A `Vec` with room for `N` items. Derefs to the slice of items pushed so far.
+#[derive(Clone)]
+pub struct BoundedVec<T, const N: usize> {
+    items: [T; N],
+    len: usize,
+}
+
+/// A `Grid` with room for `N` cells in all, however they end up split into rows.
+pub type FixedGrid<T, const N: usize> = Grid<T, BoundedVec<T, N>>;
+
+impl<T: Default, const N: usize> BoundedVec<T, N> {
+    pub fn new() -> Self {
+        BoundedVec {
+            items: core::array::from_fn(|_| T::default()),
+            len: 0,
+        }
+    }
+
+    pub fn push(&mut self, value: T) {
+        if self.len == N {
+            panic!("BoundedVec is full ({} items)", N);
+        }
+        self.items[self.len] = value;
+        self.len += 1;
+    }
+
+    pub fn pop(&mut self) -> Option<T> {
+        if self.len == 0 {
+            return None;
+        }
+        self.len -= 1;
+        Some(core::mem::take(&mut self.items[self.len]))
+    }
+
+    pub fn clear(&mut self) {
+        self.len = 0;
+    }
+
+    pub fn is_full(&self) -> bool {
+        self.len == N
+    }
+}
+
+impl<T: Default, const N: usize> Default for BoundedVec<T, N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T, const N: usize> Deref for BoundedVec<T, N> {
+    type Target = [T];
+
+    fn deref(&self) -> &[T] {
+        &self.items[..self.len]
+    }
+}
+
+impl<T, const N: usize> DerefMut for BoundedVec<T, N> {
+    fn deref_mut(&mut self) -> &mut [T] {
+        &mut self.items[..self.len]
+    }
+}
+
+impl<T: Default, const N: usize> FromIterator<T> for BoundedVec<T, N> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut items = Self::new();
+        items.extend(iter);
+        items
+    }
+}
+
+impl<T: Default, const N: usize> Extend<T> for BoundedVec<T, N> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for item in iter {
+            self.push(item);
+        }
+    }
+}
+
+impl<T: Clone + Default, const N: usize> From<&[T]> for BoundedVec<T, N> {
+    fn from(items: &[T]) -> Self {
+        items.iter().cloned().collect()
+    }
+}
+
+impl<T: PartialEq, const N: usize> PartialEq for BoundedVec<T, N> {
+    fn eq(&self, other: &Self) -> bool {
+        **self == **other
+    }
+}
+
+impl<T: Eq, const N: usize> Eq for BoundedVec<T, N> {}
+
+impl<T: Hash, const N: usize> Hash for BoundedVec<T, N> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        (**self).hash(state);
+    }
+}
+
+impl<T: fmt::Debug, const N: usize> fmt::Debug for BoundedVec<T, N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+/// A `VecDeque` (as a FIFO queue) with room for `N` items.
+pub struct BoundedQueue<T, const N: usize> {
+    items: [T; N],
+    head: usize,
+    len: usize,
+}
+
+impl<T: Copy + Default, const N: usize> BoundedQueue<T, N> {
+    pub fn new() -> Self {
+        BoundedQueue {
+            items: [T::default(); N],
+            head: 0,
+            len: 0,
+        }
+    }
+
+    pub fn push_back(&mut self, value: T) {
+        if self.len == N {
+            panic!("BoundedQueue is full ({} items)", N);
+        }
+        self.items[(self.head + self.len) % N] = value;
+        self.len += 1;
+    }
+
+    pub fn pop_front(&mut self) -> Option<T> {
+        if self.len == 0 {
+            return None;
+        }
+        let value = self.items[self.head];
+        self.head = (self.head + 1) % N;
+        self.len -= 1;
+        Some(value)
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+}
+
+impl<T: Copy + Default, const N: usize> Default for BoundedQueue<T, N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// FNV-1a. Nothing here is exposed to untrusted keys, so it doesn't need to resist collisions.
+struct Fnv(u64);
+
+impl Hasher for Fnv {
+    fn finish(&self) -> u64 {
+        self.0
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        for &byte in bytes {
+            self.0 ^= byte as u64;
+            self.0 = self.0.wrapping_mul(0x100000001b3);
+        }
+    }
+}
+
+/// Where `key` is (`Ok`) or the free slot it would go in (`Err`), by linear probing.
+/// `None` when it isn't there and every slot is taken.
+fn probe<K: Hash + Eq>(keys: &[K], used: &[bool], key: &K) -> Option<Result<usize, usize>> {
+    if keys.is_empty() {
+        return None;
+    }
+    let mut hasher = Fnv(0xcbf29ce484222325);
+    key.hash(&mut hasher);
+    let start = (hasher.finish() % keys.len() as u64) as usize;
+    for i in 0..keys.len() {
+        let at = (start + i) % keys.len();
+        if !used[at] {
+            return Some(Err(at));
+        }
+        if keys[at] == *key {
+            return Some(Ok(at));
+        }
+    }
+    None
+}
+
+/// A `HashSet` with room for `N` values (open addressing, no removal). Leave some slack over
+/// the most values expected: lookups slow down as it fills.
+pub struct BoundedSet<T, const N: usize> {
+    values: [T; N],
+    used: [bool; N],
+    len: usize,
+}
+
+impl<T: Copy + Default + Hash + Eq, const N: usize> BoundedSet<T, N> {
+    pub fn new() -> Self {
+        BoundedSet {
+            values: [T::default(); N],
+            used: [false; N],
+            len: 0,
+        }
+    }
+
+    /// `true` if `value` wasn't there yet, like `HashSet::insert`.
+    pub fn insert(&mut self, value: T) -> bool {
+        match probe(&self.values, &self.used, &value) {
+            Some(Ok(_)) => false,
+            Some(Err(at)) => {
+                self.values[at] = value;
+                self.used[at] = true;
+                self.len += 1;
+                true
+            }
+            None => panic!("BoundedSet is full ({} values)", N),
+        }
+    }
+
+    pub fn contains(&self, value: &T) -> bool {
+        matches!(probe(&self.values, &self.used, value), Some(Ok(_)))
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
+        self.values
+            .iter()
+            .zip(&self.used)
+            .filter_map(|(value, &used)| used.then_some(value))
+    }
+}
+
+impl<T: Copy + Default + Hash + Eq, const N: usize> Default for BoundedSet<T, N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// A `HashMap` with room for `N` entries (open addressing, no removal).
+pub struct BoundedMap<K, V, const N: usize> {
+    keys: [K; N],
+    values: [V; N],
+    used: [bool; N],
+    len: usize,
+}
+
+impl<K: Copy + Default + Hash + Eq, V: Copy + Default, const N: usize> BoundedMap<K, V, N> {
+    pub fn new() -> Self {
+        BoundedMap {
+            keys: [K::default(); N],
+            values: [V::default(); N],
+            used: [false; N],
+            len: 0,
+        }
+    }
+
+    fn slot(&mut self, key: K) -> usize {
+        match probe(&self.keys, &self.used, &key) {
+            Some(Ok(at)) => at,
+            Some(Err(at)) => {
+                self.keys[at] = key;
+                self.used[at] = true;
+                self.len += 1;
+                at
+            }
+            None => panic!("BoundedMap is full ({} entries)", N),
+        }
+    }
+
+    /// The old value, if there was one, like `HashMap::insert`.
+    pub fn insert(&mut self, key: K, value: V) -> Option<V> {
+        let existed = self.contains_key(&key);
+        let at = self.slot(key);
+        let old = core::mem::replace(&mut self.values[at], value);
+        existed.then_some(old)
+    }
+
+    pub fn get(&self, key: &K) -> Option<&V> {
+        match probe(&self.keys, &self.used, key) {
+            Some(Ok(at)) => Some(&self.values[at]),
+            _ => None,
+        }
+    }
+
+    pub fn contains_key(&self, key: &K) -> bool {
+        self.get(key).is_some()
+    }
+
+    pub fn entry(&mut self, key: K) -> Entry<'_, K, V, N> {
+        Entry { map: self, key }
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+}
+
+impl<K: Copy + Default + Hash + Eq, V: Copy + Default, const N: usize> Default
+    for BoundedMap<K, V, N>
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Just enough of `hash_map::Entry` for `map.entry(key).or_insert(0)`.
+pub struct Entry<'a, K, V, const N: usize> {
+    map: &'a mut BoundedMap<K, V, N>,
+    key: K,
+}
+
+impl<'a, K: Copy + Default + Hash + Eq, V: Copy + Default, const N: usize> Entry<'a, K, V, N> {
+    pub fn or_insert(self, default: V) -> &'a mut V {
+        let existed = self.map.contains_key(&self.key);
+        let at = self.map.slot(self.key);
+        if !existed {
+            self.map.values[at] = default;
+        }
+        &mut self.map.values[at]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn grid_parses_into_inline_cells() {
+        let grid: FixedGrid<char, 8> = Grid::parse(["#..", ".#."], |c| c);
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!((grid[1][1], grid.find(&'#')), ('#', Some((0, 0))));
+        let mut stack: BoundedVec<(usize, usize), 2> = BoundedVec::new();
+        stack.push((0, 0));
+        stack.push((1, 1));
+        assert!(stack.is_full());
+        assert_eq!(stack.pop(), Some((1, 1)));
+        assert!(stack.contains(&(0, 0)));
+    }
+
+    #[test]
+    fn queue_wraps_around() {
+        let mut queue: BoundedQueue<u32, 3> = BoundedQueue::new();
+        for round in 0..5 {
+            queue.push_back(round);
+            queue.push_back(round + 100);
+            assert_eq!(queue.pop_front(), Some(round));
+            assert_eq!(queue.pop_front(), Some(round + 100));
+        }
+        assert!(queue.is_empty());
+    }
+
+    #[test]
+    fn set_and_map_fill_every_slot() {
+        let mut set: BoundedSet<(usize, usize), 9> = BoundedSet::new();
+        for x in 0..3 {
+            for y in 0..3 {
+                assert!(set.insert((x, y)));
+            }
+        }
+        assert!(!set.insert((1, 2)));
+        assert!(set.contains(&(2, 2)) && !set.contains(&(3, 0)));
+        assert_eq!(set.iter().count(), 9);
+
+        let mut map: BoundedMap<u16, u64, 4> = BoundedMap::new();
+        assert_eq!(map.insert(7, 1), None);
+        *map.entry(7).or_insert(0) += 10;
+        *map.entry(8).or_insert(5) += 1;
+        assert_eq!(map.insert(7, 2), Some(11));
+        assert_eq!(
+            (map.get(&7), map.get(&8), map.get(&9)),
+            (Some(&2), Some(&6), None)
+        );
+        assert_eq!(map.len(), 2);
+    }
+
+    #[test]
+    #[should_panic(expected = "BoundedSet is full")]
+    fn overflowing_a_set_panics() {
+        let mut set: BoundedSet<u8, 2> = BoundedSet::new();
+        for value in 0..3 {
+            set.insert(value);
+        }
+    }
+}
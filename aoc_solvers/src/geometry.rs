@@ -0,0 +1,268 @@
+//! Points and directions on a 2D map.
+//!
+//! `x` grows to the right and `y` grows down, the way the input is read: north is up the page,
+//! towards row 0. A `Grid` position `(row, col)` is the point `(col, row)`.
+
+use core::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Point2 {
+    pub x: isize,
+    pub y: isize,
+}
+
+impl Point2 {
+    pub const ORIGIN: Point2 = Point2 { x: 0, y: 0 };
+
+    pub const fn new(x: isize, y: isize) -> Self {
+        Point2 { x, y }
+    }
+
+    /// The point at a `Grid` position.
+    pub fn from_grid((row, col): (usize, usize)) -> Self {
+        Point2::new(col as isize, row as isize)
+    }
+
+    /// Back to a `Grid` position, or `None` left of or above the origin.
+    pub fn to_grid(self) -> Option<(usize, usize)> {
+        Some((usize::try_from(self.y).ok()?, usize::try_from(self.x).ok()?))
+    }
+
+    pub fn manhattan(self, other: Point2) -> usize {
+        self.x.abs_diff(other.x) + self.y.abs_diff(other.y)
+    }
+
+    pub fn neighbours4(self) -> [Point2; 4] {
+        Dir4::ALL.map(|dir| self + dir)
+    }
+
+    pub fn neighbours8(self) -> [Point2; 8] {
+        Dir8::ALL.map(|dir| self + dir)
+    }
+}
+
+impl Add for Point2 {
+    type Output = Point2;
+
+    fn add(self, other: Point2) -> Point2 {
+        Point2::new(self.x + other.x, self.y + other.y)
+    }
+}
+
+impl Sub for Point2 {
+    type Output = Point2;
+
+    fn sub(self, other: Point2) -> Point2 {
+        Point2::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+impl AddAssign for Point2 {
+    fn add_assign(&mut self, other: Point2) {
+        *self = *self + other;
+    }
+}
+
+impl SubAssign for Point2 {
+    fn sub_assign(&mut self, other: Point2) {
+        *self = *self - other;
+    }
+}
+
+impl Mul<isize> for Point2 {
+    type Output = Point2;
+
+    fn mul(self, times: isize) -> Point2 {
+        Point2::new(self.x * times, self.y * times)
+    }
+}
+
+impl Neg for Point2 {
+    type Output = Point2;
+
+    fn neg(self) -> Point2 {
+        Point2::new(-self.x, -self.y)
+    }
+}
+
+/// Stepping a point one cell in a direction.
+impl Add<Dir4> for Point2 {
+    type Output = Point2;
+
+    fn add(self, dir: Dir4) -> Point2 {
+        self + dir.delta()
+    }
+}
+
+impl AddAssign<Dir4> for Point2 {
+    fn add_assign(&mut self, dir: Dir4) {
+        *self = *self + dir;
+    }
+}
+
+impl Add<Dir8> for Point2 {
+    type Output = Point2;
+
+    fn add(self, dir: Dir8) -> Point2 {
+        self + dir.delta()
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Dir4 {
+    North,
+    East,
+    South,
+    West,
+}
+
+impl Dir4 {
+    /// Clockwise from north, the same order as `grid::NEIGHBOURS4`.
+    pub const ALL: [Dir4; 4] = [Dir4::North, Dir4::East, Dir4::South, Dir4::West];
+
+    /// `^v<>` arrows or `NSEW` compass letters; `None` for anything else.
+    pub fn from_char(c: char) -> Option<Self> {
+        match c {
+            '^' | 'N' => Some(Dir4::North),
+            '>' | 'E' => Some(Dir4::East),
+            'v' | 'S' => Some(Dir4::South),
+            '<' | 'W' => Some(Dir4::West),
+            _ => None,
+        }
+    }
+
+    pub fn turn_right(self) -> Self {
+        Dir4::ALL[(self as usize + 1) % 4]
+    }
+
+    pub fn turn_left(self) -> Self {
+        Dir4::ALL[(self as usize + 3) % 4]
+    }
+
+    pub fn reverse(self) -> Self {
+        Dir4::ALL[(self as usize + 2) % 4]
+    }
+
+    /// One step this way as a point offset.
+    pub fn delta(self) -> Point2 {
+        match self {
+            Dir4::North => Point2::new(0, -1),
+            Dir4::East => Point2::new(1, 0),
+            Dir4::South => Point2::new(0, 1),
+            Dir4::West => Point2::new(-1, 0),
+        }
+    }
+
+    /// One step this way as a `(row, col)` offset, for `Grid::offset`.
+    pub fn step(self) -> (isize, isize) {
+        let delta = self.delta();
+        (delta.y, delta.x)
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Dir8 {
+    North,
+    NorthEast,
+    East,
+    SouthEast,
+    South,
+    SouthWest,
+    West,
+    NorthWest,
+}
+
+impl Dir8 {
+    /// Clockwise from north.
+    pub const ALL: [Dir8; 8] = [
+        Dir8::North,
+        Dir8::NorthEast,
+        Dir8::East,
+        Dir8::SouthEast,
+        Dir8::South,
+        Dir8::SouthWest,
+        Dir8::West,
+        Dir8::NorthWest,
+    ];
+
+    /// An eighth of a turn clockwise.
+    pub fn turn_right(self) -> Self {
+        Dir8::ALL[(self as usize + 1) % 8]
+    }
+
+    pub fn turn_left(self) -> Self {
+        Dir8::ALL[(self as usize + 7) % 8]
+    }
+
+    pub fn reverse(self) -> Self {
+        Dir8::ALL[(self as usize + 4) % 8]
+    }
+
+    pub fn delta(self) -> Point2 {
+        let (x, y) = match self {
+            Dir8::North => (0, -1),
+            Dir8::NorthEast => (1, -1),
+            Dir8::East => (1, 0),
+            Dir8::SouthEast => (1, 1),
+            Dir8::South => (0, 1),
+            Dir8::SouthWest => (-1, 1),
+            Dir8::West => (-1, 0),
+            Dir8::NorthWest => (-1, -1),
+        };
+        Point2::new(x, y)
+    }
+
+    /// One step this way as a `(row, col)` offset, for `Grid::offset`.
+    pub fn step(self) -> (isize, isize) {
+        let delta = self.delta();
+        (delta.y, delta.x)
+    }
+}
+
+impl From<Dir4> for Dir8 {
+    fn from(dir: Dir4) -> Self {
+        Dir8::ALL[dir as usize * 2]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn turns_go_round_and_reverse() {
+        for dir in Dir4::ALL {
+            assert_eq!(dir.turn_right().turn_left(), dir);
+            assert_eq!(dir.turn_right().turn_right(), dir.reverse());
+            assert_eq!(dir.delta() + dir.reverse().delta(), Point2::ORIGIN);
+            assert_eq!(Dir8::from(dir).delta(), dir.delta());
+        }
+        for dir in Dir8::ALL {
+            assert_eq!(dir.reverse().delta(), -dir.delta());
+            assert_eq!(dir.turn_left().turn_right(), dir);
+        }
+        assert_eq!(Dir4::North.turn_left(), Dir4::West);
+        assert_eq!(Dir8::East.turn_right(), Dir8::SouthEast);
+    }
+
+    #[test]
+    fn arrows_and_compass_letters_agree() {
+        let arrows = "^>v<".chars().map(Dir4::from_char);
+        assert!(arrows.eq("NESW".chars().map(Dir4::from_char)));
+        assert_eq!(Dir4::from_char('v'), Some(Dir4::South));
+        assert_eq!(Dir4::from_char('x'), None);
+    }
+
+    #[test]
+    fn points_add_up() {
+        let start = Point2::from_grid((2, 5));
+        assert_eq!(start, Point2::new(5, 2));
+        assert_eq!(start + Dir4::North, Point2::new(5, 1));
+        assert_eq!((start - Point2::new(1, 1)) * 2, Point2::new(8, 2));
+        assert_eq!(start.manhattan(Point2::new(-1, 4)), 8);
+        assert_eq!((start + Dir4::West).to_grid(), Some((2, 4)));
+        assert_eq!(Point2::new(0, -1).to_grid(), None);
+        assert_eq!(start.neighbours8().len(), 8);
+        assert!(start.neighbours4().contains(&Point2::new(6, 2)));
+    }
+}
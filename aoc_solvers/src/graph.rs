@@ -0,0 +1,389 @@
+//! Graphs whose nodes are named in the input, like `kh-tc` links or `aaa: you hhh` wiring.
+//!
+//! Each label is interned once to a `NodeId`, its index in the graph, and edges are adjacency
+//! lists of ids, so the algorithms work on plain numbers and only go back to labels for output.
+//! Edges are directed; `connect` adds both directions for an undirected graph.
+
+use alloc::borrow::ToOwned;
+use alloc::collections::{BTreeMap, BTreeSet, VecDeque};
+use alloc::string::String;
+use alloc::vec;
+use alloc::vec::Vec;
+use core::borrow::Borrow;
+use core::fmt;
+use core::ops::Range;
+
+use crate::pathfinding::bfs;
+
+pub type NodeId = usize;
+
+#[derive(Clone, Debug)]
+pub struct Graph<L = String> {
+    labels: Vec<L>,
+    ids: BTreeMap<L, NodeId>,
+    edges: Vec<Vec<NodeId>>,
+}
+
+impl<L> Default for Graph<L> {
+    fn default() -> Self {
+        Graph {
+            labels: Vec::new(),
+            ids: BTreeMap::new(),
+            edges: Vec::new(),
+        }
+    }
+}
+
+impl<L: Ord + Clone> Graph<L> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The id for `label`, adding it as a node with no edges the first time it's seen.
+    pub fn node<Q>(&mut self, label: &Q) -> NodeId
+    where
+        L: Borrow<Q>,
+        Q: Ord + ToOwned<Owned = L> + ?Sized,
+    {
+        if let Some(&id) = self.ids.get(label) {
+            return id;
+        }
+        let id = self.labels.len();
+        self.labels.push(label.to_owned());
+        self.ids.insert(label.to_owned(), id);
+        self.edges.push(Vec::new());
+        id
+    }
+
+    /// The id for `label` if it's in the graph.
+    pub fn id<Q>(&self, label: &Q) -> Option<NodeId>
+    where
+        L: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        self.ids.get(label).copied()
+    }
+
+    /// An edge from `from` to `to`, adding either node if it's new. Adding an edge twice
+    /// doesn't make a second one.
+    pub fn add_edge<Q>(&mut self, from: &Q, to: &Q)
+    where
+        L: Borrow<Q>,
+        Q: Ord + ToOwned<Owned = L> + ?Sized,
+    {
+        let (from, to) = (self.node(from), self.node(to));
+        if !self.edges[from].contains(&to) {
+            self.edges[from].push(to);
+        }
+    }
+
+    /// Edges both ways between `a` and `b`.
+    pub fn connect<Q>(&mut self, a: &Q, b: &Q)
+    where
+        L: Borrow<Q>,
+        Q: Ord + ToOwned<Owned = L> + ?Sized,
+    {
+        self.add_edge(a, b);
+        self.add_edge(b, a);
+    }
+
+    /// Just the nodes whose label passes `keep`, with the edges between them.
+    pub fn subgraph(&self, mut keep: impl FnMut(&L) -> bool) -> Self {
+        let mut sub = Graph::new();
+        for id in self.ids() {
+            if keep(&self.labels[id]) {
+                sub.node(&self.labels[id]);
+            }
+        }
+        for (from, label) in self.labels.iter().enumerate() {
+            let Some(sub_from) = sub.id(label) else {
+                continue;
+            };
+            for &to in &self.edges[from] {
+                if let Some(sub_to) = sub.id(&self.labels[to]) {
+                    sub.edges[sub_from].push(sub_to);
+                }
+            }
+        }
+        sub
+    }
+
+    /// Every path from `from` to `to`. Fails if the graph has a cycle anywhere, even one that
+    /// isn't on the way, since then the count might not be finite.
+    pub fn count_paths(&self, from: NodeId, to: NodeId) -> Result<u64, Cycle<L>> {
+        let mut paths = vec![0; self.len()];
+        paths[to] = 1;
+        for node in self.topological_sort()?.into_iter().rev() {
+            if node != to {
+                paths[node] = self.edges[node].iter().map(|&next| paths[next]).sum();
+            }
+        }
+        Ok(paths[from])
+    }
+
+    /// The nodes in an order where every edge points forwards, or the first cycle found if
+    /// there's no such order.
+    pub fn topological_sort(&self) -> Result<Vec<NodeId>, Cycle<L>> {
+        let incoming = self.incoming();
+        let mut waiting: Vec<usize> = incoming.iter().map(Vec::len).collect();
+        let mut ready: VecDeque<NodeId> = self.ids().filter(|&id| waiting[id] == 0).collect();
+        let mut order = Vec::with_capacity(self.len());
+        while let Some(node) = ready.pop_front() {
+            order.push(node);
+            for &next in &self.edges[node] {
+                waiting[next] -= 1;
+                if waiting[next] == 0 {
+                    ready.push_back(next);
+                }
+            }
+        }
+        if order.len() == self.len() {
+            return Ok(order);
+        }
+
+        // Everything left over still has an edge in from something else left over, so walking
+        // those edges backwards has to come round in a loop eventually.
+        let mut node = self.ids().find(|&id| waiting[id] > 0).unwrap();
+        let mut walked = Vec::new();
+        while !walked.contains(&node) {
+            walked.push(node);
+            node = *incoming[node]
+                .iter()
+                .find(|&&prev| waiting[prev] > 0)
+                .unwrap();
+        }
+        let start = walked.iter().position(|&id| id == node).unwrap();
+        let cycle = walked[start..]
+            .iter()
+            .rev()
+            .map(|&id| self.labels[id].clone());
+        Err(Cycle(cycle.collect()))
+    }
+}
+
+impl<L> Graph<L> {
+    pub fn len(&self) -> usize {
+        self.labels.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.labels.is_empty()
+    }
+
+    pub fn ids(&self) -> Range<NodeId> {
+        0..self.labels.len()
+    }
+
+    pub fn label(&self, id: NodeId) -> &L {
+        &self.labels[id]
+    }
+
+    /// Where the edges out of `id` go, in the order they were added.
+    pub fn neighbours(&self, id: NodeId) -> &[NodeId] {
+        &self.edges[id]
+    }
+
+    pub fn has_edge(&self, from: NodeId, to: NodeId) -> bool {
+        self.edges[from].contains(&to)
+    }
+
+    /// Every node that `from` has a path to, itself included, in id order.
+    pub fn reachable(&self, from: NodeId) -> Vec<NodeId> {
+        let steps = bfs(from, |&node| self.edges[node].iter().copied());
+        steps.into_keys().collect()
+    }
+
+    /// The groups of nodes joined by edges, taking edges both ways, each in id order.
+    pub fn components(&self) -> Vec<Vec<NodeId>> {
+        let incoming = self.incoming();
+        let mut seen = vec![false; self.len()];
+        let mut components = Vec::new();
+        for id in self.ids() {
+            if seen[id] {
+                continue;
+            }
+            let either_way = |&node: &NodeId| {
+                let edges = self.edges[node].iter().chain(&incoming[node]);
+                edges.copied()
+            };
+            let component: Vec<NodeId> = bfs(id, either_way).into_keys().collect();
+            for &node in &component {
+                seen[node] = true;
+            }
+            components.push(component);
+        }
+        components
+    }
+
+    /// Every clique, a set of nodes all linked to each other, that can't take another node, in
+    /// an undirected graph. Bron–Kerbosch, pivoting on whichever node rules out the most.
+    pub fn maximal_cliques(&self) -> Vec<Vec<NodeId>> {
+        let linked: Vec<BTreeSet<NodeId>> = self
+            .edges
+            .iter()
+            .enumerate()
+            .map(|(id, edges)| edges.iter().copied().filter(|&n| n != id).collect())
+            .collect();
+        let mut cliques = Vec::new();
+        bron_kerbosch(
+            &linked,
+            &mut Vec::new(),
+            self.ids().collect(),
+            BTreeSet::new(),
+            &mut cliques,
+        );
+        cliques
+    }
+
+    fn incoming(&self) -> Vec<Vec<NodeId>> {
+        let mut incoming = vec![Vec::new(); self.len()];
+        for (from, edges) in self.edges.iter().enumerate() {
+            for &to in edges {
+                incoming[to].push(from);
+            }
+        }
+        incoming
+    }
+}
+
+/// `clique` is linked to all of `candidates` and `excluded`; extends it every way it can using
+/// `candidates`, skipping the ones `excluded` has already covered.
+fn bron_kerbosch(
+    linked: &[BTreeSet<NodeId>],
+    clique: &mut Vec<NodeId>,
+    mut candidates: BTreeSet<NodeId>,
+    mut excluded: BTreeSet<NodeId>,
+    cliques: &mut Vec<Vec<NodeId>>,
+) {
+    let Some(pivot) = candidates
+        .iter()
+        .chain(&excluded)
+        .max_by_key(|&&node| linked[node].intersection(&candidates).count())
+    else {
+        let mut found = clique.clone();
+        found.sort_unstable();
+        cliques.push(found);
+        return;
+    };
+    let to_try: Vec<NodeId> = candidates.difference(&linked[*pivot]).copied().collect();
+    for node in to_try {
+        clique.push(node);
+        let next_candidates = candidates.intersection(&linked[node]).copied().collect();
+        let next_excluded = excluded.intersection(&linked[node]).copied().collect();
+        bron_kerbosch(linked, clique, next_candidates, next_excluded, cliques);
+        clique.pop();
+        candidates.remove(&node);
+        excluded.insert(node);
+    }
+}
+
+/// What `topological_sort` found instead of an order: each node has an edge to the next, and
+/// the last one back to the first.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Cycle<L>(pub Vec<L>);
+
+impl<L: fmt::Display> fmt::Display for Cycle<L> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "graph has a cycle: ")?;
+        for label in &self.0 {
+            write!(f, "{} -> ", label)?;
+        }
+        match self.0.first() {
+            Some(first) => write!(f, "{}", first),
+            None => Ok(()),
+        }
+    }
+}
+
+impl<L: fmt::Debug + fmt::Display> core::error::Error for Cycle<L> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::string::ToString;
+
+    fn labels(graph: &Graph, ids: &[NodeId]) -> Vec<String> {
+        ids.iter().map(|&id| graph.label(id).clone()).collect()
+    }
+
+    #[test]
+    fn interns_labels_and_sorts_edges() {
+        let mut wiring = Graph::new();
+        for (from, to) in [
+            ("you", "a"),
+            ("you", "b"),
+            ("a", "out"),
+            ("b", "out"),
+            ("b", "a"),
+        ] {
+            wiring.add_edge(from, to);
+        }
+        wiring.add_edge("you", "a");
+        assert_eq!(
+            (wiring.len(), wiring.id("out"), wiring.id("nope")),
+            (4, Some(3), None)
+        );
+        assert_eq!(wiring.neighbours(0), [1, 2]);
+
+        let order = wiring.topological_sort().unwrap();
+        assert_eq!(labels(&wiring, &order), ["you", "b", "a", "out"]);
+        let (you, out) = (wiring.id("you").unwrap(), wiring.id("out").unwrap());
+        assert_eq!(wiring.count_paths(you, out), Ok(3));
+        assert_eq!(wiring.reachable(wiring.id("b").unwrap()), [1, 2, 3]);
+
+        wiring.add_edge("out", "you");
+        wiring.add_edge("lone", "you");
+        let cycle = wiring.topological_sort().unwrap_err();
+        assert_eq!(cycle.to_string(), "graph has a cycle: a -> out -> you -> a");
+        assert!(wiring.count_paths(you, out).is_err());
+
+        let mut rules: Graph<i32> = Graph::new();
+        rules.add_edge(&47, &53);
+        rules.add_edge(&53, &29);
+        rules.add_edge(&97, &47);
+        let update = rules.subgraph(|&page| page != 53);
+        let (p47, p97) = (update.id(&47).unwrap(), update.id(&97).unwrap());
+        assert_eq!(update.len(), 3);
+        assert!(update.has_edge(p97, p47) && update.neighbours(p47).is_empty());
+    }
+
+    #[test]
+    fn finds_components_and_cliques() {
+        let mut lan = Graph::new();
+        for link in [
+            "ka-co", "ta-co", "de-co", "ta-ka", "de-ta", "ka-de", "tb-wq", "wh-tb",
+        ] {
+            let (a, b) = link.split_once('-').unwrap();
+            lan.connect(a, b);
+        }
+        let mut components: Vec<Vec<String>> = lan
+            .components()
+            .iter()
+            .map(|ids| labels(&lan, ids))
+            .collect();
+        components.iter_mut().for_each(|names| names.sort());
+        assert_eq!(
+            components,
+            [vec!["co", "de", "ka", "ta"], vec!["tb", "wh", "wq"]]
+        );
+
+        let mut cliques: Vec<Vec<String>> = lan
+            .maximal_cliques()
+            .iter()
+            .map(|ids| {
+                let mut names = labels(&lan, ids);
+                names.sort();
+                names
+            })
+            .collect();
+        cliques.sort();
+        assert_eq!(
+            cliques,
+            [
+                vec!["co", "de", "ka", "ta"],
+                vec!["tb", "wh"],
+                vec!["tb", "wq"]
+            ]
+        );
+    }
+}
@@ -0,0 +1,309 @@
+//! A rectangular grid of cells, for the days whose input is a map.
+//!
+//! Cells are stored row by row in one buffer and addressed as `(row, col)`. Indexing with a
+//! position gives a cell, indexing with a row number gives that row as a slice, so code written
+//! against `Vec<Vec<_>>` as `grid[row][col]` keeps working. The buffer is a `Vec` unless a day
+//! picks something else, like the inline `BoundedVec` behind `fixed::FixedGrid`.
+
+use alloc::vec::Vec;
+use core::fmt;
+use core::marker::PhantomData;
+use core::ops::{Deref, DerefMut, Index, IndexMut};
+
+use crate::byte_lines::ByteLines;
+
+/// Steps to the 4 orthogonal neighbours, as `(row, col)` offsets: up, right, down, left.
+pub const NEIGHBOURS4: [(isize, isize); 4] = [(-1, 0), (0, 1), (1, 0), (0, -1)];
+
+/// Steps to all 8 neighbours, row by row from the top left.
+pub const NEIGHBOURS8: [(isize, isize); 8] = [
+    (-1, -1),
+    (-1, 0),
+    (-1, 1),
+    (0, -1),
+    (0, 1),
+    (1, -1),
+    (1, 0),
+    (1, 1),
+];
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Grid<T, S = Vec<T>> {
+    width: usize,
+    height: usize,
+    cells: S,
+    cell: PhantomData<T>,
+}
+
+impl<T, S: Default + Extend<T> + Deref<Target = [T]>> Grid<T, S> {
+    /// One row per line, turning each char into a cell with `cell`. Panics if the lines aren't
+    /// all the same length, so a map has to be split off from whatever follows it first.
+    pub fn parse<L: AsRef<str>>(
+        lines: impl IntoIterator<Item = L>,
+        mut cell: impl FnMut(char) -> T,
+    ) -> Self {
+        let mut grid = Self::empty();
+        for line in lines {
+            grid.push_row(line.as_ref().chars().map(&mut cell));
+        }
+        grid
+    }
+
+    /// `parse` straight from the input buffer, a byte per cell.
+    pub fn from_bytes(input: &ByteLines<'_>, mut cell: impl FnMut(u8) -> T) -> Self {
+        let mut grid = Self::empty();
+        for line in input.lines() {
+            grid.push_row(line.iter().map(|&byte| cell(byte)));
+        }
+        grid
+    }
+
+    /// A `width` x `height` grid with `cell` called for each position in turn.
+    pub fn from_fn(width: usize, height: usize, mut cell: impl FnMut((usize, usize)) -> T) -> Self {
+        let mut grid = Self::empty();
+        for row in 0..height {
+            grid.push_row((0..width).map(|col| cell((row, col))));
+        }
+        grid
+    }
+
+    pub fn filled(width: usize, height: usize, value: T) -> Self
+    where
+        T: Clone,
+    {
+        Self::from_fn(width, height, |_| value.clone())
+    }
+
+    /// Copies `other` cell by cell into a grid with a different cell type or storage.
+    pub fn map<U, R>(other: &Grid<U, R>, mut cell: impl FnMut(&U) -> T) -> Self
+    where
+        R: Deref<Target = [U]>,
+    {
+        Self::from_fn(other.width, other.height, |pos| cell(&other[pos]))
+    }
+
+    fn empty() -> Self {
+        Grid {
+            width: 0,
+            height: 0,
+            cells: S::default(),
+            cell: PhantomData,
+        }
+    }
+
+    fn push_row(&mut self, row: impl IntoIterator<Item = T>) {
+        let before = self.cells.len();
+        self.cells.extend(row);
+        let width = self.cells.len() - before;
+        if self.height == 0 {
+            self.width = width;
+        } else if width != self.width {
+            panic!(
+                "grid row {} is {} wide, not {}",
+                self.height, width, self.width
+            );
+        }
+        self.height += 1;
+    }
+}
+
+impl<T, S: Deref<Target = [T]>> Grid<T, S> {
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Whether signed coordinates, e.g. from stepping off the edge, land inside the grid.
+    pub fn in_bounds(&self, row: isize, col: isize) -> bool {
+        row >= 0 && col >= 0 && (row as usize) < self.height && (col as usize) < self.width
+    }
+
+    pub fn get(&self, (row, col): (usize, usize)) -> Option<&T> {
+        if row < self.height && col < self.width {
+            Some(&self.cells[row * self.width + col])
+        } else {
+            None
+        }
+    }
+
+    /// `pos` moved by `step`, or `None` if that's off the grid.
+    pub fn offset(
+        &self,
+        (row, col): (usize, usize),
+        (d_row, d_col): (isize, isize),
+    ) -> Option<(usize, usize)> {
+        let row = row.checked_add_signed(d_row)?;
+        let col = col.checked_add_signed(d_col)?;
+        (row < self.height && col < self.width).then_some((row, col))
+    }
+
+    /// The orthogonal neighbours of `pos` that are on the grid, in `NEIGHBOURS4` order.
+    pub fn neighbours4(&self, pos: (usize, usize)) -> impl Iterator<Item = (usize, usize)> + '_ {
+        NEIGHBOURS4
+            .into_iter()
+            .filter_map(move |step| self.offset(pos, step))
+    }
+
+    /// All neighbours of `pos` that are on the grid, diagonals included.
+    pub fn neighbours8(&self, pos: (usize, usize)) -> impl Iterator<Item = (usize, usize)> + '_ {
+        NEIGHBOURS8
+            .into_iter()
+            .filter_map(move |step| self.offset(pos, step))
+    }
+
+    /// Positions from `from` (included) in steps of `step` until the edge: part of a row, a
+    /// column or a diagonal, depending on the step. Empty if `from` is off the grid.
+    pub fn ray(
+        &self,
+        from: (usize, usize),
+        step: (isize, isize),
+    ) -> impl Iterator<Item = (usize, usize)> + '_ {
+        let first = self.get(from).map(|_| from);
+        core::iter::successors(first, move |&pos| self.offset(pos, step))
+    }
+
+    /// Every position, row by row.
+    pub fn positions(&self) -> impl Iterator<Item = (usize, usize)> {
+        let width = self.width;
+        (0..self.height).flat_map(move |row| (0..width).map(move |col| (row, col)))
+    }
+
+    /// Every cell with its position, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = ((usize, usize), &T)> {
+        self.positions().zip(self.cells.iter())
+    }
+
+    /// Where `value` first shows up, reading row by row, like the start marker of a maze.
+    pub fn find(&self, value: &T) -> Option<(usize, usize)>
+    where
+        T: PartialEq,
+    {
+        self.iter()
+            .find(|&(_, cell)| cell == value)
+            .map(|(pos, _)| pos)
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.width.max(1))
+    }
+
+    pub fn column(&self, col: usize) -> impl Iterator<Item = &T> {
+        self.rows().map(move |row| &row[col])
+    }
+}
+
+impl<T, S: DerefMut<Target = [T]>> Grid<T, S> {
+    pub fn get_mut(&mut self, (row, col): (usize, usize)) -> Option<&mut T> {
+        if row < self.height && col < self.width {
+            Some(&mut self.cells[row * self.width + col])
+        } else {
+            None
+        }
+    }
+}
+
+impl<T, S: Deref<Target = [T]>> Index<(usize, usize)> for Grid<T, S> {
+    type Output = T;
+
+    fn index(&self, (row, col): (usize, usize)) -> &T {
+        assert!(
+            col < self.width,
+            "column {} is off a {} wide grid",
+            col,
+            self.width
+        );
+        &self.cells[row * self.width + col]
+    }
+}
+
+impl<T, S: DerefMut<Target = [T]>> IndexMut<(usize, usize)> for Grid<T, S> {
+    fn index_mut(&mut self, (row, col): (usize, usize)) -> &mut T {
+        assert!(
+            col < self.width,
+            "column {} is off a {} wide grid",
+            col,
+            self.width
+        );
+        &mut self.cells[row * self.width + col]
+    }
+}
+
+impl<T, S: Deref<Target = [T]>> Index<usize> for Grid<T, S> {
+    type Output = [T];
+
+    fn index(&self, row: usize) -> &[T] {
+        &self.cells[row * self.width..(row + 1) * self.width]
+    }
+}
+
+impl<T, S: DerefMut<Target = [T]>> IndexMut<usize> for Grid<T, S> {
+    fn index_mut(&mut self, row: usize) -> &mut [T] {
+        &mut self.cells[row * self.width..(row + 1) * self.width]
+    }
+}
+
+/// One line per row, the cells printed back to back: a `Grid<char>` prints as the map it was
+/// parsed from.
+impl<T: fmt::Display, S: Deref<Target = [T]>> fmt::Display for Grid<T, S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in self.rows() {
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::string::ToString;
+
+    const MAZE: [&str; 3] = ["#S.#", "#.##", "#..E"];
+
+    #[test]
+    fn parses_and_prints_back_the_same_map() {
+        let grid: Grid<char> = Grid::parse(MAZE, |c| c);
+        assert_eq!((grid.width(), grid.height()), (4, 3));
+        assert_eq!(
+            (grid.find(&'S'), grid.find(&'E'), grid.find(&'x')),
+            (Some((0, 1)), Some((2, 3)), None)
+        );
+        assert_eq!((grid[(2, 2)], grid[1][1]), ('.', '.'));
+        assert_eq!(grid.to_string(), MAZE.join("\n") + "\n");
+
+        let bytes = MAZE.join("\r\n");
+        let walls: Grid<bool> = Grid::from_bytes(&ByteLines::new(bytes.as_bytes()).unwrap(), |c| c == b'#');
+        assert_eq!(walls, Grid::map(&grid, |&c| c == '#'));
+        assert_eq!(walls.column(0).filter(|&&wall| wall).count(), 3);
+    }
+
+    #[test]
+    fn neighbours_and_rays_stop_at_the_edge() {
+        let grid: Grid<u8> = Grid::from_fn(3, 2, |(row, col)| (row * 3 + col) as u8);
+        assert_eq!(
+            grid.neighbours4((0, 0)).collect::<Vec<_>>(),
+            [(0, 1), (1, 0)]
+        );
+        assert_eq!(grid.neighbours8((1, 1)).count(), 5);
+        let diagonal: Vec<u8> = grid.ray((0, 0), (1, 1)).map(|pos| grid[pos]).collect();
+        assert_eq!(diagonal, [0, 4]);
+        assert_eq!(grid.ray((2, 0), (0, 1)).count(), 0);
+        assert!(grid.in_bounds(1, 2) && !grid.in_bounds(-1, 0) && !grid.in_bounds(0, 3));
+        assert_eq!(
+            (grid.get((0, 3)), grid.offset((1, 2), (0, 1))),
+            (None, None)
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "grid row 1 is 2 wide, not 3")]
+    fn ragged_lines_panic() {
+        let _: Grid<char> = Grid::parse(["...", ".."], |c| c);
+    }
+}
@@ -0,0 +1,23 @@
+use std::fs;
+use std::path::Path;
+
+/// Reads a day's input (or its sample) as lines, from `primary_solver/inputs/<year>/dayNN/`
+/// relative to the workspace root. `None` if the file isn't there.
+pub fn get_input_for_puzzle(day: u8, year: u16, sample: bool) -> Option<Vec<String>> {
+    let content = String::from_utf8(read_input_for_puzzle(day, year, sample)?).ok()?;
+    Some(content.lines().map(String::from).collect())
+}
+
+/// The same file as raw bytes, to wrap in a `ByteLines` instead of splitting it up.
+pub fn read_input_for_puzzle(day: u8, year: u16, sample: bool) -> Option<Vec<u8>> {
+    let daystring = if day < 10 {
+        format!("day0{}", day)
+    } else {
+        format!("day{}", day)
+    };
+    let file_name = if sample { "sample.txt" } else { "file.txt" };
+    let path_str = format!("primary_solver/inputs/{}/{}/{}", year, daystring, file_name);
+    let input_path = Path::new(&path_str);
+    // println!("{:?}", input_path);
+    fs::read(input_path).ok()
+}
@@ -0,0 +1,215 @@
+//! Sets of integers kept as a sorted list of inclusive ranges, for puzzles that hand out
+//! `lower-upper` ranges by the hundred and then ask what they cover.
+//!
+//! Ranges that overlap or touch are merged as they go in, so the list never has two ranges that
+//! could be one, and lookups are a binary search over it.
+
+use alloc::vec::Vec;
+use core::ops::RangeInclusive;
+
+/// Integers an `IntervalSet` can hold.
+pub trait Endpoint: Copy + Ord {
+    /// One more, unless that overflows.
+    fn next(self) -> Option<Self>;
+    /// One less, unless that overflows.
+    fn prev(self) -> Option<Self>;
+    /// How many values `start..=end` covers, saturating at `u64::MAX`.
+    fn count(start: Self, end: Self) -> u64;
+}
+
+macro_rules! endpoint {
+    ($($int:ty),*) => {$(
+        impl Endpoint for $int {
+            fn next(self) -> Option<Self> {
+                self.checked_add(1)
+            }
+
+            fn prev(self) -> Option<Self> {
+                self.checked_sub(1)
+            }
+
+            fn count(start: Self, end: Self) -> u64 {
+                (end.abs_diff(start) as u64).saturating_add(1)
+            }
+        }
+    )*};
+}
+
+endpoint!(u32, u64, usize, i32, i64);
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct IntervalSet<T> {
+    /// `(start, end)` pairs, sorted, with a gap of at least one value between each.
+    ranges: Vec<(T, T)>,
+}
+
+impl<T> Default for IntervalSet<T> {
+    fn default() -> Self {
+        IntervalSet { ranges: Vec::new() }
+    }
+}
+
+impl<T: Endpoint> IntervalSet<T> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds every value in `range`, merging it with any ranges it overlaps or touches.
+    pub fn insert(&mut self, range: RangeInclusive<T>) {
+        let (mut start, mut end) = range.into_inner();
+        if start > end {
+            return;
+        }
+        // The ranges from `first` up to `after` overlap or touch the new one.
+        let first = self
+            .ranges
+            .partition_point(|&(_, e)| e.next().is_some_and(|e| e < start));
+        let after = self
+            .ranges
+            .partition_point(|&(s, _)| s <= end || end.next() == Some(s));
+        if first < after {
+            start = start.min(self.ranges[first].0);
+            end = end.max(self.ranges[after - 1].1);
+        }
+        self.ranges.splice(first..after, [(start, end)]);
+    }
+
+    pub fn contains(&self, value: T) -> bool {
+        let after = self.ranges.partition_point(|&(s, _)| s <= value);
+        after > 0 && value <= self.ranges[after - 1].1
+    }
+
+    /// How many values are in the set, as opposed to `len`, which counts ranges.
+    pub fn total_len(&self) -> u64 {
+        let counts = self.ranges.iter().map(|&(s, e)| T::count(s, e));
+        counts.fold(0, u64::saturating_add)
+    }
+
+    /// The values in both sets.
+    pub fn intersection(&self, other: &Self) -> Self {
+        let mut both = Vec::new();
+        let (mut mine, mut theirs) = (
+            self.ranges.iter().peekable(),
+            other.ranges.iter().peekable(),
+        );
+        while let (Some(&&(s1, e1)), Some(&&(s2, e2))) = (mine.peek(), theirs.peek()) {
+            if s1.max(s2) <= e1.min(e2) {
+                both.push((s1.max(s2), e1.min(e2)));
+            }
+            // Whichever ends first can't overlap anything further along the other list.
+            if e1 < e2 {
+                mine.next();
+            } else {
+                theirs.next();
+            }
+        }
+        IntervalSet { ranges: both }
+    }
+
+    /// The values in this set but not in `other`.
+    pub fn difference(&self, other: &Self) -> Self {
+        let mut left = Vec::new();
+        let mut cuts = other.ranges.iter().peekable();
+        for &(start, end) in &self.ranges {
+            let mut start = Some(start);
+            while let Some(from) = start {
+                // Skip cuts that end before what's left of this range.
+                while cuts.next_if(|&&(_, cut_end)| cut_end < from).is_some() {}
+                match cuts.peek() {
+                    Some(&&(cut_start, cut_end)) if cut_start <= end => {
+                        if from < cut_start {
+                            left.push((from, cut_start.prev().unwrap()));
+                        }
+                        start = cut_end.next().filter(|&after| after <= end);
+                    }
+                    _ => {
+                        left.push((from, end));
+                        start = None;
+                    }
+                }
+            }
+        }
+        IntervalSet { ranges: left }
+    }
+}
+
+impl<T: Copy> IntervalSet<T> {
+    /// How many separate ranges the set is made of.
+    pub fn len(&self) -> usize {
+        self.ranges.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+
+    /// The merged ranges, lowest first.
+    pub fn iter(&self) -> impl Iterator<Item = RangeInclusive<T>> + '_ {
+        self.ranges.iter().map(|&(start, end)| start..=end)
+    }
+}
+
+impl<T: Endpoint> Extend<RangeInclusive<T>> for IntervalSet<T> {
+    fn extend<I: IntoIterator<Item = RangeInclusive<T>>>(&mut self, ranges: I) {
+        for range in ranges {
+            self.insert(range);
+        }
+    }
+}
+
+impl<T: Endpoint> FromIterator<RangeInclusive<T>> for IntervalSet<T> {
+    fn from_iter<I: IntoIterator<Item = RangeInclusive<T>>>(ranges: I) -> Self {
+        let mut set = IntervalSet::new();
+        set.extend(ranges);
+        set
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec;
+
+    #[test]
+    fn inserts_merge_overlapping_and_touching_ranges() {
+        let fresh: IntervalSet<u64> = [3..=5, 10..=14, 16..=20, 12..=18, 21..=21]
+            .into_iter()
+            .collect();
+        assert_eq!(fresh.iter().collect::<Vec<_>>(), [3..=5, 10..=21]);
+        assert_eq!((fresh.len(), fresh.total_len()), (2, 15));
+        let hits: Vec<u64> = [1, 3, 5, 6, 9, 10, 17, 21, 22]
+            .into_iter()
+            .filter(|&id| fresh.contains(id))
+            .collect();
+        assert_eq!(hits, [3, 5, 10, 17, 21]);
+
+        let mut edges: IntervalSet<i64> = IntervalSet::new();
+        edges.insert(i64::MAX - 1..=i64::MAX);
+        edges.insert(i64::MIN..=i64::MIN + 1);
+        // Empty ranges are ignored, not stored.
+        let backwards = RangeInclusive::new(5, 4);
+        edges.insert(backwards);
+        assert_eq!((edges.len(), edges.total_len()), (2, 4));
+        assert!(edges.contains(i64::MAX) && !edges.contains(0));
+    }
+
+    #[test]
+    fn intersection_and_difference() {
+        let a: IntervalSet<u32> = vec![0..=10, 20..=30, 40..=40].into_iter().collect();
+        let b: IntervalSet<u32> = vec![5..=25, 30..=45].into_iter().collect();
+        let both = a.intersection(&b);
+        assert_eq!(
+            both.iter().collect::<Vec<_>>(),
+            [5..=10, 20..=25, 30..=30, 40..=40]
+        );
+        let only_a = a.difference(&b);
+        assert_eq!(only_a.iter().collect::<Vec<_>>(), [0..=4, 26..=29]);
+        let only_b = b.difference(&a);
+        assert_eq!(
+            only_b.iter().collect::<Vec<_>>(),
+            [11..=19, 31..=39, 41..=45]
+        );
+        assert_eq!(only_a.total_len() + both.total_len(), a.total_len());
+        assert!(a.difference(&a).is_empty());
+    }
+}
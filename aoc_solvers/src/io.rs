@@ -0,0 +1,42 @@
+//! The `io::Result` the solvers return. With `std` it is the real one, so host callers can treat
+//! solver failures like any other I/O error; without it, a stand-in with just a message.
+
+#[cfg(feature = "std")]
+pub use std::io::{Error, ErrorKind, Result};
+
+#[cfg(not(feature = "std"))]
+pub use no_std::{Error, Result};
+
+#[cfg(not(feature = "std"))]
+mod no_std {
+    use alloc::string::String;
+    use core::fmt;
+
+    #[derive(Debug)]
+    pub struct Error {
+        message: String,
+    }
+
+    impl Error {
+        pub fn new(message: impl Into<String>) -> Self {
+            Error {
+                message: message.into(),
+            }
+        }
+
+        /// Mirrors `std::io::Error::other`, so solvers can fail the same way either way.
+        pub fn other(message: impl Into<String>) -> Self {
+            Self::new(message)
+        }
+    }
+
+    impl fmt::Display for Error {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.write_str(&self.message)
+        }
+    }
+
+    impl core::error::Error for Error {}
+
+    pub type Result<T> = core::result::Result<T, Error>;
+}
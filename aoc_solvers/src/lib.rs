@@ -0,0 +1,45 @@
+//! The Advent of Code solutions, the `DaySolver` trait they implement and loading their input.
+//! Single-pass days also implement `LineSolver`, so they can be fed one line at a time.
+//! The `primary_solver` binary runs them on the host and drives the Pico; anything else in the
+//! workspace that wants an answer can depend on this directly.
+//!
+//! With the default `std` feature off this is `no_std` + `alloc`, and only the days that need
+//! nothing more are built (see `SOLVABLE_DAYS`), so the firmware can link them. The `fixed`
+//! feature switches the days that support it over to the inline structures in `fixed`.
+#![cfg_attr(all(not(feature = "std"), not(test)), no_std)]
+
+extern crate alloc;
+// So `#[derive(PuzzleInput)]`, which names everything `::aoc_solvers::...`, works in here too.
+extern crate self as aoc_solvers;
+
+#[macro_use]
+mod macros;
+
+pub mod aoc2024;
+pub mod aoc2025;
+pub mod byte_lines;
+pub mod disjoint_set;
+pub mod fixed;
+pub mod geometry;
+pub mod graph;
+pub mod grid;
+#[cfg(any(feature = "std", test))]
+pub mod input;
+pub mod interval_set;
+pub mod io;
+pub mod parse;
+pub mod pathfinding;
+pub mod puzzle;
+pub mod solver;
+pub mod stream;
+#[cfg(test)]
+mod testing;
+
+pub use byte_lines::ByteLines;
+pub use geometry::{Dir4, Dir8, Point2};
+pub use grid::Grid;
+#[cfg(any(feature = "std", test))]
+pub use input::{get_input_for_puzzle, read_input_for_puzzle};
+pub use puzzle::Puzzle;
+pub use solver::{DaySolver, SOLVABLE_DAYS, solve_puzzle, solve_puzzle_bytes};
+pub use stream::{LineSolver, STREAMABLE_DAYS, line_solver, solve_lines};
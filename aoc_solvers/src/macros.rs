@@ -0,0 +1,19 @@
+// Without `std` there is nowhere to print to, so the solvers' running commentary is dropped.
+// The arguments are still type checked, so a no_std build can't hide a broken `println!`.
+
+#[cfg(not(feature = "std"))]
+macro_rules! println {
+    () => {};
+    ($($arg:tt)*) => {{
+        let _ = format_args!($($arg)*);
+    }};
+}
+
+// Only the `fixed` days print piecemeal, so a plain no_std build has no use for this one.
+#[cfg(not(feature = "std"))]
+#[allow(unused_macros)]
+macro_rules! print {
+    ($($arg:tt)*) => {{
+        let _ = format_args!($($arg)*);
+    }};
+}
@@ -0,0 +1,556 @@
+//! The bits of input parsing every other day repeats: blank-line sections, every integer on a
+//! line, `key: value` lines, `a-b` pairs and comma (or whatever) separated lists.
+//!
+//! Everything works on `Line`s, which remember where they came from, so a bad input says which
+//! line it choked on. A `ParseError` turns into an `io::Error`, so days can just use `?`.
+//!
+//! Days whose input is a few differently shaped sections can instead declare it as a struct
+//! and `#[derive(PuzzleInput)]`; see `aoc_derive` for the attributes.
+
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt;
+use core::ops::Deref;
+use core::str::FromStr;
+
+use crate::grid::Grid;
+use crate::io;
+
+pub use aoc_derive::PuzzleInput;
+
+/// What couldn't be parsed, and on which line of the input (counting from 1).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseError {
+    pub line: usize,
+    pub message: String,
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "line {}: {}", self.line, self.message)
+    }
+}
+
+impl core::error::Error for ParseError {}
+
+impl From<ParseError> for io::Error {
+    #[cfg(feature = "std")]
+    fn from(err: ParseError) -> Self {
+        io::Error::new(io::ErrorKind::InvalidData, err)
+    }
+
+    #[cfg(not(feature = "std"))]
+    fn from(err: ParseError) -> Self {
+        use alloc::string::ToString;
+        io::Error::new(err.to_string())
+    }
+}
+
+/// One line of input and its line number.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Line<'a> {
+    pub number: usize,
+    pub text: &'a str,
+}
+
+impl<'a> Line<'a> {
+    pub fn error(&self, message: impl Into<String>) -> ParseError {
+        ParseError {
+            line: self.number,
+            message: message.into(),
+        }
+    }
+
+    /// The same line with different text, for carrying the line number into a piece of it.
+    fn with(&self, text: &'a str) -> Line<'a> {
+        Line {
+            number: self.number,
+            text,
+        }
+    }
+
+    /// The whole line, trimmed, as a `T`.
+    pub fn parse<T>(&self) -> Result<T, ParseError>
+    where
+        T: FromStr,
+        T::Err: fmt::Display,
+    {
+        let text = self.text.trim();
+        text.parse()
+            .map_err(|err| self.error(format!("{text:?}: {err}")))
+    }
+
+    /// Every integer on the line, in order, ignoring whatever is around them. A `-` right
+    /// before the digits makes it negative, so `3-5` is 3 and -5; use `pair` for ranges.
+    pub fn ints<T>(&self) -> Result<Vec<T>, ParseError>
+    where
+        T: FromStr,
+        T::Err: fmt::Display,
+    {
+        let bytes = self.text.as_bytes();
+        let mut found = Vec::new();
+        let mut i = 0;
+        while i < bytes.len() {
+            let negative = bytes[i] == b'-' && bytes.get(i + 1).is_some_and(u8::is_ascii_digit);
+            if !negative && !bytes[i].is_ascii_digit() {
+                i += 1;
+                continue;
+            }
+            let start = i;
+            i += 1;
+            while i < bytes.len() && bytes[i].is_ascii_digit() {
+                i += 1;
+            }
+            found.push(self.with(&self.text[start..i]).parse()?);
+        }
+        Ok(found)
+    }
+
+    /// `ints`, when the line should have exactly `N` of them.
+    pub fn int_array<T, const N: usize>(&self) -> Result<[T; N], ParseError>
+    where
+        T: FromStr,
+        T::Err: fmt::Display,
+    {
+        let found = self.ints()?;
+        let count = found.len();
+        found
+            .try_into()
+            .map_err(|_| self.error(format!("expected {N} numbers, found {count}")))
+    }
+
+    /// Splits `key: value` at the first `sep`, both sides trimmed. Both halves keep the line
+    /// number, so they can be parsed further.
+    pub fn key_value(&self, sep: &str) -> Result<(Line<'a>, Line<'a>), ParseError> {
+        let (key, value) = self
+            .text
+            .split_once(sep)
+            .ok_or_else(|| self.error(format!("expected `{sep}` in {:?}", self.text)))?;
+        Ok((self.with(key.trim()), self.with(value.trim())))
+    }
+
+    /// Two values either side of the first `sep`, like the ends of `10-14` or `12x5`.
+    pub fn pair<T>(&self, sep: char) -> Result<(T, T), ParseError>
+    where
+        T: FromStr,
+        T::Err: fmt::Display,
+    {
+        let (a, b) = self
+            .text
+            .split_once(sep)
+            .ok_or_else(|| self.error(format!("expected `{sep}` in {:?}", self.text)))?;
+        Ok((self.with(a).parse()?, self.with(b).parse()?))
+    }
+
+    /// The pieces between each `sep`, trimmed and parsed, skipping empty ones so that runs of
+    /// spaces or a trailing comma don't matter.
+    pub fn list<T>(&self, sep: &str) -> Result<Vec<T>, ParseError>
+    where
+        T: FromStr,
+        T::Err: fmt::Display,
+    {
+        self.text
+            .split(sep)
+            .filter(|piece| !piece.trim().is_empty())
+            .map(|piece| self.with(piece).parse())
+            .collect()
+    }
+}
+
+/// Every line of `lines`, numbered from 1.
+pub fn numbered(lines: &[String]) -> impl Iterator<Item = Line<'_>> {
+    numbered_from(lines, 1)
+}
+
+fn numbered_from(lines: &[String], first: usize) -> impl Iterator<Item = Line<'_>> {
+    let numbers = first..;
+    numbers
+        .zip(lines)
+        .map(|(number, text)| Line { number, text })
+}
+
+/// A run of lines between blank ones. Derefs to the lines themselves, for code that just
+/// wants a `&[String]`.
+#[derive(Clone, Copy, Debug)]
+pub struct Section<'a> {
+    /// Line number of the first line.
+    pub first_line: usize,
+    lines: &'a [String],
+}
+
+impl<'a> Section<'a> {
+    pub fn lines(&self) -> impl Iterator<Item = Line<'a>> + use<'a> {
+        numbered_from(self.lines, self.first_line)
+    }
+
+    /// Line `index` of the section (from 0), or an error naming where it should have been.
+    pub fn line(&self, index: usize) -> Result<Line<'a>, ParseError> {
+        self.lines().nth(index).ok_or_else(|| ParseError {
+            line: self.first_line + self.lines.len(),
+            message: format!(
+                "section starting on line {} has {} lines, expected at least {}",
+                self.first_line,
+                self.lines.len(),
+                index + 1
+            ),
+        })
+    }
+}
+
+impl Deref for Section<'_> {
+    type Target = [String];
+
+    fn deref(&self) -> &[String] {
+        self.lines
+    }
+}
+
+/// The blank-line separated sections of `lines`, in order. Blank lines (whitespace counts as
+/// blank) are dropped, however many there are in a row.
+pub fn sections(lines: &[String]) -> Vec<Section<'_>> {
+    let mut found = Vec::new();
+    let mut start = 0;
+    for end in 0..=lines.len() {
+        if end == lines.len() || lines[end].trim().is_empty() {
+            if start < end {
+                found.push(Section {
+                    first_line: start + 1,
+                    lines: &lines[start..end],
+                });
+            }
+            start = end + 1;
+        }
+    }
+    found
+}
+
+/// `sections`, when the input should have exactly `N` of them.
+pub fn expect_sections<const N: usize>(lines: &[String]) -> Result<[Section<'_>; N], ParseError> {
+    let found = sections(lines);
+    let count = found.len();
+    found.try_into().map_err(|_| ParseError {
+        line: lines.len(),
+        message: format!("expected {N} sections separated by blank lines, found {count}"),
+    })
+}
+
+/// How one section becomes a field of a `#[derive(PuzzleInput)]` struct.
+pub trait FromSection: Sized {
+    fn from_section(section: Section<'_>) -> Result<Self, ParseError>;
+}
+
+/// One value per line.
+impl<T> FromSection for Vec<T>
+where
+    T: FromStr,
+    T::Err: fmt::Display,
+{
+    fn from_section(section: Section<'_>) -> Result<Self, ParseError> {
+        section.lines().map(|line| line.parse()).collect()
+    }
+}
+
+/// The lines run together, for a long string that got wrapped.
+impl FromSection for String {
+    fn from_section(section: Section<'_>) -> Result<Self, ParseError> {
+        Ok(section.concat())
+    }
+}
+
+/// A map, one row per line. Every row has to be as wide as the first.
+impl FromSection for Grid<char> {
+    fn from_section(section: Section<'_>) -> Result<Self, ParseError> {
+        let width = section.line(0)?.text.chars().count();
+        for line in section.lines() {
+            let row = line.text.chars().count();
+            if row != width {
+                return Err(line.error(format!("row is {} wide, the first row is {}", row, width)));
+            }
+        }
+        Ok(Grid::parse(section.iter(), |c| c))
+    }
+}
+
+/// A whole input, one field per section. Derive it rather than writing it by hand.
+pub trait PuzzleInput: Sized {
+    fn from_sections(sections: &mut SectionCursor<'_>) -> Result<Self, ParseError>;
+
+    /// Parses `lines`, which have to have exactly the sections the fields take.
+    fn parse_input(lines: &[String]) -> Result<Self, ParseError> {
+        let mut sections = SectionCursor::new(lines);
+        let input = Self::from_sections(&mut sections)?;
+        sections.finish()?;
+        Ok(input)
+    }
+}
+
+/// An input's sections, handed out in order to the fields of a `PuzzleInput`.
+pub struct SectionCursor<'a> {
+    sections: Vec<Section<'a>>,
+    next: usize,
+    /// Where a missing section gets reported: the end of the input.
+    last_line: usize,
+}
+
+impl<'a> SectionCursor<'a> {
+    pub fn new(lines: &'a [String]) -> Self {
+        SectionCursor {
+            sections: sections(lines),
+            next: 0,
+            last_line: lines.len(),
+        }
+    }
+
+    fn take(&mut self, field: &str) -> Result<Section<'a>, ParseError> {
+        let section = self
+            .sections
+            .get(self.next)
+            .copied()
+            .ok_or_else(|| ParseError {
+                line: self.last_line,
+                message: format!("missing the `{field}` section"),
+            })?;
+        self.next += 1;
+        Ok(section)
+    }
+
+    /// The next section, as a `T`.
+    pub fn next<T: FromSection>(&mut self, field: &str) -> Result<T, ParseError> {
+        T::from_section(self.take(field)?)
+    }
+
+    /// The next section, with each of its lines split on `sep`.
+    pub fn next_list<T>(&mut self, field: &str, sep: &str) -> Result<Vec<Vec<T>>, ParseError>
+    where
+        T: FromStr,
+        T::Err: fmt::Display,
+    {
+        self.take(field)?
+            .lines()
+            .map(|line| line.list(sep))
+            .collect()
+    }
+
+    /// Every section up to the last `keep`, each as a `T`. Possibly none.
+    pub fn repeat<T: FromSection>(&mut self, keep: usize) -> Result<Vec<T>, ParseError> {
+        let end = self.sections.len().saturating_sub(keep).max(self.next);
+        let taken = self.sections[self.next..end].iter();
+        self.next = end;
+        taken.map(|&section| T::from_section(section)).collect()
+    }
+
+    /// Fails if there are sections left that nothing took.
+    pub fn finish(self) -> Result<(), ParseError> {
+        match self.sections.get(self.next) {
+            Some(extra) => Err(ParseError {
+                line: extra.first_line,
+                message: format!(
+                    "expected {} sections separated by blank lines, found {}",
+                    self.next,
+                    self.sections.len()
+                ),
+            }),
+            None => Ok(()),
+        }
+    }
+}
+
+/// Why a line didn't fit a `#[puzzle(line = "...")]` pattern. `Line::parse` adds which line.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PatternError(pub String);
+
+impl fmt::Display for PatternError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+// The rest are what the derived `FromStr` for a `line` pattern is made of.
+
+pub fn strip_literal<'t>(text: &'t str, prefix: &str) -> Result<&'t str, PatternError> {
+    text.strip_prefix(prefix)
+        .ok_or_else(|| PatternError(format!("expected it to start with {prefix:?}")))
+}
+
+pub fn strip_end<'t>(text: &'t str, suffix: &str) -> Result<&'t str, PatternError> {
+    text.strip_suffix(suffix)
+        .ok_or_else(|| PatternError(format!("expected it to end with {suffix:?}")))
+}
+
+/// `field`'s text, up to `sep`, and what's left after it.
+pub fn split_field<'t>(
+    text: &'t str,
+    sep: &str,
+    field: &str,
+) -> Result<(&'t str, &'t str), PatternError> {
+    text.split_once(sep)
+        .ok_or_else(|| PatternError(format!("expected {sep:?} after `{field}`")))
+}
+
+pub fn field<T>(text: &str, field: &str) -> Result<T, PatternError>
+where
+    T: FromStr,
+    T::Err: fmt::Display,
+{
+    let text = text.trim();
+    text.parse()
+        .map_err(|err| PatternError(format!("`{field}` {text:?}: {err}")))
+}
+
+pub fn field_list<T>(text: &str, sep: &str, name: &str) -> Result<Vec<T>, PatternError>
+where
+    T: FromStr,
+    T::Err: fmt::Display,
+{
+    text.split(sep)
+        .filter(|piece| !piece.trim().is_empty())
+        .map(|piece| field(piece, name))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec;
+
+    fn input(text: &str) -> Vec<String> {
+        text.lines().map(String::from).collect()
+    }
+
+    #[test]
+    fn sections_split_on_blank_lines() {
+        let lines = input("Register A: 729\n\n\n  \nProgram: 0,1,5,4\nx\n");
+        let found = sections(&lines);
+        assert_eq!(found.len(), 2);
+        assert_eq!((found[0].first_line, found[1].first_line), (1, 5));
+        assert_eq!(found[1].len(), 2);
+
+        let [registers, program] = expect_sections(&lines).unwrap();
+        let (key, value) = registers.line(0).unwrap().key_value(":").unwrap();
+        assert_eq!(
+            (key.text, value.parse::<i64>().unwrap()),
+            ("Register A", 729)
+        );
+        let (_, ops) = program.line(0).unwrap().key_value(":").unwrap();
+        assert_eq!(ops.list::<u8>(",").unwrap(), [0, 1, 5, 4]);
+        assert_eq!(program.line(2).unwrap_err().line, 7);
+        assert_eq!(
+            expect_sections::<3>(&lines).unwrap_err().to_string(),
+            "line 6: expected 3 sections separated by blank lines, found 2"
+        );
+    }
+
+    #[test]
+    fn numbers_and_pairs() {
+        let lines = input("p=0,4 v=3,-3\nButton A: X+94, Y+34\n10-14\n12x5: 1 0  2\n3,x");
+        let lines: Vec<Line> = numbered(&lines).collect();
+        assert_eq!(lines[0].ints::<i32>().unwrap(), [0, 4, 3, -3]);
+        assert_eq!(lines[1].int_array::<u64, 2>().unwrap(), [94, 34]);
+        assert_eq!(lines[2].pair::<u64>('-').unwrap(), (10, 14));
+        let (size, counts) = lines[3].key_value(":").unwrap();
+        assert_eq!(size.pair::<usize>('x').unwrap(), (12, 5));
+        assert_eq!(counts.list::<u8>(" ").unwrap(), vec![1, 0, 2]);
+
+        let err = lines[4].list::<u8>(",").unwrap_err();
+        assert_eq!(err.line, 5);
+        assert!(err.to_string().starts_with("line 5: \"x\": "));
+        assert_eq!(
+            lines[1].int_array::<u64, 3>().unwrap_err().message,
+            "expected 3 numbers, found 2"
+        );
+        assert!(lines[0].pair::<u8>('-').is_err());
+    }
+
+    #[derive(Debug, PuzzleInput)]
+    #[puzzle(line = "{width}x{height}: {counts}")]
+    struct Region {
+        width: u8,
+        height: u8,
+        #[puzzle(list = " ")]
+        counts: Vec<u8>,
+    }
+
+    #[derive(Debug, PuzzleInput)]
+    struct Farm {
+        #[puzzle(repeat)]
+        shapes: Vec<Vec<String>>,
+        regions: Vec<Region>,
+    }
+
+    #[derive(Debug, PartialEq, PuzzleInput)]
+    #[puzzle(line = "<{x},{y}>")]
+    struct Point {
+        x: i32,
+        y: i32,
+    }
+
+    #[derive(Debug, PuzzleInput)]
+    struct Manual {
+        points: Vec<Point>,
+        #[puzzle(list = ",")]
+        updates: Vec<Vec<i32>>,
+        moves: String,
+    }
+
+    #[derive(Debug, PuzzleInput)]
+    struct Warehouse {
+        map: Grid<char>,
+        moves: String,
+    }
+
+    #[test]
+    fn derived_inputs() {
+        let farm = Farm::parse_input(&input("0:\n##\n\n1:\n.#\n\n4x4: 0 2\n12x5: 1 0 1")).unwrap();
+        assert_eq!(farm.shapes, [vec!["0:", "##"], vec!["1:", ".#"]]);
+        let region = &farm.regions[1];
+        assert_eq!(
+            (region.width, region.height, &region.counts[..]),
+            (12, 5, &[1, 0, 1][..])
+        );
+        let shapeless = Farm::parse_input(&input("1x1: 3")).unwrap();
+        assert!(shapeless.shapes.is_empty() && shapeless.regions.len() == 1);
+
+        let manual = Manual::parse_input(&input("<1,-2>\n<3,4>\n\n5,6\n\n<^\nv>")).unwrap();
+        assert_eq!(manual.points[0], Point { x: 1, y: -2 });
+        assert_eq!(
+            (manual.updates, &manual.moves[..]),
+            (vec![vec![5, 6]], "<^v>")
+        );
+        let warehouse = Warehouse::parse_input(&input("#.#\n.@.\n\n<^")).unwrap();
+        assert_eq!((warehouse.map[(1, 1)], &warehouse.moves[..]), ('@', "<^"));
+
+        let errors = [
+            (Farm::parse_input(&input("0:\n\n4x4 0 2")).unwrap_err(), 3),
+            (Farm::parse_input(&input("4x4: 0 x")).unwrap_err(), 1),
+            (Manual::parse_input(&input("<1,2\n\n5")).unwrap_err(), 1),
+            (Manual::parse_input(&input("<1,2>\n\n5")).unwrap_err(), 3),
+            (
+                Manual::parse_input(&input("<1,2>\n\n5\n\nv\n\nv")).unwrap_err(),
+                7,
+            ),
+            (
+                Warehouse::parse_input(&input("#.#\n.@\n\n<^")).unwrap_err(),
+                2,
+            ),
+        ];
+        let messages: Vec<String> = errors
+            .iter()
+            .map(|(err, line)| {
+                assert_eq!(err.line, *line);
+                err.message.clone()
+            })
+            .collect();
+        assert_eq!(
+            messages,
+            [
+                "\"4x4 0 2\": expected \": \" after `height`",
+                "\"4x4: 0 x\": `counts` \"x\": invalid digit found in string",
+                "\"<1,2\": expected it to end with \">\"",
+                "missing the `moves` section",
+                "expected 3 sections separated by blank lines, found 4",
+                "row is 2 wide, the first row is 3",
+            ]
+        );
+    }
+}
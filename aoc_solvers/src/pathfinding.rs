@@ -0,0 +1,229 @@
+//! Shortest-path searches over any node type.
+//!
+//! A day describes its puzzle as a start node and a function giving each node's neighbours
+//! (with the cost of the step, for the weighted searches); the search does the bookkeeping.
+//! Nodes are kept in `BTreeMap`s, so they need `Ord` rather than `Hash`, which keeps this
+//! usable without `std`. Costs are `usize`.
+
+use alloc::collections::{BTreeMap, BTreeSet, BinaryHeap, VecDeque};
+use alloc::vec;
+use alloc::vec::Vec;
+use core::cmp::Reverse;
+
+/// Fewest steps from `start` to every node it can reach, `start` itself included at 0.
+pub fn bfs<N, I>(start: N, mut neighbours: impl FnMut(&N) -> I) -> BTreeMap<N, usize>
+where
+    N: Ord + Clone,
+    I: IntoIterator<Item = N>,
+{
+    let mut steps = BTreeMap::from([(start.clone(), 0)]);
+    let mut queue = VecDeque::from([(start, 0)]);
+    while let Some((node, taken)) = queue.pop_front() {
+        for next in neighbours(&node) {
+            if !steps.contains_key(&next) {
+                steps.insert(next.clone(), taken + 1);
+                queue.push_back((next, taken + 1));
+            }
+        }
+    }
+    steps
+}
+
+/// The nearest node that is a `goal` and how many steps away it is, without exploring any
+/// further than that. `None` if no goal can be reached.
+pub fn bfs_to<N, I>(
+    start: N,
+    mut neighbours: impl FnMut(&N) -> I,
+    mut goal: impl FnMut(&N) -> bool,
+) -> Option<(N, usize)>
+where
+    N: Ord + Clone,
+    I: IntoIterator<Item = N>,
+{
+    let mut seen = BTreeSet::from([start.clone()]);
+    let mut queue = VecDeque::from([(start, 0)]);
+    while let Some((node, taken)) = queue.pop_front() {
+        if goal(&node) {
+            return Some((node, taken));
+        }
+        for next in neighbours(&node) {
+            if seen.insert(next.clone()) {
+                queue.push_back((next, taken + 1));
+            }
+        }
+    }
+    None
+}
+
+/// Cheapest cost from `start` to every node it can reach, where `neighbours` gives each next
+/// node with the cost of getting there.
+pub fn dijkstra<N, I>(start: N, neighbours: impl FnMut(&N) -> I) -> BTreeMap<N, usize>
+where
+    N: Ord + Clone,
+    I: IntoIterator<Item = (N, usize)>,
+{
+    dijkstra_all(start, neighbours).costs
+}
+
+/// The cheapest node that is a `goal` and what it costs to get there.
+pub fn dijkstra_to<N, I>(
+    start: N,
+    neighbours: impl FnMut(&N) -> I,
+    goal: impl FnMut(&N) -> bool,
+) -> Option<(N, usize)>
+where
+    N: Ord + Clone,
+    I: IntoIterator<Item = (N, usize)>,
+{
+    astar(start, neighbours, |_| 0, goal)
+}
+
+/// `dijkstra_to` steered by `heuristic`, a guess at the cost left from a node to the nearest
+/// goal. The answer is only guaranteed cheapest if the guess never overestimates.
+pub fn astar<N, I>(
+    start: N,
+    mut neighbours: impl FnMut(&N) -> I,
+    mut heuristic: impl FnMut(&N) -> usize,
+    mut goal: impl FnMut(&N) -> bool,
+) -> Option<(N, usize)>
+where
+    N: Ord + Clone,
+    I: IntoIterator<Item = (N, usize)>,
+{
+    let mut costs = BTreeMap::from([(start.clone(), 0)]);
+    let mut queue = BinaryHeap::from([Reverse((heuristic(&start), 0, start))]);
+    while let Some(Reverse((_, cost, node))) = queue.pop() {
+        if costs.get(&node).is_some_and(|&best| best < cost) {
+            continue;
+        }
+        if goal(&node) {
+            return Some((node, cost));
+        }
+        for (next, step) in neighbours(&node) {
+            let next_cost = cost + step;
+            if costs.get(&next).is_none_or(|&best| next_cost < best) {
+                costs.insert(next.clone(), next_cost);
+                queue.push(Reverse((next_cost + heuristic(&next), next_cost, next)));
+            }
+        }
+    }
+    None
+}
+
+/// What `dijkstra_all` found: the cheapest cost to each node, and every node that a cheapest
+/// path to it can come from.
+pub struct ShortestPaths<N> {
+    pub costs: BTreeMap<N, usize>,
+    pub parents: BTreeMap<N, Vec<N>>,
+}
+
+impl<N: Ord + Clone> ShortestPaths<N> {
+    pub fn cost(&self, node: &N) -> Option<usize> {
+        self.costs.get(node).copied()
+    }
+
+    /// Every node on any cheapest path from the start to one of `ends`, the ends included.
+    pub fn on_paths_to(&self, ends: impl IntoIterator<Item = N>) -> BTreeSet<N> {
+        let mut on_paths = BTreeSet::new();
+        let mut stack: Vec<N> = ends.into_iter().collect();
+        while let Some(node) = stack.pop() {
+            if !on_paths.insert(node.clone()) {
+                continue;
+            }
+            if let Some(parents) = self.parents.get(&node) {
+                stack.extend(parents.iter().cloned());
+            }
+        }
+        on_paths
+    }
+}
+
+/// Dijkstra from `start` over everything reachable, keeping track of ties so that every
+/// cheapest path can be walked back afterwards, not just one.
+pub fn dijkstra_all<N, I>(start: N, mut neighbours: impl FnMut(&N) -> I) -> ShortestPaths<N>
+where
+    N: Ord + Clone,
+    I: IntoIterator<Item = (N, usize)>,
+{
+    let mut found = ShortestPaths {
+        costs: BTreeMap::from([(start.clone(), 0)]),
+        parents: BTreeMap::new(),
+    };
+    let mut queue = BinaryHeap::from([Reverse((0, start))]);
+    while let Some(Reverse((cost, node))) = queue.pop() {
+        if found.costs.get(&node).is_some_and(|&best| best < cost) {
+            continue;
+        }
+        for (next, step) in neighbours(&node) {
+            let next_cost = cost + step;
+            match found.costs.get(&next) {
+                Some(&best) if best < next_cost => {}
+                Some(&best) if best == next_cost => {
+                    found.parents.entry(next).or_default().push(node.clone());
+                }
+                _ => {
+                    found.costs.insert(next.clone(), next_cost);
+                    found.parents.insert(next.clone(), vec![node.clone()]);
+                    queue.push(Reverse((next_cost, next)));
+                }
+            }
+        }
+    }
+    found
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::grid::Grid;
+
+    const MAZE: [&str; 5] = ["S..#.", ".#...", "...#.", "#.#..", "...#E"];
+
+    fn open(grid: &Grid<char>, pos: &(usize, usize)) -> Vec<(usize, usize)> {
+        let around = grid.neighbours4(*pos).filter(|&next| grid[next] != '#');
+        around.collect()
+    }
+
+    #[test]
+    fn breadth_first_counts_steps() {
+        let grid: Grid<char> = Grid::parse(MAZE, |c| c);
+        let steps = bfs((0, 0), |pos| open(&grid, pos));
+        assert_eq!(
+            (steps[&(0, 0)], steps[&(4, 4)], steps.get(&(0, 3))),
+            (0, 8, None)
+        );
+        let nearest = bfs_to((0, 0), |pos| open(&grid, pos), |&pos| grid[pos] == 'E');
+        assert_eq!(nearest, Some(((4, 4), 8)));
+        assert_eq!(bfs_to((0, 0), |pos| open(&grid, pos), |_| false), None);
+    }
+
+    #[test]
+    fn weighted_searches_agree() {
+        // Steps down cost 10 and the rest 1, so the cheapest route never wanders back up.
+        let grid: Grid<char> = Grid::parse(MAZE, |c| c);
+        let weighted = |pos: &(usize, usize)| {
+            let row = pos.0;
+            let around = open(&grid, pos).into_iter();
+            around.map(move |next| (next, if next.0 > row { 10 } else { 1 }))
+        };
+        let end = |pos: &(usize, usize)| *pos == (4, 4);
+        let cheapest = dijkstra_to((0, 0), weighted, end);
+        assert_eq!(cheapest, Some(((4, 4), 44)));
+        let guess = |pos: &(usize, usize)| (4 - pos.0) * 10 + (4 - pos.1).min(1);
+        assert_eq!(astar((0, 0), weighted, guess, end), cheapest);
+        assert_eq!(dijkstra((0, 0), weighted).get(&(4, 4)), Some(&44));
+    }
+
+    #[test]
+    fn every_cheapest_path_is_kept() {
+        // Two equally short ways round the block in the middle, between them using every cell.
+        let grid: Grid<char> = Grid::parse(["...", ".#.", "..."], |c| c);
+        let paths = dijkstra_all((0, 0), |pos| open(&grid, pos).into_iter().map(|n| (n, 1)));
+        assert_eq!(paths.cost(&(2, 2)), Some(4));
+        assert_eq!(paths.on_paths_to([(2, 2)]).len(), 8);
+        assert_eq!(
+            paths.on_paths_to([(0, 1)]),
+            BTreeSet::from([(0, 0), (0, 1)])
+        );
+    }
+}
@@ -1,10 +1,6 @@
-#[allow(dead_code)]
-use std::fs;
-use std::path::Path;
-
-
+/// Names a puzzle part; the day modules' tests use it to find their input.
 #[derive(Debug)]
-enum Puzzle {
+pub enum Puzzle {
     Day01A,
     Day01B,
     Day02A,
@@ -47,5 +43,3 @@ impl Puzzle {
         }
     }
 }
-use tokio::net::TcpStream;
-use tokio::time::{timeout, Duration};
@@ -0,0 +1,144 @@
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use crate::byte_lines::ByteLines;
+use crate::{aoc2024, aoc2025, io};
+
+// Solvers are awaited in place rather than spawned, so nobody needs the futures to be `Send`.
+#[allow(async_fn_in_trait)]
+pub trait DaySolver {
+    // Both parts hand back their answer as text so it can be compared with what the Pico returns.
+    async fn solve_a(&self, lines: &Vec<String>) -> io::Result<String>;
+    async fn solve_b(&self, lines: &Vec<String>) -> io::Result<String>;
+    // The same from the raw input. Days that work on bytes override these so the input never
+    // gets split into `String`s; the rest get their lines built for them.
+    async fn solve_a_bytes(&self, input: &ByteLines<'_>) -> io::Result<String> {
+        self.solve_a(&input.to_lines()).await
+    }
+    async fn solve_b_bytes(&self, input: &ByteLines<'_>) -> io::Result<String> {
+        self.solve_b(&input.to_lines()).await
+    }
+    fn get_day(&self) -> u8;
+    fn get_year(&self) -> u16;
+}
+
+/// Every (year, day) with a `DaySolver`, i.e. what the host can solve by number.
+#[cfg(feature = "std")]
+pub const SOLVABLE_DAYS: &[(u16, u8)] = &[
+    (2024, 7),
+    (2024, 10),
+    (2024, 18),
+    (2024, 22),
+    (2025, 1),
+    (2025, 2),
+    (2025, 3),
+    (2025, 4),
+    (2025, 5),
+    (2025, 6),
+    (2025, 7),
+    (2025, 8),
+    (2025, 9),
+    (2025, 10),
+    (2025, 11),
+    (2025, 12),
+];
+
+/// Without `std`, the days that only need `alloc`.
+#[cfg(all(not(feature = "std"), not(feature = "fixed")))]
+pub const SOLVABLE_DAYS: &[(u16, u8)] = &[
+    (2024, 7),
+    (2025, 1),
+    (2025, 2),
+    (2025, 3),
+    (2025, 4),
+    (2025, 5),
+    (2025, 6),
+];
+
+/// Without `std` but with `fixed`, those plus the days that keep their state in fixed-size
+/// containers.
+#[cfg(all(not(feature = "std"), feature = "fixed"))]
+pub const SOLVABLE_DAYS: &[(u16, u8)] = &[
+    (2024, 7),
+    (2024, 10),
+    (2024, 18),
+    (2025, 1),
+    (2025, 2),
+    (2025, 3),
+    (2025, 4),
+    (2025, 5),
+    (2025, 6),
+    (2025, 7),
+];
+
+/// Binds `$solver` to the `DaySolver` for (year, day) and evaluates to `Some($body)`, or `None`
+/// when there isn't one.
+macro_rules! with_day {
+    ($year:expr, $day:expr, $solver:ident => $body:expr) => {{
+        macro_rules! day {
+            ($day_solver:expr) => {
+                Some({
+                    let $solver = $day_solver;
+                    $body
+                })
+            };
+        }
+        match ($year, $day) {
+            (2024, 7) => day!(aoc2024::day07::Day),
+            #[cfg(any(feature = "std", feature = "fixed"))]
+            (2024, 10) => day!(aoc2024::day10::Day),
+            #[cfg(any(feature = "std", feature = "fixed"))]
+            (2024, 18) => day!(aoc2024::day18::Day),
+            #[cfg(feature = "std")]
+            (2024, 22) => day!(aoc2024::day22::Day),
+            (2025, 1) => day!(aoc2025::day01::Day),
+            (2025, 2) => day!(aoc2025::day02::Day),
+            (2025, 3) => day!(aoc2025::day03::Day),
+            (2025, 4) => day!(aoc2025::day04::Day),
+            (2025, 5) => day!(aoc2025::day05::Day),
+            (2025, 6) => day!(aoc2025::day06::Day),
+            #[cfg(any(feature = "std", feature = "fixed"))]
+            (2025, 7) => day!(aoc2025::day07::Day),
+            #[cfg(feature = "std")]
+            (2025, 8) => day!(aoc2025::day08::Day),
+            #[cfg(feature = "std")]
+            (2025, 9) => day!(aoc2025::day09::Day),
+            #[cfg(feature = "std")]
+            (2025, 10) => day!(aoc2025::day10::Day),
+            #[cfg(feature = "std")]
+            (2025, 11) => day!(aoc2025::day11::Day),
+            #[cfg(feature = "std")]
+            (2025, 12) => day!(aoc2025::day12::Day),
+            _ => None,
+        }
+    }};
+}
+
+/// Runs one part of a puzzle picked by number, for callers that only know the day at runtime
+/// (host nodes, the shard coordinator). `None` when there is no solver for that day.
+pub async fn solve_puzzle(
+    year: u16,
+    day: u8,
+    part: char,
+    lines: &Vec<String>,
+) -> Option<io::Result<String>> {
+    with_day!(year, day, solver => if part == 'A' {
+        solver.solve_a(lines).await
+    } else {
+        solver.solve_b(lines).await
+    })
+}
+
+/// `solve_puzzle` for input that is still one buffer, e.g. a file or an upload.
+pub async fn solve_puzzle_bytes(
+    year: u16,
+    day: u8,
+    part: char,
+    input: &ByteLines<'_>,
+) -> Option<io::Result<String>> {
+    with_day!(year, day, solver => if part == 'A' {
+        solver.solve_a_bytes(input).await
+    } else {
+        solver.solve_b_bytes(input).await
+    })
+}
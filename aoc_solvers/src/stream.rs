@@ -0,0 +1,112 @@
+//! Solving from a stream of lines instead of a `Vec<String>` of the whole input.
+//!
+//! Days that only ever look at each line once, in order, implement `LineSolver` for their parts
+//! and keep just what they need between lines (running totals, parsed numbers), so the input
+//! never has to be held in memory. Where the lines come from is up to the caller: a slice, a
+//! file, stdin, a socket, or chunks arriving on the Pico.
+
+use alloc::boxed::Box;
+use alloc::string::String;
+
+use crate::{aoc2024, aoc2025, io};
+
+/// One part of a day, fed its input a line at a time.
+pub trait LineSolver {
+    /// The next line, without its line ending.
+    fn line(&mut self, line: &str) -> io::Result<()>;
+    /// Called once after the last line, with the answer as text (same as `DaySolver`).
+    fn finish(&mut self) -> io::Result<String>;
+}
+
+/// Feeds `lines` through `solver` and returns its answer.
+pub fn solve_lines<'a, S: LineSolver + ?Sized>(
+    solver: &mut S,
+    lines: impl IntoIterator<Item = &'a str>,
+) -> io::Result<String> {
+    for line in lines {
+        solver.line(line)?;
+    }
+    solver.finish()
+}
+
+/// Every (year, day) with a `LineSolver` for both parts.
+#[cfg(feature = "std")]
+pub const STREAMABLE_DAYS: &[(u16, u8)] = &[
+    (2024, 1),
+    (2024, 2),
+    (2024, 7),
+    (2024, 22),
+    (2025, 1),
+    (2025, 2),
+    (2025, 3),
+];
+
+/// Without `std`, the streamable days in the `alloc` subset.
+#[cfg(not(feature = "std"))]
+pub const STREAMABLE_DAYS: &[(u16, u8)] = &[(2024, 7), (2025, 1), (2025, 2), (2025, 3)];
+
+/// A fresh solver for one part of a day picked by number; `None` if that day can't stream.
+pub fn line_solver(year: u16, day: u8, part: char) -> Option<Box<dyn LineSolver>> {
+    macro_rules! parts {
+        ($year:ident::$day:ident) => {
+            Some(if part == 'A' {
+                Box::new($year::$day::PartA::default()) as Box<dyn LineSolver>
+            } else {
+                Box::new($year::$day::PartB::default())
+            })
+        };
+    }
+    match (year, day) {
+        #[cfg(feature = "std")]
+        (2024, 1) => parts!(aoc2024::day01),
+        #[cfg(feature = "std")]
+        (2024, 2) => parts!(aoc2024::day02),
+        (2024, 7) => parts!(aoc2024::day07),
+        #[cfg(feature = "std")]
+        (2024, 22) => parts!(aoc2024::day22),
+        (2025, 1) => parts!(aoc2025::day01),
+        (2025, 2) => parts!(aoc2025::day02),
+        (2025, 3) => parts!(aoc2025::day03),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn every_streamable_day_has_both_parts() {
+        for &(year, day) in STREAMABLE_DAYS {
+            assert!(
+                line_solver(year, day, 'A').is_some(),
+                "{} day {}",
+                year,
+                day
+            );
+            assert!(
+                line_solver(year, day, 'B').is_some(),
+                "{} day {}",
+                year,
+                day
+            );
+        }
+        assert!(line_solver(2025, 4, 'A').is_none());
+    }
+
+    #[test]
+    fn streamed_samples_get_the_known_answers() {
+        let dial = "L68\nL30\nR48\nL5\nR60\nL55\nL1\nL99\nR14\nL82";
+        let equations = "190: 10 19\n3267: 81 40 27\n83: 17 5\n156: 15 6\n7290: 6 8 6 15\n\
+                         161011: 16 10 13\n192: 17 8 14\n21037: 9 7 18 13\n292: 11 6 16 20";
+        for (year, day, input, answers) in [
+            (2025, 1, dial, ["3", "6"]),
+            (2024, 7, equations, ["3749", "11387"]),
+        ] {
+            for (part, answer) in ['A', 'B'].into_iter().zip(answers) {
+                let mut solver = line_solver(year, day, part).unwrap();
+                assert_eq!(solve_lines(&mut *solver, input.lines()).unwrap(), answer);
+            }
+        }
+    }
+}
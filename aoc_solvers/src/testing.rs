@@ -0,0 +1,19 @@
+//! Shared by the day tests.
+
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::pin::pin;
+use core::task::{Context, Poll, Waker};
+
+/// The solvers are async but never wait on anything, so one poll finishes them.
+pub fn block_on<F: Future>(future: F) -> F::Output {
+    match pin!(future).poll(&mut Context::from_waker(Waker::noop())) {
+        Poll::Ready(output) => output,
+        Poll::Pending => panic!("solver waited on something"),
+    }
+}
+
+/// A sample the way the solvers get their input, a `String` per line.
+pub fn lines(text: &str) -> Vec<String> {
+    text.lines().map(String::from).collect()
+}
@@ -0,0 +1,252 @@
+//! Shared-key authentication.
+//!
+//! The handshake, before `Start`:
+//! 1. host -> node `Hello`: `host_nonce`
+//! 2. node -> host `Challenge`: `node_nonce | node_proof(key, host_nonce, node_nonce)`
+//! 3. host -> node `Auth`: `host_proof(key, host_nonce, node_nonce)`
+//!
+//! Each side checks the other's proof and hangs up if it is wrong, so neither talks to anything
+//! that doesn't hold the key. Both then derive a session key, and every later frame (Naks and
+//! errors included) has `counter: u32 LE | tag[16]` appended to its payload. The tag covers the
+//! direction, counter, kind, seq and payload; counters only go up, so a recorded frame can't be
+//! played back at either side. Nonces come from the caller, since only it knows where to get
+//! randomness (the RP2040 has its ring oscillator, the host has the OS).
+
+use hmac::{Hmac, Mac};
+use sha2::Sha256;
+
+use crate::frame::FrameKind;
+
+type HmacSha256 = Hmac<Sha256>;
+
+pub const NONCE_LEN: usize = 16;
+pub const PROOF_LEN: usize = 32;
+pub const COUNTER_LEN: usize = 4;
+pub const TAG_LEN: usize = 16;
+/// Bytes added to the payload of every frame after the handshake.
+pub const TRAILER_LEN: usize = COUNTER_LEN + TAG_LEN;
+
+pub type Nonce = [u8; NONCE_LEN];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AuthError {
+    /// The payload is too short to hold a trailer (or a handshake field).
+    Missing,
+    /// The tag or proof doesn't match: wrong key or a tampered frame.
+    BadTag,
+    /// The counter didn't go up, i.e. a replayed or reordered frame.
+    Replayed(u32),
+}
+
+impl core::fmt::Display for AuthError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            AuthError::Missing => write!(f, "missing authentication"),
+            AuthError::BadTag => write!(f, "authentication tag doesn't match"),
+            AuthError::Replayed(counter) => write!(f, "replayed frame (counter {})", counter),
+        }
+    }
+}
+
+impl core::error::Error for AuthError {}
+
+/// Which end of the link this is; frames are tagged with the direction they travel in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Role {
+    Host,
+    Node,
+}
+
+impl Role {
+    fn label(self) -> &'static [u8] {
+        match self {
+            Role::Host => b"AOC host",
+            Role::Node => b"AOC node",
+        }
+    }
+
+    fn peer(self) -> Role {
+        match self {
+            Role::Host => Role::Node,
+            Role::Node => Role::Host,
+        }
+    }
+}
+
+fn mac(key: &[u8], parts: &[&[u8]]) -> HmacSha256 {
+    let mut mac = HmacSha256::new_from_slice(key).expect("HMAC takes keys of any length");
+    for part in parts {
+        mac.update(part);
+    }
+    mac
+}
+
+/// What `role` sends to show it holds `key`.
+pub fn proof(key: &[u8], role: Role, host_nonce: &Nonce, node_nonce: &Nonce) -> [u8; PROOF_LEN] {
+    mac(key, &[role.label(), host_nonce, node_nonce])
+        .finalize()
+        .into_bytes()
+        .into()
+}
+
+/// Checks the other side's proof, in constant time.
+pub fn verify_proof(
+    key: &[u8],
+    role: Role,
+    host_nonce: &Nonce,
+    node_nonce: &Nonce,
+    proof: &[u8],
+) -> Result<(), AuthError> {
+    mac(key, &[role.label(), host_nonce, node_nonce])
+        .verify_slice(proof)
+        .map_err(|_| AuthError::BadTag)
+}
+
+/// Tags outgoing frames and checks incoming ones once the handshake is done.
+pub struct Session {
+    key: [u8; 32],
+    role: Role,
+    next_sent: u32,
+    next_received: u32,
+}
+
+impl Session {
+    pub fn new(key: &[u8], role: Role, host_nonce: &Nonce, node_nonce: &Nonce) -> Self {
+        Session {
+            key: mac(key, &[b"AOC session", host_nonce, node_nonce])
+                .finalize()
+                .into_bytes()
+                .into(),
+            role,
+            next_sent: 0,
+            next_received: 0,
+        }
+    }
+
+    fn frame_mac(
+        &self,
+        from: Role,
+        counter: u32,
+        kind: FrameKind,
+        seq: u32,
+        payload: &[u8],
+    ) -> HmacSha256 {
+        mac(
+            &self.key,
+            &[
+                from.label(),
+                &counter.to_le_bytes(),
+                &[kind as u8],
+                &seq.to_le_bytes(),
+                payload,
+            ],
+        )
+    }
+
+    /// The trailer to append to the payload of an outgoing frame.
+    pub fn seal(&mut self, kind: FrameKind, seq: u32, payload: &[u8]) -> [u8; TRAILER_LEN] {
+        let counter = self.next_sent;
+        self.next_sent += 1;
+        let tag = self
+            .frame_mac(self.role, counter, kind, seq, payload)
+            .finalize()
+            .into_bytes();
+        let mut trailer = [0u8; TRAILER_LEN];
+        trailer[..COUNTER_LEN].copy_from_slice(&counter.to_le_bytes());
+        trailer[COUNTER_LEN..].copy_from_slice(&tag[..TAG_LEN]);
+        trailer
+    }
+
+    /// Checks an incoming frame's trailer and returns the payload without it.
+    pub fn open<'a>(
+        &mut self,
+        kind: FrameKind,
+        seq: u32,
+        payload: &'a [u8],
+    ) -> Result<&'a [u8], AuthError> {
+        let body_len = payload
+            .len()
+            .checked_sub(TRAILER_LEN)
+            .ok_or(AuthError::Missing)?;
+        let (body, trailer) = payload.split_at(body_len);
+        let (counter, tag) = trailer.split_at(COUNTER_LEN);
+        let counter = u32::from_le_bytes([counter[0], counter[1], counter[2], counter[3]]);
+        self.frame_mac(self.role.peer(), counter, kind, seq, body)
+            .verify_truncated_left(tag)
+            .map_err(|_| AuthError::BadTag)?;
+        // Checked after the tag so a garbled counter reads as tampering, not a replay.
+        if counter < self.next_received {
+            return Err(AuthError::Replayed(counter));
+        }
+        self.next_received = counter + 1;
+        Ok(body)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const HOST_NONCE: Nonce = [1; NONCE_LEN];
+    const NODE_NONCE: Nonce = [2; NONCE_LEN];
+
+    #[test]
+    fn proofs_need_the_right_key_and_role() {
+        let node_proof = proof(b"workshop", Role::Node, &HOST_NONCE, &NODE_NONCE);
+        assert_eq!(
+            verify_proof(
+                b"workshop",
+                Role::Node,
+                &HOST_NONCE,
+                &NODE_NONCE,
+                &node_proof
+            ),
+            Ok(())
+        );
+        assert_eq!(
+            verify_proof(b"guess", Role::Node, &HOST_NONCE, &NODE_NONCE, &node_proof),
+            Err(AuthError::BadTag)
+        );
+        // A node can't just reflect the host's own proof back.
+        assert_eq!(
+            verify_proof(
+                b"workshop",
+                Role::Host,
+                &HOST_NONCE,
+                &NODE_NONCE,
+                &node_proof
+            ),
+            Err(AuthError::BadTag)
+        );
+    }
+
+    #[test]
+    fn sealed_frames_open_once_on_the_other_side() {
+        let mut host = Session::new(b"workshop", Role::Host, &HOST_NONCE, &NODE_NONCE);
+        let mut node = Session::new(b"workshop", Role::Node, &HOST_NONCE, &NODE_NONCE);
+
+        let mut payload = [0u8; 5 + TRAILER_LEN];
+        payload[..5].copy_from_slice(b"12,34");
+        let trailer = host.seal(FrameKind::Chunk, 0, &payload[..5]);
+        payload[5..].copy_from_slice(&trailer);
+        assert_eq!(node.open(FrameKind::Chunk, 0, &payload), Ok(&b"12,34"[..]));
+        // Same frame again is a replay; a different seq or kind is a forgery.
+        assert_eq!(
+            node.open(FrameKind::Chunk, 0, &payload),
+            Err(AuthError::Replayed(0))
+        );
+        assert_eq!(
+            node.open(FrameKind::Chunk, 1, &payload),
+            Err(AuthError::BadTag)
+        );
+        // Tags are per direction, so the host doesn't accept its own frame back.
+        assert_eq!(
+            host.open(FrameKind::Chunk, 0, &payload),
+            Err(AuthError::BadTag)
+        );
+        assert_eq!(
+            node.open(FrameKind::Ack, 0, &[0; 3]),
+            Err(AuthError::Missing)
+        );
+    }
+}
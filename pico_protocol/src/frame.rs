@@ -0,0 +1,246 @@
+#[cfg(feature = "alloc")]
+use alloc::{string::String, vec::Vec};
+use core::fmt;
+
+use crate::crc::crc32;
+
+/// Largest payload either side will accept, so a garbled length can't make us allocate the world.
+pub const MAX_PAYLOAD: usize = 64 * 1024;
+
+/// Frame layout on the wire: `kind: u8 | seq: u32 LE | len: u32 LE | payload[len] | crc: u32 LE`,
+/// where the CRC32 covers everything before it.
+pub const HEADER_LEN: usize = 9;
+pub const CRC_LEN: usize = 4;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FrameKind {
+    /// Host -> node: opens (or resumes) a transfer, payload is a `StartInfo`.
+    Start = 1,
+    /// Node -> host: `seq` is the next chunk the node wants.
+    Ready = 2,
+    /// Host -> node: one chunk of input, `seq` is the chunk index.
+    Chunk = 3,
+    /// Node -> host: every chunk up to and including `seq` has been consumed.
+    Ack = 4,
+    /// Node -> host: the answer, as text.
+    Result = 5,
+    /// Either direction: something went wrong, payload is a message.
+    Error = 6,
+    /// Either direction: the last frame failed its CRC. `seq` is the next chunk the sender of
+    /// the Nak expects; outside a chunk exchange the other side just repeats its last frame.
+    Nak = 7,
+    /// Node -> host: a line of device log, as text. Can arrive at any point after `Ready`.
+    Log = 8,
+    /// Node -> host: how far along the solve is, payload is a `Progress`. Same as `Log`.
+    Progress = 9,
+    /// Host -> node: asks to authenticate, payload is the host's nonce. Only ever first.
+    Hello = 10,
+    /// Node -> host: the node's nonce and its proof that it knows the key (see `auth`).
+    Challenge = 11,
+    /// Host -> node: the host's proof. Every frame after it carries a tag.
+    Auth = 12,
+}
+
+impl FrameKind {
+    pub fn from_u8(value: u8) -> Option<Self> {
+        match value {
+            1 => Some(FrameKind::Start),
+            2 => Some(FrameKind::Ready),
+            3 => Some(FrameKind::Chunk),
+            4 => Some(FrameKind::Ack),
+            5 => Some(FrameKind::Result),
+            6 => Some(FrameKind::Error),
+            7 => Some(FrameKind::Nak),
+            8 => Some(FrameKind::Log),
+            9 => Some(FrameKind::Progress),
+            10 => Some(FrameKind::Hello),
+            11 => Some(FrameKind::Challenge),
+            12 => Some(FrameKind::Auth),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DecodeError {
+    /// Not a whole frame yet; this many bytes in total are needed.
+    Incomplete(usize),
+    /// The length field is over `MAX_PAYLOAD`, so the stream can't be trusted any more.
+    TooLarge(usize),
+    BadCrc,
+    UnknownKind(u8),
+}
+
+impl fmt::Display for DecodeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DecodeError::Incomplete(needed) => write!(f, "Frame needs {} bytes", needed),
+            DecodeError::TooLarge(len) => write!(f, "Frame payload of {} bytes is too large", len),
+            DecodeError::BadCrc => write!(f, "Frame failed its CRC"),
+            DecodeError::UnknownKind(kind) => write!(f, "Unknown frame kind {}", kind),
+        }
+    }
+}
+
+impl core::error::Error for DecodeError {}
+
+/// The output buffer can't hold the encoded frame.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BufferTooSmall;
+
+/// Payload length from a frame header, so a reader knows how much more to wait for.
+pub fn payload_len(header: &[u8; HEADER_LEN]) -> Result<usize, DecodeError> {
+    let len = u32::from_le_bytes([header[5], header[6], header[7], header[8]]) as usize;
+    if len > MAX_PAYLOAD {
+        return Err(DecodeError::TooLarge(len));
+    }
+    Ok(len)
+}
+
+/// A frame whose payload borrows from the receive buffer; all the firmware needs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FrameRef<'a> {
+    pub kind: FrameKind,
+    pub seq: u32,
+    pub payload: &'a [u8],
+}
+
+impl<'a> FrameRef<'a> {
+    pub fn new(kind: FrameKind, seq: u32, payload: &'a [u8]) -> Self {
+        FrameRef { kind, seq, payload }
+    }
+
+    pub fn encoded_len(&self) -> usize {
+        HEADER_LEN + self.payload.len() + CRC_LEN
+    }
+
+    /// Writes the frame to the start of `out` and returns how many bytes it took.
+    pub fn encode_into(&self, out: &mut [u8]) -> Result<usize, BufferTooSmall> {
+        let len = self.encoded_len();
+        if out.len() < len || self.payload.len() > MAX_PAYLOAD {
+            return Err(BufferTooSmall);
+        }
+        out[0] = self.kind as u8;
+        out[1..5].copy_from_slice(&self.seq.to_le_bytes());
+        out[5..9].copy_from_slice(&(self.payload.len() as u32).to_le_bytes());
+        out[HEADER_LEN..len - CRC_LEN].copy_from_slice(self.payload);
+        let crc = crc32(&out[..len - CRC_LEN]);
+        out[len - CRC_LEN..len].copy_from_slice(&crc.to_le_bytes());
+        Ok(len)
+    }
+
+    /// Parses the frame at the start of `bytes`, returning it and the number of bytes it used.
+    pub fn decode(bytes: &'a [u8]) -> Result<(Self, usize), DecodeError> {
+        let Some(header) = bytes.first_chunk::<HEADER_LEN>() else {
+            return Err(DecodeError::Incomplete(HEADER_LEN));
+        };
+        let len = HEADER_LEN + payload_len(header)? + CRC_LEN;
+        if bytes.len() < len {
+            return Err(DecodeError::Incomplete(len));
+        }
+        let (body, crc) = bytes[..len].split_at(len - CRC_LEN);
+        if crc32(body) != u32::from_le_bytes([crc[0], crc[1], crc[2], crc[3]]) {
+            return Err(DecodeError::BadCrc);
+        }
+        let kind = FrameKind::from_u8(bytes[0]).ok_or(DecodeError::UnknownKind(bytes[0]))?;
+        let seq = u32::from_le_bytes([bytes[1], bytes[2], bytes[3], bytes[4]]);
+        Ok((FrameRef::new(kind, seq, &body[HEADER_LEN..]), len))
+    }
+}
+
+/// An owned frame, for the host side.
+#[cfg(feature = "alloc")]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Frame {
+    pub kind: FrameKind,
+    pub seq: u32,
+    pub payload: Vec<u8>,
+}
+
+#[cfg(feature = "alloc")]
+impl Frame {
+    pub fn new(kind: FrameKind, seq: u32, payload: Vec<u8>) -> Self {
+        Frame { kind, seq, payload }
+    }
+
+    pub fn empty(kind: FrameKind, seq: u32) -> Self {
+        Frame::new(kind, seq, Vec::new())
+    }
+
+    pub fn text(&self) -> String {
+        String::from_utf8_lossy(&self.payload).into_owned()
+    }
+
+    pub fn borrowed(&self) -> FrameRef<'_> {
+        FrameRef::new(self.kind, self.seq, &self.payload)
+    }
+
+    pub fn encode(&self) -> Vec<u8> {
+        let frame = self.borrowed();
+        let mut bytes = alloc::vec![0u8; frame.encoded_len()];
+        frame
+            .encode_into(&mut bytes)
+            .expect("buffer is sized for the frame");
+        bytes
+    }
+
+    /// Parses one whole encoded frame; `None` if it is the wrong length, fails its CRC or has a
+    /// kind we don't know.
+    pub fn decode(bytes: &[u8]) -> Option<Frame> {
+        match FrameRef::decode(bytes) {
+            Ok((frame, len)) if len == bytes.len() => {
+                Some(Frame::new(frame.kind, frame.seq, frame.payload.to_vec()))
+            }
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn frame_round_trips_without_alloc() {
+        let frame = FrameRef::new(FrameKind::Chunk, 7, b"1,2,3\n4,5,6");
+        let mut buffer = [0u8; 64];
+        let len = frame.encode_into(&mut buffer).unwrap();
+        assert_eq!(len, frame.encoded_len());
+        assert_eq!(FrameRef::decode(&buffer[..len + 5]), Ok((frame, len)));
+        assert_eq!(
+            FrameRef::decode(&buffer[..len - 1]),
+            Err(DecodeError::Incomplete(len))
+        );
+        assert_eq!(
+            frame.encode_into(&mut buffer[..len - 1]),
+            Err(BufferTooSmall)
+        );
+    }
+
+    #[test]
+    fn corruption_and_garbage_are_reported() {
+        let mut buffer = [0u8; 32];
+        let len = FrameRef::new(FrameKind::Ack, 3, &[])
+            .encode_into(&mut buffer)
+            .unwrap();
+        buffer[2] ^= 0x01;
+        assert_eq!(FrameRef::decode(&buffer[..len]), Err(DecodeError::BadCrc));
+
+        let mut bogus = [0u8; HEADER_LEN];
+        bogus[5..9].copy_from_slice(&(MAX_PAYLOAD as u32 + 1).to_le_bytes());
+        assert_eq!(
+            FrameRef::decode(&bogus),
+            Err(DecodeError::TooLarge(MAX_PAYLOAD + 1))
+        );
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn owned_frames_match_borrowed_ones() {
+        let frame = Frame::new(FrameKind::Result, 0, b"1234".to_vec());
+        let bytes = frame.encode();
+        assert_eq!(Frame::decode(&bytes), Some(frame.clone()));
+        assert_eq!(FrameRef::decode(&bytes).unwrap().0, frame.borrowed());
+        assert_eq!(Frame::decode(&bytes[..bytes.len() - 1]), None);
+    }
+}
@@ -0,0 +1,467 @@
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::fmt;
+
+/// Pre-parsed input, so the Pico gets numbers instead of text it has to parse on an M0+.
+///
+/// Layout: `magic "AOCB" | version: u8 | year: u16 LE | day: u8 | kind: u8 | body`, where the
+/// body depends on the kind:
+/// - `Grid` (1): `width: u16 LE | height: u16 LE | off: u8 | on: u8 | cells`, one bit per cell,
+///   row major, least significant bit first.
+/// - `Points` (2): `dims: u8 | bits: u8 | count: varint | coords`, every coordinate packed into
+///   `bits` bits, least significant bit first.
+/// - `Numbers` (3): `count: varint | numbers`, each an unsigned LEB128 varint.
+pub const MAGIC: &[u8; 4] = b"AOCB";
+/// Bump when the layout changes; nodes reject versions they don't know.
+pub const ENCODING_VERSION: u8 = 1;
+
+const HEADER_LEN: usize = 9;
+
+/// The shapes of input we know how to pack.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PuzzleData {
+    /// A rectangular grid of (at most) two symbols.
+    Grid {
+        width: u16,
+        height: u16,
+        off: u8,
+        on: u8,
+        cells: Vec<bool>,
+    },
+    /// One comma separated point per line, `dims` non-negative coordinates each, flattened.
+    Points { dims: u8, coords: Vec<u32> },
+    /// One non-negative number per line.
+    Numbers(Vec<u64>),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EncodedInput {
+    pub year: u16,
+    pub day: u8,
+    pub data: PuzzleData,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DecodeError {
+    BadMagic,
+    UnsupportedVersion(u8),
+    UnknownKind(u8),
+    Truncated,
+    Invalid(&'static str),
+}
+
+impl fmt::Display for DecodeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DecodeError::BadMagic => write!(f, "Not a binary puzzle input"),
+            DecodeError::UnsupportedVersion(v) => {
+                write!(
+                    f,
+                    "Binary input version {} (expected {})",
+                    v, ENCODING_VERSION
+                )
+            }
+            DecodeError::UnknownKind(k) => write!(f, "Unknown binary input kind {}", k),
+            DecodeError::Truncated => write!(f, "Binary input is truncated"),
+            DecodeError::Invalid(what) => write!(f, "Invalid binary input: {}", what),
+        }
+    }
+}
+
+impl core::error::Error for DecodeError {}
+
+impl EncodedInput {
+    /// Parses `lines` into the packed form for the days that have one. Returns `None` for other
+    /// days, or when the input doesn't fit the format exactly (then it goes as text).
+    pub fn parse(year: u16, day: u8, lines: &[String]) -> Option<Self> {
+        let data = match (year, day) {
+            (2025, 4) => parse_grid(lines)?,
+            (2024, 18) => parse_points(lines, 2)?,
+            (2025, 8) => parse_points(lines, 3)?,
+            (2024, 22) => parse_numbers(lines)?,
+            _ => return None,
+        };
+        let input = EncodedInput { year, day, data };
+        // Only ever send something the node can turn back into exactly the same input.
+        (input.to_lines() == lines).then_some(input)
+    }
+
+    pub fn encode(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(HEADER_LEN);
+        bytes.extend_from_slice(MAGIC);
+        bytes.push(ENCODING_VERSION);
+        bytes.extend_from_slice(&self.year.to_le_bytes());
+        bytes.push(self.day);
+        match &self.data {
+            PuzzleData::Grid {
+                width,
+                height,
+                off,
+                on,
+                cells,
+            } => {
+                bytes.push(1);
+                bytes.extend_from_slice(&width.to_le_bytes());
+                bytes.extend_from_slice(&height.to_le_bytes());
+                bytes.push(*off);
+                bytes.push(*on);
+                let mut bits = BitWriter::default();
+                for &cell in cells {
+                    bits.write(cell as u32, 1);
+                }
+                bytes.extend_from_slice(&bits.finish());
+            }
+            PuzzleData::Points { dims, coords } => {
+                let width = bit_width(coords.iter().copied().max().unwrap_or(0));
+                bytes.push(2);
+                bytes.push(*dims);
+                bytes.push(width);
+                write_varint(&mut bytes, (coords.len() / *dims as usize) as u64);
+                let mut bits = BitWriter::default();
+                for &coord in coords {
+                    bits.write(coord, width);
+                }
+                bytes.extend_from_slice(&bits.finish());
+            }
+            PuzzleData::Numbers(numbers) => {
+                bytes.push(3);
+                write_varint(&mut bytes, numbers.len() as u64);
+                for &number in numbers {
+                    write_varint(&mut bytes, number);
+                }
+            }
+        }
+        bytes
+    }
+
+    pub fn decode(bytes: &[u8]) -> Result<Self, DecodeError> {
+        if bytes.len() < HEADER_LEN || &bytes[..4] != MAGIC {
+            return Err(DecodeError::BadMagic);
+        }
+        if bytes[4] != ENCODING_VERSION {
+            return Err(DecodeError::UnsupportedVersion(bytes[4]));
+        }
+        let year = u16::from_le_bytes([bytes[5], bytes[6]]);
+        let day = bytes[7];
+        let mut body = Reader {
+            bytes: &bytes[HEADER_LEN..],
+        };
+        let data = match bytes[8] {
+            1 => {
+                let width = body.u16()?;
+                let height = body.u16()?;
+                let off = body.u8()?;
+                let on = body.u8()?;
+                if width == 0 {
+                    return Err(DecodeError::Invalid("grid width"));
+                }
+                let count = width as usize * height as usize;
+                let mut bits = BitReader::new(body.take(count.div_ceil(8))?);
+                let cells = (0..count).map(|_| bits.read(1) == 1).collect();
+                PuzzleData::Grid {
+                    width,
+                    height,
+                    off,
+                    on,
+                    cells,
+                }
+            }
+            2 => {
+                let dims = body.u8()?;
+                let width = body.u8()?;
+                if dims == 0 || width == 0 || width > 32 {
+                    return Err(DecodeError::Invalid("point layout"));
+                }
+                let count = body.varint()? as usize;
+                // Every point is at least a bit, which bounds a garbled count.
+                if count > body.bytes.len().saturating_mul(8) {
+                    return Err(DecodeError::Truncated);
+                }
+                let total = count
+                    .checked_mul(dims as usize)
+                    .ok_or(DecodeError::Invalid("point count"))?;
+                let packed = total
+                    .checked_mul(width as usize)
+                    .ok_or(DecodeError::Invalid("point count"))?
+                    .div_ceil(8);
+                let mut bits = BitReader::new(body.take(packed)?);
+                let coords = (0..total).map(|_| bits.read(width)).collect();
+                PuzzleData::Points { dims, coords }
+            }
+            3 => {
+                let count = body.varint()? as usize;
+                // Every varint is at least a byte, which bounds a garbled count.
+                if count > body.bytes.len() {
+                    return Err(DecodeError::Truncated);
+                }
+                let numbers = (0..count)
+                    .map(|_| body.varint())
+                    .collect::<Result<_, _>>()?;
+                PuzzleData::Numbers(numbers)
+            }
+            kind => return Err(DecodeError::UnknownKind(kind)),
+        };
+        if !body.bytes.is_empty() {
+            return Err(DecodeError::Invalid("trailing bytes"));
+        }
+        Ok(EncodedInput { year, day, data })
+    }
+
+    /// Turns the packed input back into the text lines the solvers take.
+    pub fn to_lines(&self) -> Vec<String> {
+        match &self.data {
+            PuzzleData::Grid {
+                width,
+                off,
+                on,
+                cells,
+                ..
+            } => cells
+                .chunks(*width as usize)
+                .map(|row| {
+                    row.iter()
+                        .map(|&cell| if cell { *on as char } else { *off as char })
+                        .collect()
+                })
+                .collect(),
+            PuzzleData::Points { dims, coords } => coords
+                .chunks(*dims as usize)
+                .map(|point| {
+                    point
+                        .iter()
+                        .map(|c| c.to_string())
+                        .collect::<Vec<_>>()
+                        .join(",")
+                })
+                .collect(),
+            PuzzleData::Numbers(numbers) => numbers.iter().map(|n| n.to_string()).collect(),
+        }
+    }
+}
+
+fn parse_grid(lines: &[String]) -> Option<PuzzleData> {
+    let width = lines.first()?.len();
+    if width == 0 || lines.iter().any(|line| line.len() != width) {
+        return None;
+    }
+    let mut symbols: Vec<u8> = lines.concat().into_bytes();
+    symbols.sort_unstable();
+    symbols.dedup();
+    let (off, on) = match symbols[..] {
+        [only] => (only, only),
+        [off, on] => (off, on),
+        _ => return None,
+    };
+    Some(PuzzleData::Grid {
+        width: width.try_into().ok()?,
+        height: lines.len().try_into().ok()?,
+        off,
+        on,
+        cells: lines
+            .iter()
+            .flat_map(|l| l.bytes())
+            .map(|b| b == on && on != off)
+            .collect(),
+    })
+}
+
+fn parse_points(lines: &[String], dims: u8) -> Option<PuzzleData> {
+    let mut coords = Vec::with_capacity(lines.len() * dims as usize);
+    for line in lines {
+        let before = coords.len();
+        for coord in line.split(',') {
+            coords.push(coord.parse().ok()?);
+        }
+        if coords.len() - before != dims as usize {
+            return None;
+        }
+    }
+    Some(PuzzleData::Points { dims, coords })
+}
+
+fn parse_numbers(lines: &[String]) -> Option<PuzzleData> {
+    let numbers = lines
+        .iter()
+        .map(|line| line.parse().ok())
+        .collect::<Option<_>>()?;
+    Some(PuzzleData::Numbers(numbers))
+}
+
+/// Bits needed for `max`, at least one so an all-zero list still has a width.
+fn bit_width(max: u32) -> u8 {
+    (32 - max.leading_zeros()).max(1) as u8
+}
+
+fn write_varint(bytes: &mut Vec<u8>, mut value: u64) {
+    while value >= 0x80 {
+        bytes.push(value as u8 | 0x80);
+        value >>= 7;
+    }
+    bytes.push(value as u8);
+}
+
+#[derive(Default)]
+struct BitWriter {
+    bytes: Vec<u8>,
+    used: u8,
+}
+
+impl BitWriter {
+    fn write(&mut self, value: u32, width: u8) {
+        for bit in 0..width {
+            if self.used == 0 {
+                self.bytes.push(0);
+            }
+            let last = self.bytes.len() - 1;
+            self.bytes[last] |= (((value >> bit) & 1) as u8) << self.used;
+            self.used = (self.used + 1) % 8;
+        }
+    }
+
+    fn finish(self) -> Vec<u8> {
+        self.bytes
+    }
+}
+
+struct BitReader<'a> {
+    bytes: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> BitReader<'a> {
+    fn new(bytes: &'a [u8]) -> Self {
+        BitReader { bytes, pos: 0 }
+    }
+
+    /// Callers size `bytes` for everything they read, so this never runs off the end.
+    fn read(&mut self, width: u8) -> u32 {
+        let mut value = 0;
+        for bit in 0..width {
+            let byte = self.bytes[self.pos / 8];
+            value |= (((byte >> (self.pos % 8)) & 1) as u32) << bit;
+            self.pos += 1;
+        }
+        value
+    }
+}
+
+struct Reader<'a> {
+    bytes: &'a [u8],
+}
+
+impl<'a> Reader<'a> {
+    fn take(&mut self, n: usize) -> Result<&'a [u8], DecodeError> {
+        if self.bytes.len() < n {
+            return Err(DecodeError::Truncated);
+        }
+        let (head, rest) = self.bytes.split_at(n);
+        self.bytes = rest;
+        Ok(head)
+    }
+
+    fn u8(&mut self) -> Result<u8, DecodeError> {
+        Ok(self.take(1)?[0])
+    }
+
+    fn u16(&mut self) -> Result<u16, DecodeError> {
+        let bytes = self.take(2)?;
+        Ok(u16::from_le_bytes([bytes[0], bytes[1]]))
+    }
+
+    fn varint(&mut self) -> Result<u64, DecodeError> {
+        let mut value = 0u64;
+        for shift in (0..64).step_by(7) {
+            let byte = self.u8()?;
+            value |= ((byte & 0x7F) as u64) << shift;
+            if byte & 0x80 == 0 {
+                return Ok(value);
+            }
+        }
+        Err(DecodeError::Invalid("varint too long"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn lines(text: &str) -> Vec<String> {
+        text.lines().map(String::from).collect()
+    }
+
+    fn round_trip(year: u16, day: u8, input: &[String]) -> Vec<u8> {
+        let encoded = EncodedInput::parse(year, day, input).unwrap();
+        let bytes = encoded.encode();
+        let decoded = EncodedInput::decode(&bytes).unwrap();
+        assert_eq!(decoded, encoded);
+        assert_eq!(decoded.to_lines(), input);
+        bytes
+    }
+
+    #[test]
+    fn grid_packs_a_bit_per_cell() {
+        let input = lines(
+            "..@@.@@@@.\n@@@.@.@.@@\n@@@@@.@.@@\n@.@@@@..@.\n@@.@@@@.@@\n\
+             .@@@@@@@.@\n.@.@.@.@@@\n@.@@@.@@@@\n.@@@@@@@@.\n@.@.@@@.@.",
+        );
+        let bytes = round_trip(2025, 4, &input);
+        assert_eq!(bytes.len(), HEADER_LEN + 6 + 13);
+    }
+
+    #[test]
+    fn points_and_numbers_round_trip() {
+        round_trip(2024, 18, &lines("5,4\n4,2\n4,5\n3,0\n2,1\n6,3\n70,70"));
+        let junctions = lines("162,817,812\n57,618,57\n906,360,560\n0,0,0\n99999,1,2");
+        let bytes = round_trip(2025, 8, &junctions);
+        // 17 bits per coordinate instead of up to 6 characters.
+        assert_eq!(bytes.len(), HEADER_LEN + 3 + (5 * 3 * 17usize).div_ceil(8));
+        round_trip(2024, 22, &lines("1\n10\n100\n2024\n16777215"));
+    }
+
+    #[test]
+    fn input_that_would_not_round_trip_stays_text() {
+        assert_eq!(EncodedInput::parse(2024, 22, &lines("1\n+10")), None);
+        assert_eq!(EncodedInput::parse(2024, 22, &lines("1\n\n5")), None);
+        assert_eq!(EncodedInput::parse(2025, 8, &lines("1,2,3\n4,5")), None);
+        assert_eq!(EncodedInput::parse(2025, 4, &lines("@.\n@")), None);
+        assert_eq!(EncodedInput::parse(2025, 1, &lines("L68")), None);
+    }
+
+    #[test]
+    fn rejects_bad_headers_and_truncation() {
+        let bytes = EncodedInput::parse(2024, 22, &lines("1\n10\n100"))
+            .unwrap()
+            .encode();
+        let mut wrong_version = bytes.clone();
+        wrong_version[4] = ENCODING_VERSION + 1;
+        assert_eq!(
+            EncodedInput::decode(&wrong_version),
+            Err(DecodeError::UnsupportedVersion(ENCODING_VERSION + 1))
+        );
+        assert_eq!(
+            EncodedInput::decode(&bytes[..bytes.len() - 1]),
+            Err(DecodeError::Truncated)
+        );
+        assert_eq!(
+            EncodedInput::decode(b"1\n10\n100"),
+            Err(DecodeError::BadMagic)
+        );
+    }
+
+    #[test]
+    fn rejects_point_counts_the_body_cannot_hold() {
+        let bytes = EncodedInput::parse(2025, 8, &lines("1,2,3"))
+            .unwrap()
+            .encode();
+        let huge_count = [0xff, 0xff, 0xff, 0xff, 0x0f];
+        for (width, error) in [
+            (0, DecodeError::Invalid("point layout")),
+            (1, DecodeError::Truncated),
+        ] {
+            let mut garbled = bytes[..HEADER_LEN].to_vec();
+            garbled.extend_from_slice(&[3, width]);
+            garbled.extend_from_slice(&huge_count);
+            assert_eq!(EncodedInput::decode(&garbled), Err(error));
+        }
+    }
+}
@@ -0,0 +1,31 @@
+//! The wire format between the host and a solver node (the Pico W firmware or a host node).
+//!
+//! Builds without `std` so the firmware can use it as is. The `alloc` feature adds owned frames
+//! and the pre-parsed input format, which the host always wants; `auth` adds the shared-key
+//! handshake and frame tags.
+#![cfg_attr(not(test), no_std)]
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+#[cfg(feature = "auth")]
+pub mod auth;
+mod crc;
+mod frame;
+#[cfg(feature = "alloc")]
+pub mod input;
+mod progress;
+mod start;
+
+pub use crc::crc32;
+#[cfg(feature = "alloc")]
+pub use frame::Frame;
+pub use frame::{
+    BufferTooSmall, CRC_LEN, DecodeError, FrameKind, FrameRef, HEADER_LEN, MAX_PAYLOAD, payload_len,
+};
+pub use progress::Progress;
+pub use start::{InputEncoding, StartInfo};
+
+/// Bumped whenever the framing or any payload layout changes. Sent in every `Start` frame, and
+/// a node refuses a transfer from a host with a different version.
+pub const PROTOCOL_VERSION: u8 = 1;
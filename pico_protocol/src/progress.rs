@@ -0,0 +1,73 @@
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+use crate::frame::BufferTooSmall;
+
+/// Payload of a `Progress` frame: `done: u32 LE | total: u32 LE | stage` (UTF-8, may be empty).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Progress<'a> {
+    pub done: u32,
+    pub total: u32,
+    pub stage: &'a str,
+}
+
+impl<'a> Progress<'a> {
+    pub fn encoded_len(&self) -> usize {
+        8 + self.stage.len()
+    }
+
+    pub fn encode_into(&self, out: &mut [u8]) -> Result<usize, BufferTooSmall> {
+        let len = self.encoded_len();
+        if out.len() < len {
+            return Err(BufferTooSmall);
+        }
+        out[0..4].copy_from_slice(&self.done.to_le_bytes());
+        out[4..8].copy_from_slice(&self.total.to_le_bytes());
+        out[8..len].copy_from_slice(self.stage.as_bytes());
+        Ok(len)
+    }
+
+    #[cfg(feature = "alloc")]
+    pub fn encode(&self) -> Vec<u8> {
+        let mut bytes = alloc::vec![0u8; self.encoded_len()];
+        self.encode_into(&mut bytes)
+            .expect("buffer is sized for the progress");
+        bytes
+    }
+
+    /// A stage that isn't valid UTF-8 comes back empty rather than failing the whole frame.
+    pub fn decode(bytes: &'a [u8]) -> Option<Self> {
+        let (counts, stage) = bytes.split_at_checked(8)?;
+        Some(Progress {
+            done: u32::from_le_bytes([counts[0], counts[1], counts[2], counts[3]]),
+            total: u32::from_le_bytes([counts[4], counts[5], counts[6], counts[7]]),
+            stage: core::str::from_utf8(stage).unwrap_or(""),
+        })
+    }
+
+    pub fn percent(&self) -> f64 {
+        if self.total == 0 {
+            return 0.0;
+        }
+        100.0 * self.done as f64 / self.total as f64
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn progress_round_trips() {
+        let progress = Progress {
+            done: 3,
+            total: 12,
+            stage: "regions",
+        };
+        let mut buffer = [0u8; 32];
+        let len = progress.encode_into(&mut buffer).unwrap();
+        assert_eq!(Progress::decode(&buffer[..len]), Some(progress));
+        assert_eq!(progress.percent(), 25.0);
+        assert_eq!(Progress::decode(&buffer[..7]), None);
+    }
+}
@@ -0,0 +1,101 @@
+use crate::PROTOCOL_VERSION;
+
+/// How the chunks of a transfer are to be read.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InputEncoding {
+    /// The input lines joined with `\n`.
+    Text = 0,
+    /// A pre-parsed `EncodedInput` (see `input`).
+    Binary = 1,
+}
+
+impl InputEncoding {
+    pub fn from_u8(value: u8) -> Option<Self> {
+        match value {
+            0 => Some(InputEncoding::Text),
+            1 => Some(InputEncoding::Binary),
+            _ => None,
+        }
+    }
+}
+
+/// Payload of a `Start` frame. Sending the same `transfer_id` again asks the node to resume.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct StartInfo {
+    /// `PROTOCOL_VERSION` of the sender.
+    pub version: u8,
+    pub year: u16,
+    pub day: u8,
+    pub part: char,
+    pub transfer_id: u32,
+    pub total_len: u32,
+    pub chunk_count: u32,
+    pub window: u16,
+    pub encoding: InputEncoding,
+}
+
+impl StartInfo {
+    pub const LEN: usize = 20;
+
+    pub fn is_current_version(&self) -> bool {
+        self.version == PROTOCOL_VERSION
+    }
+
+    pub fn encode(&self) -> [u8; Self::LEN] {
+        let mut bytes = [0u8; Self::LEN];
+        bytes[0] = self.version;
+        bytes[1..3].copy_from_slice(&self.year.to_le_bytes());
+        bytes[3] = self.day;
+        bytes[4] = self.part as u8;
+        bytes[5..9].copy_from_slice(&self.transfer_id.to_le_bytes());
+        bytes[9..13].copy_from_slice(&self.total_len.to_le_bytes());
+        bytes[13..17].copy_from_slice(&self.chunk_count.to_le_bytes());
+        bytes[17..19].copy_from_slice(&self.window.to_le_bytes());
+        bytes[19] = self.encoding as u8;
+        bytes
+    }
+
+    pub fn decode(bytes: &[u8]) -> Option<Self> {
+        if bytes.len() != Self::LEN {
+            return None;
+        }
+        let u32_at =
+            |i: usize| u32::from_le_bytes([bytes[i], bytes[i + 1], bytes[i + 2], bytes[i + 3]]);
+        Some(StartInfo {
+            version: bytes[0],
+            year: u16::from_le_bytes([bytes[1], bytes[2]]),
+            day: bytes[3],
+            part: bytes[4] as char,
+            transfer_id: u32_at(5),
+            total_len: u32_at(9),
+            chunk_count: u32_at(13),
+            window: u16::from_le_bytes([bytes[17], bytes[18]]),
+            encoding: InputEncoding::from_u8(bytes[19])?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn start_info_round_trips() {
+        let info = StartInfo {
+            version: PROTOCOL_VERSION,
+            year: 2025,
+            day: 8,
+            part: 'B',
+            transfer_id: 0xDEADBEEF,
+            total_len: 19_000,
+            chunk_count: 19,
+            window: 4,
+            encoding: InputEncoding::Binary,
+        };
+        assert_eq!(StartInfo::decode(&info.encode()), Some(info));
+        assert!(info.is_current_version());
+        let mut unknown_encoding = info.encode();
+        unknown_encoding[19] = 9;
+        assert_eq!(StartInfo::decode(&unknown_encoding), None);
+    }
+}
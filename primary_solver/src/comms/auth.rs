@@ -0,0 +1,275 @@
+use pico_protocol::auth::{self, NONCE_LEN, Nonce, PROOF_LEN, Role, Session};
+use tokio::io::{AsyncRead, AsyncWrite};
+use tokio::time::{Duration, timeout};
+
+use crate::comms::error::{CommsError, CommsResult};
+use crate::comms::frame::{Frame, FrameKind, read_frame, write_frame};
+
+const HANDSHAKE_TIMEOUT: Duration = Duration::from_secs(10);
+/// Corrupt handshake frames are Nak'd like any other, but not forever.
+const MAX_HANDSHAKE_NAKS: u32 = 5;
+
+/// An authenticated connection: seals what we send and checks what we receive.
+pub struct AuthSession {
+    session: Session,
+    /// The host's `Auth` frame, kept until a sealed frame from the node shows it got there.
+    unconfirmed: Option<Frame>,
+}
+
+impl AuthSession {
+    pub fn seal(&mut self, frame: &Frame) -> Frame {
+        let mut payload = frame.payload.clone();
+        payload.extend_from_slice(&self.session.seal(frame.kind, frame.seq, &frame.payload));
+        Frame::new(frame.kind, frame.seq, payload)
+    }
+
+    pub fn open(&mut self, frame: Frame) -> CommsResult<Frame> {
+        match self.session.open(frame.kind, frame.seq, &frame.payload) {
+            Ok(payload) => {
+                self.unconfirmed = None;
+                Ok(Frame::new(frame.kind, frame.seq, payload.to_vec()))
+            }
+            Err(e) => Err(CommsError::AuthFailed(format!(
+                "{:?} {}: {}",
+                frame.kind, frame.seq, e
+            ))),
+        }
+    }
+
+    /// The `Auth` frame to send again when `frame` is the node's Nak for it. A corrupt `Auth`
+    /// leaves the node in the handshake, so its Nak comes without a tag.
+    pub fn lost_auth(&self, frame: &Frame) -> Option<&Frame> {
+        self.unconfirmed
+            .as_ref()
+            .filter(|_| frame.kind == FrameKind::Nak && frame.payload.is_empty())
+    }
+}
+
+fn nonce() -> CommsResult<Nonce> {
+    let mut nonce = [0u8; NONCE_LEN];
+    getrandom::fill(&mut nonce)
+        .map_err(|e| CommsError::AuthFailed(format!("No randomness for a nonce: {}", e)))?;
+    Ok(nonce)
+}
+
+/// Sends `frame` and waits for the reply, repeating it when either side sees a corrupt frame.
+async fn exchange<S: AsyncRead + AsyncWrite + Unpin>(
+    stream: &mut S,
+    frame: &Frame,
+) -> CommsResult<Frame> {
+    write_frame(stream, frame).await?;
+    for _ in 0..MAX_HANDSHAKE_NAKS {
+        let received = match timeout(HANDSHAKE_TIMEOUT, read_frame(stream)).await {
+            Ok(received) => received?,
+            Err(_) => {
+                return Err(CommsError::TimedOut(format!(
+                    "waiting for a reply to {:?}",
+                    frame.kind
+                )));
+            }
+        };
+        match received {
+            Some(reply) if reply.kind == FrameKind::Nak => write_frame(stream, frame).await?,
+            Some(reply) => return Ok(reply),
+            None => write_frame(stream, &Frame::empty(FrameKind::Nak, 0)).await?,
+        }
+    }
+    Err(CommsError::ChecksumFailure(MAX_HANDSHAKE_NAKS))
+}
+
+/// Host side of the handshake. Anything but a valid proof from the node is refused, including
+/// a node that doesn't know about authentication at all.
+pub async fn authenticate_host<S: AsyncRead + AsyncWrite + Unpin>(
+    stream: &mut S,
+    key: &[u8],
+) -> CommsResult<AuthSession> {
+    let host_nonce = nonce()?;
+    let hello = Frame::new(FrameKind::Hello, 0, host_nonce.to_vec());
+    let challenge = exchange(stream, &hello).await?;
+    match challenge.kind {
+        FrameKind::Challenge if challenge.payload.len() == NONCE_LEN + PROOF_LEN => {}
+        FrameKind::Error => {
+            return Err(CommsError::AuthFailed(format!(
+                "Node refused to authenticate: {}",
+                challenge.text()
+            )));
+        }
+        other => {
+            return Err(CommsError::AuthFailed(format!(
+                "Node answered Hello with {:?} ({} bytes)",
+                other,
+                challenge.payload.len()
+            )));
+        }
+    }
+    let (node_nonce, node_proof) = challenge.payload.split_at(NONCE_LEN);
+    let node_nonce: Nonce = node_nonce.try_into().expect("split at NONCE_LEN");
+    if auth::verify_proof(key, Role::Node, &host_nonce, &node_nonce, node_proof).is_err() {
+        return Err(CommsError::AuthFailed(
+            "Node doesn't know the shared secret".to_string(),
+        ));
+    }
+    let proof = auth::proof(key, Role::Host, &host_nonce, &node_nonce);
+    let auth = Frame::new(FrameKind::Auth, 0, proof.to_vec());
+    write_frame(stream, &auth).await?;
+    Ok(AuthSession {
+        session: Session::new(key, Role::Host, &host_nonce, &node_nonce),
+        unconfirmed: Some(auth),
+    })
+}
+
+/// Node side of the handshake, once `hello` has arrived. A host with the wrong key gets an
+/// `Error` frame before the node hangs up.
+pub async fn authenticate_node<S: AsyncRead + AsyncWrite + Unpin>(
+    stream: &mut S,
+    key: &[u8],
+    hello: &Frame,
+) -> CommsResult<AuthSession> {
+    let host_nonce: Nonce = hello.payload.as_slice().try_into().map_err(|_| {
+        CommsError::ProtocolViolation(format!("Hello with a {} byte nonce", hello.payload.len()))
+    })?;
+    let node_nonce = nonce()?;
+    let mut payload = node_nonce.to_vec();
+    payload.extend_from_slice(&auth::proof(key, Role::Node, &host_nonce, &node_nonce));
+    let reply = exchange(stream, &Frame::new(FrameKind::Challenge, 0, payload)).await?;
+    let verified = reply.kind == FrameKind::Auth
+        && auth::verify_proof(key, Role::Host, &host_nonce, &node_nonce, &reply.payload).is_ok();
+    if !verified {
+        let refusal = Frame::new(FrameKind::Error, 0, b"Authentication failed".to_vec());
+        write_frame(stream, &refusal).await?;
+        return Err(CommsError::AuthFailed(format!(
+            "Host answered the challenge with a bad {:?}",
+            reply.kind
+        )));
+    }
+    Ok(AuthSession {
+        session: Session::new(key, Role::Node, &host_nonce, &node_nonce),
+        unconfirmed: None,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::comms::chunked::{ChunkOptions, ChunkedUpload};
+    use crate::comms::frame::HEADER_LEN;
+    use crate::comms::host_node::{NodeState, serve_session};
+    use std::sync::Mutex;
+    use tokio::io::{AsyncWriteExt, DuplexStream};
+
+    fn lines() -> Vec<String> {
+        ["1", "2", "3", "2024"].map(String::from).to_vec()
+    }
+
+    /// Runs one upload against a host node, with the given keys on each side.
+    async fn upload(
+        host_key: Option<&[u8]>,
+        node_key: Option<&[u8]>,
+    ) -> (CommsResult<String>, CommsResult<()>) {
+        let mut upload = ChunkedUpload::new(2024, 22, 'B', &lines(), ChunkOptions::default());
+        if let Some(key) = host_key {
+            upload.authenticate_with(key);
+        }
+        let state = Mutex::new(NodeState::default());
+        let (mut host, mut device) = tokio::io::duplex(256);
+        tokio::join!(
+            async {
+                let answer = upload.run(&mut host).await;
+                drop(host);
+                answer
+            },
+            serve_session(&mut device, &state, node_key)
+        )
+    }
+
+    #[tokio::test]
+    async fn authenticated_upload_gets_the_answer() {
+        let (answer, _) = upload(Some(b"workshop"), Some(b"workshop")).await;
+        assert_eq!(answer.unwrap(), "23");
+    }
+
+    /// Passes frames between the two ends, flipping a payload bit of the first `Auth` frame.
+    async fn corrupt_first_auth(host: DuplexStream, node: DuplexStream) {
+        let (mut from_host, mut to_host) = tokio::io::split(host);
+        let (mut from_node, mut to_node) = tokio::io::split(node);
+        let upstream = async {
+            let mut corrupted = false;
+            while let Ok(Some(frame)) = read_frame(&mut from_host).await {
+                let mut bytes = frame.encode();
+                if frame.kind == FrameKind::Auth && !corrupted {
+                    corrupted = true;
+                    bytes[HEADER_LEN] ^= 0x01;
+                }
+                if to_node.write_all(&bytes).await.is_err() {
+                    break;
+                }
+            }
+            let _ = to_node.shutdown().await;
+        };
+        let downstream = tokio::io::copy(&mut from_node, &mut to_host);
+        let _ = tokio::join!(upstream, downstream);
+    }
+
+    #[tokio::test]
+    async fn corrupted_auth_is_sent_again() {
+        let mut upload = ChunkedUpload::new(2024, 22, 'B', &lines(), ChunkOptions::default());
+        upload.authenticate_with(b"workshop");
+        let state = Mutex::new(NodeState::default());
+        let (mut host, host_end) = tokio::io::duplex(256);
+        let (node_end, mut device) = tokio::io::duplex(256);
+        let (answer, served, _) = tokio::join!(
+            async {
+                let answer = upload.run(&mut host).await;
+                drop(host);
+                answer
+            },
+            async {
+                let served = serve_session(&mut device, &state, Some(b"workshop")).await;
+                drop(device);
+                served
+            },
+            corrupt_first_auth(host_end, node_end)
+        );
+        assert_eq!(answer.unwrap(), "23");
+        served.unwrap();
+        assert_eq!(upload.stats().retransmits, 1);
+    }
+
+    #[tokio::test]
+    async fn wrong_key_is_refused() {
+        let (answer, _) = upload(Some(b"workshop"), Some(b"guess")).await;
+        let e = answer.unwrap_err();
+        assert!(matches!(e, CommsError::AuthFailed(_)), "{}", e);
+        assert!(!e.is_transient());
+    }
+
+    #[tokio::test]
+    async fn node_without_a_key_is_refused() {
+        let (answer, _) = upload(Some(b"workshop"), None).await;
+        assert!(matches!(answer, Err(CommsError::AuthFailed(_))));
+    }
+
+    #[tokio::test]
+    async fn node_with_a_key_refuses_a_plain_upload() {
+        let (answer, served) = upload(None, Some(b"workshop")).await;
+        assert!(matches!(answer, Err(CommsError::Device(_))));
+        assert!(matches!(served, Err(CommsError::AuthFailed(_))));
+    }
+
+    #[tokio::test]
+    async fn node_refuses_a_host_with_the_wrong_proof() {
+        let (mut host, mut device) = tokio::io::duplex(256);
+        let state = Mutex::new(NodeState::default());
+        let node = serve_session(&mut device, &state, Some(b"workshop"));
+        let host = async {
+            let hello = Frame::new(FrameKind::Hello, 0, vec![7; NONCE_LEN]);
+            let challenge = exchange(&mut host, &hello).await.unwrap();
+            assert_eq!(challenge.kind, FrameKind::Challenge);
+            let forged = Frame::new(FrameKind::Auth, 0, vec![0; PROOF_LEN]);
+            exchange(&mut host, &forged).await.unwrap()
+        };
+        let (served, reply) = tokio::join!(node, host);
+        assert!(matches!(served, Err(CommsError::AuthFailed(_))));
+        assert_eq!(reply.kind, FrameKind::Error);
+    }
+}
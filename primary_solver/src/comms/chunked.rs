@@ -0,0 +1,682 @@
+use std::path::Path;
+
+use pico_protocol::Progress;
+use pico_protocol::input::EncodedInput;
+use serde::Deserialize;
+use tokio::io::{AsyncRead, AsyncWrite};
+use tokio::time::{Duration, sleep, timeout};
+
+use crate::comms::auth::{AuthSession, authenticate_host};
+use crate::comms::config::CommsConfig;
+use crate::comms::device_log::DeviceConsole;
+use crate::comms::error::{CommsError, CommsResult};
+use crate::comms::frame::{
+    Frame, FrameKind, InputEncoding, MAX_PAYLOAD, PROTOCOL_VERSION, StartInfo, read_frame,
+    write_frame,
+};
+use crate::comms::link::LinkStats;
+use crate::comms::transcript::TranscriptWriter;
+use crate::comms::transport::{SerialTransport, TcpTransport, Transport};
+
+const ACK_TIMEOUT: Duration = Duration::from_secs(10);
+const RESULT_TIMEOUT: Duration = Duration::from_secs(120);
+/// Past this many bad frames back to back the link is not going to recover on its own.
+const MAX_CORRUPT_IN_A_ROW: u32 = 5;
+
+#[derive(Debug, Clone, Copy, Deserialize)]
+#[serde(default)]
+pub struct ChunkOptions {
+    /// Target chunk size in bytes; chunks are cut on line boundaries where possible.
+    pub chunk_size: usize,
+    /// Number of chunks allowed in flight before waiting for an ack.
+    pub window: u16,
+}
+
+impl Default for ChunkOptions {
+    fn default() -> Self {
+        ChunkOptions {
+            chunk_size: 1024,
+            window: 4,
+        }
+    }
+}
+
+/// Splits the input (joined with `\n`, same bytes as the one-shot upload) into chunks of at most
+/// `chunk_size` bytes that end on a newline, so the node can consume whole lines per chunk.
+/// A single line longer than `chunk_size` is cut wherever it has to be.
+pub fn split_into_chunks(lines: &[String], chunk_size: usize) -> Vec<Vec<u8>> {
+    let data = lines.join("\n").into_bytes();
+    let chunk_size = chunk_size.clamp(1, MAX_PAYLOAD);
+    let mut chunks = Vec::new();
+    let mut start = 0;
+    while start < data.len() {
+        let mut end = (start + chunk_size).min(data.len());
+        if end < data.len()
+            && let Some(newline) = data[start..end].iter().rposition(|&b| b == b'\n')
+        {
+            end = start + newline + 1;
+        }
+        chunks.push(data[start..end].to_vec());
+        start = end;
+    }
+    chunks
+}
+
+/// FNV-1a over the puzzle and the data, so resending the same input maps to the same transfer.
+fn transfer_id(year: u16, day: u8, part: char, chunks: &[Vec<u8>]) -> u32 {
+    let mut hash: u32 = 0x811c9dc5;
+    let header = [year as u8, (year >> 8) as u8, day, part as u8];
+    for &byte in header.iter().chain(chunks.iter().flatten()) {
+        hash ^= byte as u32;
+        hash = hash.wrapping_mul(0x01000193);
+    }
+    hash
+}
+
+/// A transfer that remembers how far the node got, so it can be resumed on a new connection.
+pub struct ChunkedUpload {
+    start: StartInfo,
+    chunks: Vec<Vec<u8>>,
+    acked: u32,
+    /// Highest chunk index sent so far, to tell retransmits from first sends.
+    sent: u32,
+    stats: LinkStats,
+    console: DeviceConsole,
+    /// Shared secret to authenticate every connection with, if the node wants one.
+    key: Option<Vec<u8>>,
+    /// The current connection's session, once authenticated.
+    auth: Option<AuthSession>,
+}
+
+impl ChunkedUpload {
+    pub fn new(year: u16, day: u8, part: char, lines: &[String], options: ChunkOptions) -> Self {
+        let chunks = split_into_chunks(lines, options.chunk_size);
+        Self::from_chunks(year, day, part, chunks, InputEncoding::Text, options)
+    }
+
+    /// Uploads pre-parsed input instead of text. There are no lines to respect, so the bytes
+    /// are cut into plain `chunk_size` pieces.
+    pub fn binary(input: &EncodedInput, part: char, options: ChunkOptions) -> Self {
+        let chunks = input
+            .encode()
+            .chunks(options.chunk_size.clamp(1, MAX_PAYLOAD))
+            .map(<[u8]>::to_vec)
+            .collect();
+        Self::from_chunks(
+            input.year,
+            input.day,
+            part,
+            chunks,
+            InputEncoding::Binary,
+            options,
+        )
+    }
+
+    fn from_chunks(
+        year: u16,
+        day: u8,
+        part: char,
+        chunks: Vec<Vec<u8>>,
+        encoding: InputEncoding,
+        options: ChunkOptions,
+    ) -> Self {
+        let start = StartInfo {
+            version: PROTOCOL_VERSION,
+            year,
+            day,
+            part,
+            transfer_id: transfer_id(year, day, part, &chunks),
+            total_len: chunks.iter().map(|c| c.len() as u32).sum(),
+            chunk_count: chunks.len() as u32,
+            window: options.window.max(1),
+            encoding,
+        };
+        Self::from_parts(start, chunks)
+    }
+
+    /// Rebuilds an upload from its `Start` info and chunks, e.g. out of a transcript.
+    pub fn from_parts(start: StartInfo, chunks: Vec<Vec<u8>>) -> Self {
+        ChunkedUpload {
+            start,
+            chunks,
+            acked: 0,
+            sent: 0,
+            stats: LinkStats::default(),
+            console: DeviceConsole::default(),
+            key: None,
+            auth: None,
+        }
+    }
+
+    /// Makes every connection start with the handshake in `pico_protocol::auth`, and refuses
+    /// nodes that don't know `key`.
+    pub fn authenticate_with(&mut self, key: &[u8]) {
+        self.key = Some(key.to_vec());
+    }
+
+    pub fn chunk_count(&self) -> u32 {
+        self.start.chunk_count
+    }
+
+    /// Number of chunks the node has acknowledged so far.
+    pub fn acked(&self) -> u32 {
+        self.acked
+    }
+
+    pub fn stats(&self) -> LinkStats {
+        self.stats
+    }
+
+    /// Where the node's log and progress frames go.
+    pub fn console(&mut self) -> &mut DeviceConsole {
+        &mut self.console
+    }
+
+    /// Runs (or resumes) the transfer over `stream` and returns the node's answer.
+    pub async fn run<S: AsyncRead + AsyncWrite + Unpin>(
+        &mut self,
+        stream: &mut S,
+    ) -> CommsResult<String> {
+        let result = self.transfer(stream).await;
+        self.console.end_status();
+        result
+    }
+
+    async fn transfer<S: AsyncRead + AsyncWrite + Unpin>(
+        &mut self,
+        stream: &mut S,
+    ) -> CommsResult<String> {
+        // A new connection is a new session, even when it resumes the same transfer.
+        self.auth = None;
+        if let Some(key) = &self.key {
+            self.auth = Some(authenticate_host(stream, key).await?);
+        }
+        let start = Frame::new(FrameKind::Start, 0, self.start.encode().to_vec());
+        self.send(stream, &start).await?;
+        let ready = loop {
+            let frame = self.expect(stream, FrameKind::Ready, ACK_TIMEOUT).await?;
+            if frame.kind == FrameKind::Nak {
+                self.stats.retransmits += 1;
+                self.send(stream, &start).await?;
+                continue;
+            }
+            break frame;
+        };
+        if ready.seq > self.start.chunk_count {
+            return Err(protocol_error(format!(
+                "Node asked to resume at chunk {} of {}",
+                ready.seq, self.start.chunk_count
+            )));
+        }
+        // The node is the one that knows what it has consumed, so it decides where we resume.
+        self.acked = ready.seq;
+
+        let mut next = self.acked;
+        while self.acked < self.start.chunk_count {
+            while next < self.start.chunk_count && next - self.acked < self.start.window as u32 {
+                if next < self.sent {
+                    self.stats.retransmits += 1;
+                }
+                let chunk = Frame::new(FrameKind::Chunk, next, self.chunks[next as usize].clone());
+                self.send(stream, &chunk).await?;
+                next += 1;
+                self.sent = self.sent.max(next);
+            }
+            let frame = self.expect(stream, FrameKind::Ack, ACK_TIMEOUT).await?;
+            if frame.seq >= next || frame.seq + 1 < self.acked {
+                return Err(protocol_error(format!(
+                    "Node sent {:?} for chunk {} but chunks {}..{} are in flight",
+                    frame.kind, frame.seq, self.acked, next
+                )));
+            }
+            if frame.kind == FrameKind::Nak {
+                // Go back N: everything from the chunk the node is missing gets sent again.
+                self.acked = self.acked.max(frame.seq);
+                next = frame.seq;
+                continue;
+            }
+            self.acked = self.acked.max(frame.seq + 1);
+        }
+
+        let result = self
+            .expect(stream, FrameKind::Result, RESULT_TIMEOUT)
+            .await?;
+        if result.kind == FrameKind::Nak {
+            return Err(protocol_error(
+                "Node sent a Nak after every chunk was acked".to_string(),
+            ));
+        }
+        Ok(result.text().trim().to_string())
+    }
+
+    async fn send<S: AsyncWrite + Unpin>(
+        &mut self,
+        stream: &mut S,
+        frame: &Frame,
+    ) -> CommsResult<()> {
+        match &mut self.auth {
+            Some(auth) => write_frame(stream, &auth.seal(frame)).await?,
+            None => write_frame(stream, frame).await?,
+        }
+        self.stats.frames_sent += 1;
+        Ok(())
+    }
+
+    /// Waits for a frame of `kind` (or a Nak, which the caller deals with). Corrupted frames are
+    /// counted and Nak'd so the node repeats them. Log and progress frames are shown as they
+    /// come in, and since each one restarts `wait` a long solve that reports progress doesn't
+    /// time out.
+    async fn expect<S: AsyncRead + AsyncWrite + Unpin>(
+        &mut self,
+        stream: &mut S,
+        kind: FrameKind,
+        wait: Duration,
+    ) -> CommsResult<Frame> {
+        let mut corrupt_in_a_row = 0;
+        loop {
+            let received = match timeout(wait, read_frame(stream)).await {
+                Ok(received) => received?,
+                Err(_) => {
+                    return Err(CommsError::TimedOut(format!("waiting for {:?}", kind)));
+                }
+            };
+            let frame = match (received, &mut self.auth) {
+                (Some(frame), Some(auth)) => match auth.lost_auth(&frame) {
+                    // The node is still waiting on our Auth, so that goes first and the caller
+                    // repeats whatever it sent after it.
+                    Some(proof) => {
+                        write_frame(stream, proof).await?;
+                        self.stats.naks_received += 1;
+                        return Ok(frame);
+                    }
+                    None => auth.open(frame)?,
+                },
+                (Some(frame), None) => frame,
+                (None, _) => {
+                    self.stats.crc_errors += 1;
+                    corrupt_in_a_row += 1;
+                    if corrupt_in_a_row > MAX_CORRUPT_IN_A_ROW {
+                        return Err(CommsError::ChecksumFailure(corrupt_in_a_row));
+                    }
+                    let nak = Frame::empty(FrameKind::Nak, self.acked);
+                    self.send(stream, &nak).await?;
+                    continue;
+                }
+            };
+            self.stats.frames_received += 1;
+            corrupt_in_a_row = 0;
+            return match frame.kind {
+                FrameKind::Log => {
+                    self.console.log(&frame.text());
+                    continue;
+                }
+                FrameKind::Progress => {
+                    match Progress::decode(&frame.payload) {
+                        Some(progress) => self.console.progress(&progress),
+                        None => self.console.log("(malformed progress frame)"),
+                    }
+                    continue;
+                }
+                k if k == kind => Ok(frame),
+                FrameKind::Nak => {
+                    self.stats.naks_received += 1;
+                    Ok(frame)
+                }
+                FrameKind::Error => Err(CommsError::Device(frame.text())),
+                other => Err(protocol_error(format!(
+                    "Expected {:?}, got {:?}",
+                    kind, other
+                ))),
+            };
+        }
+    }
+}
+
+fn report(stats: LinkStats) {
+    if stats.is_clean() {
+        println!("Link: {}", stats);
+    } else {
+        println!("Link had errors: {}", stats);
+    }
+}
+
+fn protocol_error(message: String) -> CommsError {
+    CommsError::ProtocolViolation(message)
+}
+
+/// Streams the input to the configured node in acknowledged chunks, over the serial port if
+/// `config.serial` is set and TCP otherwise. Transient failures (including the node rebooting)
+/// are retried per `config.retry`, resuming from the last chunk the node acknowledged. With a
+/// `shared_secret` configured, nodes that can't authenticate are refused.
+pub async fn upload_chunked(
+    config: &CommsConfig,
+    year: u16,
+    day: u8,
+    part: char,
+    lines: &[String],
+) -> CommsResult<String> {
+    match &config.serial {
+        Some(serial) => {
+            let transport = SerialTransport::new(serial);
+            upload_via(&transport, config, year, day, part, lines).await
+        }
+        None => {
+            let transport = TcpTransport::new(config.node_address());
+            upload_via(&transport, config, year, day, part, lines).await
+        }
+    }
+}
+
+/// `upload_chunked` over any transport.
+pub async fn upload_via<T: Transport>(
+    transport: &T,
+    config: &CommsConfig,
+    year: u16,
+    day: u8,
+    part: char,
+    lines: &[String],
+) -> CommsResult<String> {
+    let options = config.chunk;
+    let binary = config
+        .binary_input
+        .then(|| EncodedInput::parse(year, day, lines))
+        .flatten();
+    let mut upload = match &binary {
+        Some(input) => {
+            println!("Sending {} day {} pre-parsed", year, day);
+            ChunkedUpload::binary(input, part, options)
+        }
+        None => ChunkedUpload::new(year, day, part, lines, options),
+    };
+    if let Some(secret) = &config.shared_secret {
+        upload.authenticate_with(secret.as_bytes());
+    }
+    println!(
+        "Uploading {} chunks of up to {} bytes (window {})",
+        upload.chunk_count(),
+        options.chunk_size,
+        options.window
+    );
+    if let Some(path) = &config.device_log
+        && let Err(e) = upload.console().save_to(Path::new(path))
+    {
+        eprintln!("Not saving the device log to {}: {}", path, e);
+    }
+    let mut transcript = match &config.transcript {
+        Some(path) => match TranscriptWriter::open(Path::new(path)) {
+            Ok(writer) => Some(writer),
+            Err(e) => {
+                eprintln!("Not recording a transcript to {}: {}", path, e);
+                None
+            }
+        },
+        None => None,
+    };
+    let mut attempt = 1;
+    loop {
+        let result = match (transport.open().await, &mut transcript) {
+            (Ok(stream), Some(transcript)) => {
+                let mut recorded = transcript.record(&transport.describe(), stream);
+                upload.run(&mut recorded).await
+            }
+            (Ok(mut stream), None) => upload.run(&mut stream).await,
+            (Err(e), _) => Err(e),
+        };
+        match result {
+            Ok(answer) => {
+                report(upload.stats());
+                return Ok(answer);
+            }
+            Err(e) => match config.retry.next_delay(attempt, &e) {
+                Some(delay) => {
+                    eprintln!(
+                        "Attempt {}/{} failed after {}/{} chunks ({}), retrying in {:.1?}",
+                        attempt,
+                        config.retry.max_attempts,
+                        upload.acked(),
+                        upload.chunk_count(),
+                        e,
+                        delay
+                    );
+                    sleep(delay).await;
+                    attempt += 1;
+                }
+                None => {
+                    report(upload.stats());
+                    return Err(e);
+                }
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tokio::io::{AsyncWriteExt, DuplexStream};
+
+    #[derive(Default)]
+    struct FakeNode {
+        transfer_id: Option<u32>,
+        received: Vec<u8>,
+        next_seq: u32,
+        resumed_at: Vec<u32>,
+        last_sent: Option<Frame>,
+        /// Pretend this chunk failed its CRC the first time it arrives.
+        reject_chunk: Option<u32>,
+        /// Flip a byte in the ack for this chunk the first time it is sent.
+        garble_ack: Option<u32>,
+        /// Log and report progress while "solving", like firmware with logging on.
+        chatty: bool,
+    }
+
+    impl FakeNode {
+        async fn send(&mut self, stream: &mut DuplexStream, frame: Frame) {
+            let mut bytes = frame.encode();
+            if frame.kind == FrameKind::Ack && self.garble_ack == Some(frame.seq) {
+                self.garble_ack = None;
+                bytes[2] ^= 0x40;
+            }
+            stream.write_all(&bytes).await.unwrap();
+            self.last_sent = Some(frame);
+        }
+    }
+
+    /// Minimal node: acks chunks in order (go-back-N on a bad chunk) and answers with the
+    /// number of lines received. Hangs up once `drop_at` chunks have arrived, if set.
+    async fn serve(node: &mut FakeNode, mut stream: DuplexStream, drop_at: Option<u32>) {
+        let start = read_frame(&mut stream).await.unwrap().unwrap();
+        let info = StartInfo::decode(&start.payload).unwrap();
+        if node.transfer_id != Some(info.transfer_id) {
+            node.transfer_id = Some(info.transfer_id);
+            node.received.clear();
+            node.next_seq = 0;
+        }
+        node.resumed_at.push(node.next_seq);
+        node.send(&mut stream, Frame::empty(FrameKind::Ready, node.next_seq))
+            .await;
+        while node.next_seq < info.chunk_count {
+            if drop_at == Some(node.next_seq) {
+                return;
+            }
+            let frame = read_frame(&mut stream).await.unwrap().unwrap();
+            match frame.kind {
+                FrameKind::Nak => {
+                    let last = node.last_sent.clone().unwrap();
+                    node.send(&mut stream, last).await;
+                }
+                FrameKind::Chunk if node.reject_chunk == Some(frame.seq) => {
+                    node.reject_chunk = None;
+                    node.send(&mut stream, Frame::empty(FrameKind::Nak, node.next_seq))
+                        .await;
+                }
+                FrameKind::Chunk if frame.seq == node.next_seq => {
+                    node.received.extend_from_slice(&frame.payload);
+                    node.next_seq += 1;
+                    node.send(&mut stream, Frame::empty(FrameKind::Ack, frame.seq))
+                        .await;
+                }
+                FrameKind::Chunk => {}
+                other => panic!("unexpected {:?}", other),
+            }
+        }
+        if node.chatty {
+            node.send(
+                &mut stream,
+                Frame::new(FrameKind::Log, 0, b"parsed".to_vec()),
+            )
+            .await;
+            for done in 1..=3 {
+                let progress = Progress {
+                    done,
+                    total: 3,
+                    stage: "lines",
+                };
+                node.send(
+                    &mut stream,
+                    Frame::new(FrameKind::Progress, 0, progress.encode()),
+                )
+                .await;
+            }
+        }
+        let lines = node.received.split(|&b| b == b'\n').count();
+        let answer = format!("{}", lines).into_bytes();
+        node.send(&mut stream, Frame::new(FrameKind::Result, 0, answer))
+            .await;
+        // Answer any Nak for the result itself.
+        while let Ok(Some(frame)) = read_frame(&mut stream).await {
+            if frame.kind == FrameKind::Nak {
+                let last = node.last_sent.clone().unwrap();
+                node.send(&mut stream, last).await;
+            }
+        }
+    }
+
+    fn sample_lines() -> Vec<String> {
+        (0..200)
+            .map(|i| format!("{}: {}", i, "x".repeat(i % 37)))
+            .collect()
+    }
+
+    fn options(window: u16) -> ChunkOptions {
+        ChunkOptions {
+            chunk_size: 100,
+            window,
+        }
+    }
+
+    #[test]
+    fn chunks_end_on_line_boundaries() {
+        let lines = sample_lines();
+        let chunks = split_into_chunks(&lines, 128);
+        assert_eq!(chunks.concat(), lines.join("\n").into_bytes());
+        for chunk in &chunks[..chunks.len() - 1] {
+            assert!(chunk.len() <= 128);
+            assert_eq!(chunk.last(), Some(&b'\n'));
+        }
+    }
+
+    #[tokio::test]
+    async fn uploads_in_windowed_chunks() {
+        let lines = sample_lines();
+        let mut upload = ChunkedUpload::new(2025, 8, 'A', &lines, options(3));
+        let (mut host, device) = tokio::io::duplex(256);
+        let mut node = FakeNode::default();
+        let (answer, _) = tokio::join!(
+            async {
+                let answer = upload.run(&mut host).await;
+                drop(host);
+                answer
+            },
+            serve(&mut node, device, None)
+        );
+        assert_eq!(answer.unwrap(), "200");
+        assert_eq!(node.received, lines.join("\n").into_bytes());
+        assert!(upload.stats().is_clean());
+    }
+
+    #[tokio::test]
+    async fn resumes_from_last_acked_chunk() {
+        let lines = sample_lines();
+        let mut upload = ChunkedUpload::new(2024, 22, 'B', &lines, options(2));
+        let mut node = FakeNode::default();
+
+        let (mut host, device) = tokio::io::duplex(256);
+        let (first, _) = tokio::join!(upload.run(&mut host), serve(&mut node, device, Some(5)));
+        assert!(first.is_err());
+
+        let (mut host, device) = tokio::io::duplex(256);
+        let (second, _) = tokio::join!(
+            async {
+                let answer = upload.run(&mut host).await;
+                drop(host);
+                answer
+            },
+            serve(&mut node, device, None)
+        );
+        assert_eq!(second.unwrap(), "200");
+        assert_eq!(node.resumed_at, vec![0, 5]);
+        assert_eq!(node.received, lines.join("\n").into_bytes());
+    }
+
+    #[tokio::test]
+    async fn recovers_from_corruption_in_both_directions() {
+        let lines = sample_lines();
+        let mut upload = ChunkedUpload::new(2025, 2, 'B', &lines, options(4));
+        let (mut host, device) = tokio::io::duplex(256);
+        let mut node = FakeNode {
+            reject_chunk: Some(3),
+            garble_ack: Some(6),
+            ..FakeNode::default()
+        };
+        let (answer, _) = tokio::join!(
+            async {
+                let answer = upload.run(&mut host).await;
+                drop(host);
+                answer
+            },
+            serve(&mut node, device, None)
+        );
+        assert_eq!(answer.unwrap(), "200");
+        assert_eq!(node.received, lines.join("\n").into_bytes());
+        let stats = upload.stats();
+        assert_eq!(stats.crc_errors, 1);
+        assert_eq!(stats.naks_received, 1);
+        assert!(stats.retransmits >= 1);
+    }
+
+    #[tokio::test]
+    async fn shows_log_and_progress_before_the_result() {
+        let lines = sample_lines();
+        let mut upload = ChunkedUpload::new(2025, 8, 'A', &lines, options(4));
+        let path = std::env::temp_dir().join(format!("device-log-{}.txt", std::process::id()));
+        let _ = std::fs::remove_file(&path);
+        upload.console().save_to(&path).unwrap();
+        let (mut host, device) = tokio::io::duplex(256);
+        let mut node = FakeNode {
+            chatty: true,
+            ..FakeNode::default()
+        };
+        let (answer, _) = tokio::join!(
+            async {
+                let answer = upload.run(&mut host).await;
+                drop(host);
+                answer
+            },
+            serve(&mut node, device, None)
+        );
+        assert_eq!(answer.unwrap(), "200");
+        assert_eq!(upload.console().logged, 1);
+        assert_eq!(upload.console().last_progress, Some((3, 3)));
+        let saved = std::fs::read_to_string(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(saved.lines().count(), 4);
+        assert!(
+            saved
+                .lines()
+                .last()
+                .unwrap()
+                .ends_with("progress lines 3/3")
+        );
+    }
+}
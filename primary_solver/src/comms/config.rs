@@ -0,0 +1,82 @@
+use std::fs;
+use std::path::Path;
+
+use serde::Deserialize;
+
+use crate::comms::chunked::ChunkOptions;
+use crate::comms::discovery::DiscoveryOptions;
+use crate::comms::host_node::HOST_NODE_PORT;
+use crate::comms::pico_sender::PICO_ADDRESS;
+use crate::comms::retry::RetryPolicy;
+use crate::comms::transport::SerialOptions;
+
+const CONFIG_PATH: &str = "primary_solver/comms.json";
+
+/// Settings for talking to nodes, read from `primary_solver/comms.json` when it exists.
+/// Every field is optional, e.g. `{ "address": "10.0.0.42:1234", "retry": { "max_attempts": 20 } }`.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct CommsConfig {
+    /// Fixed node address. Leave unset to find one with LAN discovery.
+    pub address: Option<String>,
+    /// Nodes to shard a puzzle across. Leave empty to use every node discovery finds.
+    pub nodes: Vec<String>,
+    /// TCP port to listen on when this machine runs as a node.
+    pub node_port: u16,
+    /// Talk to the node over this serial port instead of TCP.
+    pub serial: Option<SerialOptions>,
+    /// Send pre-parsed binary input (see `pico_protocol::input`) for the days that have a packed format.
+    pub binary_input: bool,
+    /// File to append a timestamped record of every frame to (JSON lines), for `replay`.
+    pub transcript: Option<String>,
+    /// File to append the node's log and progress messages to.
+    pub device_log: Option<String>,
+    /// Key shared with the nodes. When set, the host and nodes authenticate each other and tag
+    /// every frame, and neither talks to anything that doesn't know it.
+    pub shared_secret: Option<String>,
+    pub discovery: DiscoveryOptions,
+    pub chunk: ChunkOptions,
+    pub retry: RetryPolicy,
+}
+
+impl Default for CommsConfig {
+    fn default() -> Self {
+        CommsConfig {
+            address: None,
+            nodes: Vec::new(),
+            node_port: HOST_NODE_PORT,
+            serial: None,
+            binary_input: false,
+            transcript: None,
+            device_log: None,
+            shared_secret: None,
+            discovery: DiscoveryOptions::default(),
+            chunk: ChunkOptions::default(),
+            retry: RetryPolicy::default(),
+        }
+    }
+}
+
+impl CommsConfig {
+    /// The configured or discovered address, falling back to the workshop Pico's last known one.
+    pub fn node_address(&self) -> &str {
+        self.address.as_deref().unwrap_or(PICO_ADDRESS)
+    }
+
+    pub fn load() -> Self {
+        Self::load_from(Path::new(CONFIG_PATH))
+    }
+
+    pub fn load_from(path: &Path) -> Self {
+        let Ok(content) = fs::read_to_string(path) else {
+            return CommsConfig::default();
+        };
+        match serde_json::from_str(&content) {
+            Ok(config) => config,
+            Err(e) => {
+                eprintln!("Ignoring {}: {}", path.display(), e);
+                CommsConfig::default()
+            }
+        }
+    }
+}
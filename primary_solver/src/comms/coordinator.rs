@@ -0,0 +1,329 @@
+use std::collections::VecDeque;
+
+use tokio::task::JoinSet;
+
+use crate::comms::chunked::upload_chunked;
+use crate::comms::config::CommsConfig;
+use crate::comms::discovery::discover_nodes;
+use crate::comms::error::{CommsError, CommsResult};
+use crate::comms::pico_sender::answer_from_reply;
+
+/// Shards per node, so a dropped node's work can be spread over the others.
+const SHARDS_PER_NODE: usize = 2;
+
+/// Splits an input into the lines every shard needs (e.g. shape definitions) and the records.
+type Split = fn(&[String]) -> (Vec<String>, Vec<String>);
+
+/// How a day's input breaks into independent records and how the partial answers combine.
+struct DayShape {
+    split: Split,
+    /// Rebuilds a valid input from the shared lines and one shard's records.
+    join: fn(&[String], &[String]) -> Vec<String>,
+    /// Combines the shard answers into the puzzle answer.
+    reduce: fn(&[String]) -> Option<String>,
+    /// Parts whose answer is a function of independent per-record results.
+    parts: &'static [char],
+}
+
+fn split_lines(lines: &[String]) -> (Vec<String>, Vec<String>) {
+    (Vec::new(), lines.to_vec())
+}
+
+fn join_lines(shared: &[String], records: &[String]) -> Vec<String> {
+    shared.iter().chain(records).cloned().collect()
+}
+
+/// 2025 day02 is one line of comma separated ranges.
+fn split_ranges(lines: &[String]) -> (Vec<String>, Vec<String>) {
+    let ranges = lines
+        .concat()
+        .split(',')
+        .map(str::trim)
+        .filter(|r| !r.is_empty())
+        .map(String::from)
+        .collect();
+    (Vec::new(), ranges)
+}
+
+fn join_ranges(_shared: &[String], records: &[String]) -> Vec<String> {
+    vec![records.join(",")]
+}
+
+/// 2025 day12: the shape blocks are shared, each `WxH: counts` region line is a record.
+fn split_regions(lines: &[String]) -> (Vec<String>, Vec<String>) {
+    let is_region = |line: &String| line.contains('x') && line.contains(':');
+    let first_region = lines.iter().position(is_region).unwrap_or(lines.len());
+    (
+        lines[..first_region].to_vec(),
+        lines[first_region..].to_vec(),
+    )
+}
+
+fn sum(answers: &[String]) -> Option<String> {
+    let mut total: i128 = 0;
+    for answer in answers {
+        total += answer.trim().parse::<i128>().ok()?;
+    }
+    Some(total.to_string())
+}
+
+fn day_shape(year: u16, day: u8) -> Option<DayShape> {
+    let lines = |parts| DayShape {
+        split: split_lines,
+        join: join_lines,
+        reduce: sum,
+        parts,
+    };
+    match (year, day) {
+        (2024, 7) => Some(lines(&['A', 'B'])),
+        // Part B picks one price sequence across all buyers, so it doesn't shard.
+        (2024, 22) => Some(lines(&['A'])),
+        (2025, 2) => Some(DayShape {
+            split: split_ranges,
+            join: join_ranges,
+            reduce: sum,
+            parts: &['A', 'B'],
+        }),
+        (2025, 10) => Some(lines(&['A', 'B'])),
+        (2025, 12) => Some(DayShape {
+            split: split_regions,
+            join: join_lines,
+            reduce: sum,
+            parts: &['A'],
+        }),
+        _ => None,
+    }
+}
+
+/// Splits `records` into at most `count` contiguous, non-empty shards.
+fn make_shards(records: &[String], count: usize) -> Vec<Vec<String>> {
+    let count = count.clamp(1, records.len().max(1));
+    let size = records.len().div_ceil(count).max(1);
+    records.chunks(size).map(|chunk| chunk.to_vec()).collect()
+}
+
+/// Solves one puzzle part by sharding its input across `nodes` and reducing the answers.
+/// A node that drops out (after its retries) is dropped and its shard goes back in the queue
+/// for the nodes that are left. Any other failure, like a node rejecting its shard, would fail
+/// the same way everywhere, so it ends the run.
+pub async fn run_sharded(
+    config: &CommsConfig,
+    nodes: &[String],
+    year: u16,
+    day: u8,
+    part: char,
+    lines: &[String],
+) -> CommsResult<String> {
+    let shape = day_shape(year, day)
+        .filter(|shape| shape.parts.contains(&part))
+        .ok_or_else(|| {
+            CommsError::ProtocolViolation(format!(
+                "{} day {} part {} can't be sharded",
+                year, day, part
+            ))
+        })?;
+    if nodes.is_empty() {
+        return Err(CommsError::Unreachable(std::io::Error::other(
+            "No nodes to shard across",
+        )));
+    }
+    let (shared, records) = (shape.split)(lines);
+    let shards = make_shards(&records, nodes.len() * SHARDS_PER_NODE);
+    println!(
+        "Sharding {} records into {} shards across {} nodes",
+        records.len(),
+        shards.len(),
+        nodes.len()
+    );
+
+    let mut pending: VecDeque<usize> = (0..shards.len()).collect();
+    let mut idle: Vec<String> = nodes.to_vec();
+    let mut answers: Vec<Option<String>> = vec![None; shards.len()];
+    let mut running = JoinSet::new();
+    loop {
+        while !idle.is_empty() && !pending.is_empty() {
+            let node = idle.pop().unwrap();
+            let shard = pending.pop_front().unwrap();
+            // Each shard goes to its node over the network, never down the configured serial
+            // port, which would send every shard to the same Pico.
+            let node_config = CommsConfig {
+                address: Some(node.clone()),
+                serial: None,
+                ..config.clone()
+            };
+            let input = (shape.join)(&shared, &shards[shard]);
+            running.spawn(async move {
+                let result = upload_chunked(&node_config, year, day, part, &input).await;
+                (node, shard, result)
+            });
+        }
+        let Some(finished) = running.join_next().await else {
+            break;
+        };
+        let (node, shard, result) = finished.expect("shard task panicked");
+        match result {
+            Ok(reply) => {
+                answers[shard] = Some(answer_from_reply(&reply));
+                idle.push(node);
+            }
+            Err(e) if e.is_transient() => {
+                eprintln!(
+                    "Node {} dropped out ({}), reassigning shard {}",
+                    node, e, shard
+                );
+                pending.push_back(shard);
+            }
+            Err(e) => {
+                eprintln!("Node {} failed shard {}: {}", node, shard, e);
+                return Err(e);
+            }
+        }
+    }
+
+    if !pending.is_empty() {
+        return Err(CommsError::Unreachable(std::io::Error::other(format!(
+            "Every node dropped out with {} shards left",
+            pending.len()
+        ))));
+    }
+    let answers: Vec<String> = answers.into_iter().flatten().collect();
+    (shape.reduce)(&answers).ok_or_else(|| {
+        CommsError::ProtocolViolation(format!("Couldn't combine shard answers {:?}", answers))
+    })
+}
+
+/// The nodes to shard across: `config.nodes` if set, otherwise every discovered node that
+/// advertises the puzzle.
+pub async fn shard_nodes(config: &CommsConfig, year: u16, day: u8) -> Vec<String> {
+    if !config.nodes.is_empty() {
+        return config.nodes.clone();
+    }
+    match discover_nodes(&config.discovery).await {
+        Ok(nodes) => nodes
+            .iter()
+            .filter(|node| node.supports(year, day))
+            .map(|node| node.address.to_string())
+            .collect(),
+        Err(e) => {
+            eprintln!("Discovery failed: {}", e);
+            Vec::new()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::comms::frame::{Frame, FrameKind, read_frame, write_frame};
+    use crate::comms::host_node::serve_uploads;
+    use crate::comms::retry::RetryPolicy;
+    use crate::comms::transport::SerialOptions;
+    use aoc_solvers::solver::solve_puzzle;
+    use tokio::net::TcpListener;
+
+    const RANGES: &str = "11-22,95-115,998-1012,1188511880-1188511890,222220-222224,\
+        1698522-1698528,446443-446449,38593856-38593862,565653-565659,\
+        824824821-824824827,2121212118-2121212124";
+
+    async fn host_node() -> String {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = listener.local_addr().unwrap().to_string();
+        tokio::spawn(serve_uploads(listener, None));
+        address
+    }
+
+    /// A node that accepts connections and hangs up straight away, like a Pico mid-reboot.
+    async fn dead_node() -> String {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = listener.local_addr().unwrap().to_string();
+        tokio::spawn(async move {
+            while let Ok((stream, _)) = listener.accept().await {
+                drop(stream);
+            }
+        });
+        address
+    }
+
+    /// A node that answers every upload with an error, like a solver rejecting its input.
+    async fn refusing_node() -> String {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = listener.local_addr().unwrap().to_string();
+        tokio::spawn(async move {
+            while let Ok((mut stream, _)) = listener.accept().await {
+                let _ = read_frame(&mut stream).await;
+                let error = Frame::new(FrameKind::Error, 0, b"Bad shard".to_vec());
+                let _ = write_frame(&mut stream, &error).await;
+            }
+        });
+        address
+    }
+
+    fn quick_config() -> CommsConfig {
+        CommsConfig {
+            retry: RetryPolicy {
+                max_attempts: 2,
+                initial_backoff_ms: 10,
+                max_backoff_ms: 10,
+                multiplier: 1.0,
+            },
+            ..CommsConfig::default()
+        }
+    }
+
+    #[test]
+    fn region_lines_are_split_from_shapes() {
+        let lines: Vec<String> = ["0:", "##", "", "4x4: 1", "5x3: 0"]
+            .iter()
+            .map(|s| s.to_string())
+            .collect();
+        let (shared, records) = split_regions(&lines);
+        assert_eq!(shared, vec!["0:", "##", ""]);
+        assert_eq!(records, vec!["4x4: 1", "5x3: 0"]);
+        assert_eq!(make_shards(&records, 4).len(), 2);
+    }
+
+    #[tokio::test]
+    async fn sharded_answer_matches_single_solve() {
+        let lines = vec![RANGES.to_string()];
+        let nodes = vec![host_node().await, host_node().await, dead_node().await];
+        for part in ['A', 'B'] {
+            let expected = solve_puzzle(2025, 2, part, &lines).await.unwrap().unwrap();
+            let sharded = run_sharded(&quick_config(), &nodes, 2025, 2, part, &lines)
+                .await
+                .unwrap();
+            assert_eq!(sharded, expected);
+        }
+    }
+
+    #[tokio::test]
+    async fn shards_ignore_the_serial_port() {
+        let lines = vec![RANGES.to_string()];
+        let nodes = vec![host_node().await];
+        let config = CommsConfig {
+            serial: Some(SerialOptions {
+                path: "/dev/no-such-pico".to_string(),
+                baud: 115_200,
+            }),
+            ..quick_config()
+        };
+        let expected = solve_puzzle(2025, 2, 'A', &lines).await.unwrap().unwrap();
+        let sharded = run_sharded(&config, &nodes, 2025, 2, 'A', &lines).await;
+        assert_eq!(sharded.unwrap(), expected);
+    }
+
+    #[tokio::test]
+    async fn fails_when_every_node_drops() {
+        let lines = vec![RANGES.to_string()];
+        let nodes = vec![dead_node().await];
+        let result = run_sharded(&quick_config(), &nodes, 2025, 2, 'A', &lines).await;
+        assert!(matches!(result, Err(CommsError::Unreachable(_))));
+    }
+
+    #[tokio::test]
+    async fn rejected_shard_ends_the_run() {
+        let lines = vec![RANGES.to_string()];
+        let nodes = vec![host_node().await, refusing_node().await];
+        let result = run_sharded(&quick_config(), &nodes, 2025, 2, 'A', &lines).await;
+        assert!(matches!(result, Err(CommsError::Device(_))), "{:?}", result);
+    }
+}
@@ -0,0 +1,191 @@
+use std::time::{Duration, Instant};
+
+use crate::comms::config::CommsConfig;
+use crate::comms::error::{CommsError, CommsResult};
+use crate::comms::pico_sender::solve_on_node;
+use aoc_solvers::solver::DaySolver;
+
+const RED: &str = "\x1b[31m";
+const GREEN: &str = "\x1b[32m";
+const RESET: &str = "\x1b[0m";
+
+/// The outcome of running one part on the host and on a node.
+#[derive(Debug)]
+pub struct CrossCheck {
+    pub part: char,
+    pub host_answer: String,
+    pub node_answer: String,
+    pub host_time: Duration,
+    pub node_time: Duration,
+}
+
+impl CrossCheck {
+    pub fn matches(&self) -> bool {
+        self.host_answer == self.node_answer
+    }
+
+    /// How many times slower the node was than the host (includes the network round trip).
+    pub fn speed_ratio(&self) -> f64 {
+        self.node_time.as_secs_f64() / self.host_time.as_secs_f64().max(f64::EPSILON)
+    }
+
+    /// True when the node answer is the host answer wrapped to 32 bits, the classic
+    /// `usize`/`i32` overflow on the RP2040.
+    pub fn looks_like_32bit_overflow(&self) -> bool {
+        match (
+            self.host_answer.parse::<i128>(),
+            self.node_answer.parse::<i128>(),
+        ) {
+            (Ok(host), Ok(node)) => {
+                host != node && (host as u32 as i128 == node || host as i32 as i128 == node)
+            }
+            _ => false,
+        }
+    }
+
+    pub fn print(&self) {
+        println!(
+            "Part {}: host {} in {:.2?}, node {} in {:.2?} ({:.1}x)",
+            self.part,
+            self.host_answer,
+            self.host_time,
+            self.node_answer,
+            self.node_time,
+            self.speed_ratio()
+        );
+        if self.matches() {
+            println!("{}Part {}: answers match{}", GREEN, self.part, RESET);
+        } else {
+            println!(
+                "{}Part {}: MISMATCH host={} node={}{}",
+                RED, self.part, self.host_answer, self.node_answer, RESET
+            );
+            if self.looks_like_32bit_overflow() {
+                println!(
+                    "{}Node answer is the host answer truncated to 32 bits - check for usize/i32 overflow{}",
+                    RED, RESET
+                );
+            }
+        }
+    }
+}
+
+/// Runs the requested parts (bit 1 = A, bit 2 = B, same as `main`) on the host and then on the
+/// configured node, returning one `CrossCheck` per part.
+pub async fn cross_check<S: DaySolver>(
+    day_solver: &S,
+    part: u8,
+    lines: &Vec<String>,
+    config: &CommsConfig,
+) -> CommsResult<Vec<CrossCheck>> {
+    let mut checks = Vec::new();
+    for (mask, part_char) in [(1, 'A'), (2, 'B')] {
+        if part & mask == 0 {
+            continue;
+        }
+        let start_time = Instant::now();
+        let host_answer = if part_char == 'A' {
+            day_solver.solve_a(lines).await.map_err(CommsError::Io)?
+        } else {
+            day_solver.solve_b(lines).await.map_err(CommsError::Io)?
+        };
+        let host_time = start_time.elapsed();
+
+        let start_time = Instant::now();
+        let node_answer = solve_on_node(
+            config,
+            day_solver.get_year(),
+            day_solver.get_day(),
+            part_char,
+            lines,
+        )
+        .await?;
+        let node_time = start_time.elapsed();
+
+        checks.push(CrossCheck {
+            part: part_char,
+            host_answer,
+            node_answer,
+            host_time,
+            node_time,
+        });
+    }
+    Ok(checks)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::comms::host_node::serve_uploads;
+    use aoc_solvers::aoc2024::day22;
+    use tokio::net::TcpListener;
+
+    fn lines() -> Vec<String> {
+        ["1", "10", "100", "2024"].map(String::from).to_vec()
+    }
+
+    /// A host node on a free local port, and a config that points at it.
+    async fn node_config() -> CommsConfig {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = listener.local_addr().unwrap().to_string();
+        tokio::spawn(serve_uploads(listener, None));
+        CommsConfig {
+            address: Some(address),
+            ..CommsConfig::default()
+        }
+    }
+
+    /// Day 22 with 2^32 added to every answer, so the node's correct answer looks like the
+    /// host's truncated to 32 bits.
+    struct Widened;
+
+    impl Widened {
+        fn widen(answer: std::io::Result<String>) -> std::io::Result<String> {
+            let answer: u64 = answer?.parse().unwrap();
+            Ok((answer + (1 << 32)).to_string())
+        }
+    }
+
+    impl DaySolver for Widened {
+        async fn solve_a(&self, lines: &Vec<String>) -> std::io::Result<String> {
+            Self::widen(day22::Day.solve_a(lines).await)
+        }
+
+        async fn solve_b(&self, lines: &Vec<String>) -> std::io::Result<String> {
+            Self::widen(day22::Day.solve_b(lines).await)
+        }
+
+        fn get_day(&self) -> u8 {
+            22
+        }
+
+        fn get_year(&self) -> u16 {
+            2024
+        }
+    }
+
+    #[tokio::test]
+    async fn host_and_node_agree() {
+        let config = node_config().await;
+        let checks = cross_check(&day22::Day, 3, &lines(), &config)
+            .await
+            .unwrap();
+        let parts: Vec<char> = checks.iter().map(|check| check.part).collect();
+        assert_eq!(parts, ['A', 'B']);
+        for check in &checks {
+            assert!(check.matches(), "{:?}", check);
+            assert!(!check.looks_like_32bit_overflow());
+        }
+    }
+
+    #[tokio::test]
+    async fn mismatch_is_reported() {
+        let config = node_config().await;
+        let checks = cross_check(&Widened, 2, &lines(), &config).await.unwrap();
+        assert_eq!(checks.len(), 1);
+        let check = &checks[0];
+        assert_eq!(check.part, 'B');
+        assert!(!check.matches());
+        assert!(check.looks_like_32bit_overflow(), "{:?}", check);
+    }
+}
@@ -0,0 +1,75 @@
+use std::fs::{File, OpenOptions};
+use std::io::{self, BufWriter, Write};
+use std::path::Path;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use pico_protocol::Progress;
+
+/// Shows what the node says while it works: log lines scroll, progress stays on one status line.
+/// Both can also be appended to a file.
+#[derive(Default)]
+pub struct DeviceConsole {
+    file: Option<BufWriter<File>>,
+    status_shown: bool,
+    /// Log lines received so far.
+    pub logged: u32,
+    /// `(done, total)` from the latest progress frame.
+    pub last_progress: Option<(u32, u32)>,
+}
+
+impl DeviceConsole {
+    /// Also appends everything to `path`, one timestamped line per frame.
+    pub fn save_to(&mut self, path: &Path) -> io::Result<()> {
+        let file = OpenOptions::new().create(true).append(true).open(path)?;
+        self.file = Some(BufWriter::new(file));
+        Ok(())
+    }
+
+    pub fn log(&mut self, text: &str) {
+        self.end_status();
+        for line in text.lines() {
+            println!("[node] {}", line);
+        }
+        self.logged += 1;
+        self.save(text);
+    }
+
+    pub fn progress(&mut self, progress: &Progress) {
+        print!(
+            "\r\x1b[2K[node] {} {:.0}% ({}/{})",
+            progress.stage,
+            progress.percent(),
+            progress.done,
+            progress.total
+        );
+        let _ = io::stdout().flush();
+        self.status_shown = true;
+        self.save(&format!(
+            "progress {} {}/{}",
+            progress.stage, progress.done, progress.total
+        ));
+        self.last_progress = Some((progress.done, progress.total));
+    }
+
+    /// Moves off the status line so normal output doesn't land on the end of it.
+    pub fn end_status(&mut self) {
+        if self.status_shown {
+            println!();
+            self.status_shown = false;
+        }
+    }
+
+    fn save(&mut self, text: &str) {
+        let Some(file) = &mut self.file else {
+            return;
+        };
+        let t_ms = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |t| t.as_millis());
+        let written = writeln!(file, "{} {}", t_ms, text).and_then(|_| file.flush());
+        if let Err(e) = written {
+            eprintln!("Couldn't save device log: {}", e);
+            self.file = None;
+        }
+    }
+}
@@ -0,0 +1,170 @@
+use std::net::SocketAddr;
+
+use serde::Deserialize;
+use tokio::io;
+use tokio::net::UdpSocket;
+use tokio::time::{Duration, Instant, timeout_at};
+
+use crate::comms::config::CommsConfig;
+
+pub const DISCOVERY_PORT: u16 = 1235;
+pub const DISCOVER_REQUEST: &str = "AOC?DISCOVER v1";
+const ADVERT_PREFIX: &str = "AOC!NODE v1";
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct DiscoveryOptions {
+    /// Where the request is sent; a single address (e.g. `127.0.0.1:1235`) also works.
+    pub broadcast: String,
+    /// How long to collect replies for.
+    pub timeout_ms: u64,
+}
+
+impl Default for DiscoveryOptions {
+    fn default() -> Self {
+        DiscoveryOptions {
+            broadcast: format!("255.255.255.255:{}", DISCOVERY_PORT),
+            timeout_ms: 1000,
+        }
+    }
+}
+
+/// What a node says about itself in reply to a discovery request:
+/// `AOC!NODE v1;port=1234;fw=0.3.1;days=2025-01,2025-02`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NodeAdvert {
+    pub port: u16,
+    pub firmware: String,
+    pub days: Vec<(u16, u8)>,
+}
+
+impl NodeAdvert {
+    pub fn encode(&self) -> String {
+        let days: Vec<String> = self
+            .days
+            .iter()
+            .map(|(year, day)| format!("{}-{:02}", year, day))
+            .collect();
+        format!(
+            "{};port={};fw={};days={}",
+            ADVERT_PREFIX,
+            self.port,
+            self.firmware,
+            days.join(",")
+        )
+    }
+
+    pub fn parse(message: &str) -> Option<Self> {
+        let mut fields = message.trim().split(';');
+        if fields.next()? != ADVERT_PREFIX {
+            return None;
+        }
+        let mut advert = NodeAdvert {
+            port: 0,
+            firmware: String::new(),
+            days: Vec::new(),
+        };
+        for field in fields {
+            let (key, value) = field.split_once('=')?;
+            match key {
+                "port" => advert.port = value.parse().ok()?,
+                "fw" => advert.firmware = value.to_string(),
+                "days" => {
+                    for entry in value.split(',').filter(|e| !e.is_empty()) {
+                        let (year, day) = entry.split_once('-')?;
+                        advert.days.push((year.parse().ok()?, day.parse().ok()?));
+                    }
+                }
+                // Newer firmware may add fields; ignore the ones we don't know.
+                _ => {}
+            }
+        }
+        (advert.port != 0).then_some(advert)
+    }
+}
+
+/// A node found on the LAN: where to connect and what it can solve.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NodeInfo {
+    pub address: SocketAddr,
+    pub firmware: String,
+    pub days: Vec<(u16, u8)>,
+}
+
+impl NodeInfo {
+    pub fn supports(&self, year: u16, day: u8) -> bool {
+        self.days.contains(&(year, day))
+    }
+}
+
+/// Broadcasts a discovery request and collects every node that answers before the timeout.
+/// The solver address is the IP the reply came from plus the port the node advertised.
+pub async fn discover_nodes(options: &DiscoveryOptions) -> io::Result<Vec<NodeInfo>> {
+    let socket = UdpSocket::bind("0.0.0.0:0").await?;
+    socket.set_broadcast(true)?;
+    socket
+        .send_to(DISCOVER_REQUEST.as_bytes(), &options.broadcast)
+        .await?;
+
+    let deadline = Instant::now() + Duration::from_millis(options.timeout_ms);
+    let mut nodes: Vec<NodeInfo> = Vec::new();
+    let mut buffer = [0u8; 1024];
+    while let Ok(received) = timeout_at(deadline, socket.recv_from(&mut buffer)).await {
+        // One bad datagram (on Windows, even an ICMP port-unreachable from some other host)
+        // shouldn't throw away the nodes that did answer, or the ones still to come.
+        let (n, from) = match received {
+            Ok(received) => received,
+            Err(e) => {
+                eprintln!("Discovery receive failed: {}", e);
+                continue;
+            }
+        };
+        let Some(advert) = NodeAdvert::parse(&String::from_utf8_lossy(&buffer[..n])) else {
+            continue;
+        };
+        let address = SocketAddr::new(from.ip(), advert.port);
+        if nodes.iter().all(|node| node.address != address) {
+            nodes.push(NodeInfo {
+                address,
+                firmware: advert.firmware,
+                days: advert.days,
+            });
+        }
+    }
+    Ok(nodes)
+}
+
+/// Fills in `config.address` from discovery when it isn't set, preferring a node that
+/// advertises the puzzle being run. Leaves it unset if nothing suitable answers.
+pub async fn resolve_node_address(config: &mut CommsConfig, year: u16, day: u8) {
+    if config.address.is_some() {
+        return;
+    }
+    let nodes = match discover_nodes(&config.discovery).await {
+        Ok(nodes) => nodes,
+        Err(e) => {
+            eprintln!("Discovery failed: {}", e);
+            return;
+        }
+    };
+    for node in &nodes {
+        println!(
+            "Found node {} (fw {}, {} days{})",
+            node.address,
+            node.firmware,
+            node.days.len(),
+            if node.supports(year, day) {
+                ""
+            } else {
+                ", not this one"
+            }
+        );
+    }
+    match nodes.iter().find(|node| node.supports(year, day)) {
+        Some(node) => {
+            println!("Using node {}", node.address);
+            config.address = Some(node.address.to_string());
+        }
+        None => eprintln!("No node on the LAN can solve {} day {}", year, day),
+    }
+}
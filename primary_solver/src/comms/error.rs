@@ -0,0 +1,111 @@
+use std::fmt;
+
+use tokio::io;
+
+/// Everything that can go wrong talking to a node, sorted by what the caller should do about it.
+#[derive(Debug)]
+pub enum CommsError {
+    /// Couldn't open a connection: refused, or no route while the node (or its Wi-Fi) is
+    /// rebooting.
+    Unreachable(io::Error),
+    /// The connection dropped part way through (reset, EOF, broken pipe).
+    Disconnected(io::Error),
+    /// Nothing arrived in time.
+    TimedOut(String),
+    /// The node sent something the protocol doesn't allow at that point.
+    ProtocolViolation(String),
+    /// The node reported an error of its own.
+    Device(String),
+    /// Frames kept failing their CRC; the count is how many in a row.
+    ChecksumFailure(u32),
+    /// The other side couldn't prove it has the shared secret, or a frame's tag was wrong.
+    AuthFailed(String),
+    /// Any other I/O error, e.g. from the host-side solver.
+    Io(io::Error),
+}
+
+impl CommsError {
+    /// Transient errors are worth reconnecting for; the rest will fail the same way again.
+    pub fn is_transient(&self) -> bool {
+        matches!(
+            self,
+            CommsError::Unreachable(_)
+                | CommsError::Disconnected(_)
+                | CommsError::TimedOut(_)
+                | CommsError::ChecksumFailure(_)
+        )
+    }
+}
+
+impl fmt::Display for CommsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CommsError::Unreachable(e) => write!(f, "node unreachable: {}", e),
+            CommsError::Disconnected(e) => write!(f, "connection lost: {}", e),
+            CommsError::TimedOut(what) => write!(f, "timed out {}", what),
+            CommsError::ProtocolViolation(what) => write!(f, "protocol violation: {}", what),
+            CommsError::Device(message) => write!(f, "node error: {}", message),
+            CommsError::ChecksumFailure(count) => {
+                write!(f, "{} corrupted frames in a row", count)
+            }
+            CommsError::AuthFailed(what) => write!(f, "authentication failed: {}", what),
+            CommsError::Io(e) => write!(f, "I/O error: {}", e),
+        }
+    }
+}
+
+impl std::error::Error for CommsError {}
+
+impl From<io::Error> for CommsError {
+    fn from(e: io::Error) -> Self {
+        match e.kind() {
+            io::ErrorKind::ConnectionRefused
+            | io::ErrorKind::HostUnreachable
+            | io::ErrorKind::NetworkUnreachable => CommsError::Unreachable(e),
+            io::ErrorKind::ConnectionReset
+            | io::ErrorKind::ConnectionAborted
+            | io::ErrorKind::BrokenPipe
+            | io::ErrorKind::UnexpectedEof => CommsError::Disconnected(e),
+            io::ErrorKind::TimedOut => CommsError::TimedOut(e.to_string()),
+            io::ErrorKind::InvalidData => CommsError::ProtocolViolation(e.to_string()),
+            // A bad address, no such port... trying again won't change those.
+            _ => CommsError::Io(e),
+        }
+    }
+}
+
+pub type CommsResult<T> = Result<T, CommsError>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn only_errors_that_can_clear_up_are_transient() {
+        let transient = [
+            io::ErrorKind::ConnectionRefused,
+            io::ErrorKind::HostUnreachable,
+            io::ErrorKind::NetworkUnreachable,
+            io::ErrorKind::ConnectionReset,
+            io::ErrorKind::TimedOut,
+        ];
+        for kind in transient {
+            assert!(
+                CommsError::from(io::Error::from(kind)).is_transient(),
+                "{kind:?}"
+            );
+        }
+        let fatal = [
+            io::ErrorKind::NotFound,
+            io::ErrorKind::PermissionDenied,
+            io::ErrorKind::InvalidInput,
+            io::ErrorKind::AddrNotAvailable,
+        ];
+        for kind in fatal {
+            assert!(
+                !CommsError::from(io::Error::from(kind)).is_transient(),
+                "{kind:?}"
+            );
+        }
+    }
+}
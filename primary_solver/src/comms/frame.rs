@@ -0,0 +1,115 @@
+use std::time::Duration;
+
+use tokio::io::{self, AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt};
+use tokio::time::timeout;
+
+pub use pico_protocol::{
+    CRC_LEN, Frame, FrameKind, HEADER_LEN, InputEncoding, MAX_PAYLOAD, PROTOCOL_VERSION, StartInfo,
+    payload_len,
+};
+
+pub async fn write_frame<W: AsyncWrite + Unpin>(stream: &mut W, frame: &Frame) -> io::Result<()> {
+    stream.write_all(&frame.encode()).await?;
+    stream.flush().await
+}
+
+/// How long the link has to stay quiet before we take it that the other side has sent all it's
+/// going to and is waiting on us.
+const QUIET: Duration = Duration::from_millis(250);
+
+/// Reads one frame. `Ok(None)` means a frame arrived but was damaged, so the caller should ask
+/// for it again.
+///
+/// There's no sync byte on the wire, so a damaged length can't be told apart from a good one
+/// until the CRC fails, and by then we may have read into the next frame or still be waiting
+/// for bytes that were never sent. So whenever something doesn't check out, the rest of what's
+/// in flight is drained until the other side goes quiet, leaving the next frame it sends (the
+/// one it resends for our NAK) at the start of the stream.
+pub async fn read_frame<R: AsyncRead + Unpin>(stream: &mut R) -> io::Result<Option<Frame>> {
+    let mut header = [0u8; HEADER_LEN];
+    stream.read_exact(&mut header).await?;
+    let len = match payload_len(&header) {
+        Ok(len) if FrameKind::from_u8(header[0]).is_some() => len,
+        _ => return drain(stream).await.map(|()| None),
+    };
+    let mut bytes = vec![0u8; HEADER_LEN + len + CRC_LEN];
+    bytes[..HEADER_LEN].copy_from_slice(&header);
+    // A length bumped up past the real frame would otherwise have us wait for bytes that
+    // are never coming.
+    if !read_until_quiet(stream, &mut bytes[HEADER_LEN..]).await? {
+        return Ok(None);
+    }
+    match Frame::decode(&bytes) {
+        Some(frame) => Ok(Some(frame)),
+        None => drain(stream).await.map(|()| None),
+    }
+}
+
+/// Fills `buf`, or returns `false` if the stream goes quiet first.
+async fn read_until_quiet<R: AsyncRead + Unpin>(
+    stream: &mut R,
+    buf: &mut [u8],
+) -> io::Result<bool> {
+    let mut filled = 0;
+    while filled < buf.len() {
+        match timeout(QUIET, stream.read(&mut buf[filled..])).await {
+            Ok(Ok(0)) => return Err(io::ErrorKind::UnexpectedEof.into()),
+            Ok(Ok(n)) => filled += n,
+            Ok(Err(e)) => return Err(e),
+            Err(_) => return Ok(false),
+        }
+    }
+    Ok(true)
+}
+
+/// Throws away whatever arrives until the stream goes quiet (or ends, which the next read will
+/// report).
+async fn drain<R: AsyncRead + Unpin>(stream: &mut R) -> io::Result<()> {
+    let mut scratch = [0u8; 1024];
+    loop {
+        match timeout(QUIET, stream.read(&mut scratch)).await {
+            Ok(Ok(0)) | Err(_) => return Ok(()),
+            Ok(Ok(_)) => {}
+            Ok(Err(e)) => return Err(e),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn corrupted_frame_fails_crc() {
+        let frame = Frame::new(FrameKind::Chunk, 3, b"1,2,3\n4,5,6".to_vec());
+        let mut bytes = frame.encode();
+        assert_eq!(
+            read_frame(&mut bytes.as_slice()).await.unwrap(),
+            Some(frame)
+        );
+        bytes[HEADER_LEN + 2] ^= 0x01;
+        assert_eq!(read_frame(&mut bytes.as_slice()).await.unwrap(), None);
+    }
+
+    /// Flips a bit of the length and checks we come back in step for the resent frame, whether
+    /// the bad length ran past the frame, stopped short of it, or was too big to be a frame.
+    #[tokio::test]
+    async fn resyncs_after_a_corrupted_length() {
+        let frame = Frame::new(FrameKind::Chunk, 3, vec![7; 300]);
+        // The length is the little-endian u32 at bytes 5..9, here 300 = 0x12c.
+        for (byte, bit, what) in [(6, 0x01, "short"), (6, 0x80, "long"), (8, 0x40, "huge")] {
+            let mut damaged = frame.encode();
+            damaged[byte] ^= bit;
+
+            let (mut ours, mut theirs) = io::duplex(4096);
+            theirs.write_all(&damaged).await.unwrap();
+            assert_eq!(read_frame(&mut ours).await.unwrap(), None, "{what}");
+            write_frame(&mut theirs, &frame).await.unwrap();
+            assert_eq!(
+                read_frame(&mut ours).await.unwrap(),
+                Some(frame.clone()),
+                "{what}"
+            );
+        }
+    }
+}
@@ -0,0 +1,471 @@
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use pico_protocol::Progress;
+use pico_protocol::input::EncodedInput;
+use tokio::io::{self, AsyncRead, AsyncWrite};
+use tokio::net::{TcpListener, UdpSocket};
+
+use crate::comms::auth::{AuthSession, authenticate_node};
+use crate::comms::discovery::{DISCOVER_REQUEST, DISCOVERY_PORT, NodeAdvert};
+use crate::comms::error::{CommsError, CommsResult};
+use crate::comms::frame::{
+    Frame, FrameKind, InputEncoding, MAX_PAYLOAD, PROTOCOL_VERSION, StartInfo, read_frame,
+    write_frame,
+};
+use aoc_solvers::ByteLines;
+use aoc_solvers::solver::{SOLVABLE_DAYS, solve_puzzle, solve_puzzle_bytes};
+
+/// Port a host node advertises for puzzle uploads, same as the Pico.
+pub const HOST_NODE_PORT: u16 = 1234;
+
+/// The most input a node will take in one upload. Real puzzle inputs are tens of kilobytes.
+pub const MAX_INPUT_LEN: usize = 8 * 1024 * 1024;
+
+/// How many cut-off transfers are kept for resuming, and for how long. A host that gives up
+/// never comes back for its transfer, so without a limit they'd pile up for as long as the
+/// node runs.
+const MAX_PARTIAL_TRANSFERS: usize = 4;
+const PARTIAL_TRANSFER_TTL: Duration = Duration::from_secs(10 * 60);
+
+/// What this machine advertises when it stands in for a Pico.
+pub fn host_advert(port: u16) -> NodeAdvert {
+    NodeAdvert {
+        port,
+        firmware: format!("host-{}", env!("CARGO_PKG_VERSION")),
+        days: SOLVABLE_DAYS.to_vec(),
+    }
+}
+
+/// Answers discovery requests on `socket` the way a Pico node does, forever.
+pub async fn answer_discovery(socket: UdpSocket, advert: NodeAdvert) -> io::Result<()> {
+    let reply = advert.encode();
+    let mut buffer = [0u8; 256];
+    loop {
+        let (n, from) = socket.recv_from(&mut buffer).await?;
+        if buffer[..n].trim_ascii() == DISCOVER_REQUEST.as_bytes() {
+            socket.send_to(reply.as_bytes(), from).await?;
+        }
+    }
+}
+
+/// Transfers that were cut off part way, kept so the host can resume them on a new connection.
+/// Each one remembers when it expires.
+#[derive(Default)]
+pub struct NodeState {
+    transfers: HashMap<u32, (Instant, PartialTransfer)>,
+}
+
+struct PartialTransfer {
+    data: Vec<u8>,
+    next_seq: u32,
+}
+
+impl NodeState {
+    /// Keeps `transfer` for resuming, first dropping the expired ones and, if that still
+    /// leaves too many, the oldest.
+    fn keep(&mut self, id: u32, transfer: PartialTransfer) {
+        let now = Instant::now();
+        self.transfers.retain(|_, (expires, _)| now < *expires);
+        while self.transfers.len() >= MAX_PARTIAL_TRANSFERS {
+            let oldest = self
+                .transfers
+                .iter()
+                .min_by_key(|(_, (expires, _))| *expires)
+                .map(|(&id, _)| id);
+            self.transfers.remove(&oldest.unwrap());
+        }
+        self.transfers
+            .insert(id, (now + PARTIAL_TRANSFER_TTL, transfer));
+    }
+
+    /// Takes back a kept transfer, unless it has expired.
+    fn resume(&mut self, id: u32) -> Option<PartialTransfer> {
+        let (expires, transfer) = self.transfers.remove(&id)?;
+        (Instant::now() < expires).then_some(transfer)
+    }
+}
+
+/// The node's end of one connection. Remembers the last frame for Naks, and once the host has
+/// authenticated, seals everything sent and checks everything received.
+struct NodeLink<'a, S> {
+    stream: &'a mut S,
+    last_sent: Option<Frame>,
+    auth: Option<AuthSession>,
+}
+
+impl<S: AsyncRead + AsyncWrite + Unpin> NodeLink<'_, S> {
+    async fn send(&mut self, frame: Frame) -> CommsResult<()> {
+        match &mut self.auth {
+            Some(auth) => write_frame(self.stream, &auth.seal(&frame)).await?,
+            None => write_frame(self.stream, &frame).await?,
+        }
+        self.last_sent = Some(frame);
+        Ok(())
+    }
+
+    /// Sends the last frame again, sealed afresh so it doesn't look like a replay.
+    async fn resend(&mut self) -> CommsResult<()> {
+        match self.last_sent.take() {
+            Some(frame) => self.send(frame).await,
+            None => Ok(()),
+        }
+    }
+
+    async fn read(&mut self) -> CommsResult<Option<Frame>> {
+        match (read_frame(self.stream).await?, &mut self.auth) {
+            (Some(frame), Some(auth)) => auth.open(frame).map(Some),
+            (frame, _) => Ok(frame),
+        }
+    }
+
+    /// Tells the host why it is being hung up on.
+    async fn refuse(&mut self, message: &str) -> CommsResult<()> {
+        self.send(Frame::new(FrameKind::Error, 0, message.as_bytes().to_vec()))
+            .await?;
+        Err(CommsError::AuthFailed(message.to_string()))
+    }
+}
+
+/// Node side of one upload: the same framing, acks, Naks and resume rules the Pico firmware
+/// follows, with the puzzle solved by the host solvers. With a `key`, the host has to
+/// authenticate before anything else.
+pub async fn serve_session<S: AsyncRead + AsyncWrite + Unpin>(
+    stream: &mut S,
+    state: &Mutex<NodeState>,
+    key: Option<&[u8]>,
+) -> CommsResult<()> {
+    let mut link = NodeLink {
+        stream,
+        last_sent: None,
+        auth: None,
+    };
+    let info = loop {
+        match link.read().await? {
+            None => link.send(Frame::empty(FrameKind::Nak, 0)).await?,
+            Some(frame) if frame.kind == FrameKind::Hello && link.auth.is_none() => match key {
+                Some(key) => link.auth = Some(authenticate_node(link.stream, key, &frame).await?),
+                None => return link.refuse("This node has no shared secret").await,
+            },
+            Some(frame) if frame.kind == FrameKind::Start => {
+                if key.is_some() && link.auth.is_none() {
+                    return link.refuse("This node needs authentication").await;
+                }
+                break StartInfo::decode(&frame.payload).ok_or_else(|| {
+                    CommsError::ProtocolViolation("Malformed Start frame".to_string())
+                })?;
+            }
+            Some(frame) => {
+                return Err(CommsError::ProtocolViolation(format!(
+                    "Expected Start, got {:?}",
+                    frame.kind
+                )));
+            }
+        }
+    };
+    if !info.is_current_version() {
+        let message = format!(
+            "Protocol version {} (this node speaks {})",
+            info.version, PROTOCOL_VERSION
+        );
+        return link
+            .send(Frame::new(FrameKind::Error, 0, message.into_bytes()))
+            .await;
+    }
+    if !SOLVABLE_DAYS.contains(&(info.year, info.day)) {
+        let message = format!("No solver for {} day {}", info.year, info.day);
+        return link
+            .send(Frame::new(FrameKind::Error, 0, message.into_bytes()))
+            .await;
+    }
+
+    // The Start frame's sizes are only the peer's word, so check them before reserving space.
+    let total_len = info.total_len as usize;
+    if total_len > MAX_INPUT_LEN
+        || total_len > (info.chunk_count as usize).saturating_mul(MAX_PAYLOAD)
+    {
+        let message = format!(
+            "Input of {} bytes in {} chunks is over the {} byte limit or doesn't fit",
+            info.total_len, info.chunk_count, MAX_INPUT_LEN
+        );
+        return link
+            .send(Frame::new(FrameKind::Error, 0, message.into_bytes()))
+            .await;
+    }
+
+    let resumed = state.lock().unwrap().resume(info.transfer_id);
+    let mut transfer = resumed.unwrap_or(PartialTransfer {
+        data: Vec::with_capacity(info.total_len as usize),
+        next_seq: 0,
+    });
+    let received = async {
+        link.send(Frame::empty(FrameKind::Ready, transfer.next_seq))
+            .await?;
+        while transfer.next_seq < info.chunk_count {
+            match link.read().await? {
+                None => {
+                    let nak = Frame::empty(FrameKind::Nak, transfer.next_seq);
+                    link.send(nak).await?;
+                }
+                Some(frame) => match frame.kind {
+                    FrameKind::Nak => link.resend().await?,
+                    FrameKind::Chunk if frame.seq == transfer.next_seq => {
+                        if transfer.data.len() + frame.payload.len() > total_len {
+                            return Err(CommsError::ProtocolViolation(format!(
+                                "Chunks add up to more than the {} bytes announced",
+                                total_len
+                            )));
+                        }
+                        transfer.data.extend_from_slice(&frame.payload);
+                        transfer.next_seq += 1;
+                        link.send(Frame::empty(FrameKind::Ack, frame.seq)).await?;
+                    }
+                    // Anything after a chunk we Nak'd is dropped until the host goes back.
+                    FrameKind::Chunk => {}
+                    other => {
+                        return Err(CommsError::ProtocolViolation(format!(
+                            "Unexpected {:?} during upload",
+                            other
+                        )));
+                    }
+                },
+            }
+        }
+        Ok(())
+    }
+    .await;
+    if let Err(e) = received {
+        state.lock().unwrap().keep(info.transfer_id, transfer);
+        return Err(e);
+    }
+
+    let started = format!(
+        "Solving {} day {} part {} on {}",
+        info.year,
+        info.day,
+        info.part,
+        host_advert(0).firmware
+    );
+    link.send(Frame::new(FrameKind::Log, 0, started.into_bytes()))
+        .await?;
+    let progress = |done| {
+        let progress = Progress {
+            done,
+            total: 1,
+            stage: "solving",
+        };
+        Frame::new(FrameKind::Progress, 0, progress.encode())
+    };
+    link.send(progress(0)).await?;
+    // Text is solved straight from the received bytes; pre-parsed input has to become lines.
+    let solved = match info.encoding {
+        InputEncoding::Text => match ByteLines::new(&transfer.data) {
+            Ok(input) => solve_puzzle_bytes(info.year, info.day, info.part, &input).await,
+            Err(e) => Some(Err(e)),
+        },
+        InputEncoding::Binary => match decoded_lines(&info, &transfer.data) {
+            Ok(lines) => solve_puzzle(info.year, info.day, info.part, &lines).await,
+            Err(message) => Some(Err(io::Error::other(message))),
+        },
+    };
+    let reply = match solved {
+        Some(Ok(answer)) => Frame::new(FrameKind::Result, 0, answer.into_bytes()),
+        Some(Err(e)) => Frame::new(FrameKind::Error, 0, e.to_string().into_bytes()),
+        None => Frame::new(FrameKind::Error, 0, b"No solver".to_vec()),
+    };
+    link.send(progress(1)).await?;
+    link.send(reply).await?;
+
+    // The host Naks the result if it arrived corrupted, otherwise it just hangs up.
+    while let Ok(frame) = link.read().await {
+        if frame.is_none_or(|frame| frame.kind == FrameKind::Nak) {
+            link.resend().await?;
+        }
+    }
+    Ok(())
+}
+
+/// Decodes pre-parsed input back into the lines the solver expects.
+fn decoded_lines(info: &StartInfo, data: &[u8]) -> Result<Vec<String>, String> {
+    let input = EncodedInput::decode(data).map_err(|e| e.to_string())?;
+    if (input.year, input.day) != (info.year, info.day) {
+        return Err(format!(
+            "Binary input is for {} day {}, not {} day {}",
+            input.year, input.day, info.year, info.day
+        ));
+    }
+    Ok(input.to_lines())
+}
+
+/// Accepts uploads on `listener` until it fails, one task per connection. Hosts have to
+/// authenticate with `key` if it is set.
+pub async fn serve_uploads(listener: TcpListener, key: Option<Vec<u8>>) -> io::Result<()> {
+    let state = Arc::new(Mutex::new(NodeState::default()));
+    loop {
+        let (mut stream, from) = listener.accept().await?;
+        let state = state.clone();
+        let key = key.clone();
+        tokio::spawn(async move {
+            if let Err(e) = serve_session(&mut stream, &state, key.as_deref()).await {
+                eprintln!("Session with {} ended: {}", from, e);
+            }
+        });
+    }
+}
+
+/// Runs this machine as a node so the host-side comms can be exercised without a Pico.
+/// Several can run at once on different ports; only the first gets the discovery port.
+pub async fn run_host_node(port: u16, key: Option<Vec<u8>>) -> io::Result<()> {
+    match UdpSocket::bind(("0.0.0.0", DISCOVERY_PORT)).await {
+        Ok(socket) => {
+            println!("Answering discovery on UDP {}", DISCOVERY_PORT);
+            tokio::spawn(answer_discovery(socket, host_advert(port)));
+        }
+        Err(e) => eprintln!("Not answering discovery: {}", e),
+    }
+    let listener = TcpListener::bind(("0.0.0.0", port)).await?;
+    match key {
+        Some(_) => println!("Solving authenticated uploads on TCP {}", port),
+        None => println!("Solving uploads on TCP {}", port),
+    }
+    serve_uploads(listener, key).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::comms::chunked::{ChunkOptions, ChunkedUpload};
+    use crate::comms::discovery::{DiscoveryOptions, discover_nodes};
+
+    #[test]
+    fn advert_round_trips() {
+        let advert = host_advert(4321);
+        assert_eq!(NodeAdvert::parse(&advert.encode()), Some(advert));
+        assert_eq!(NodeAdvert::parse("AOC!NODE v1;fw=1.0"), None);
+        assert_eq!(NodeAdvert::parse("hello"), None);
+    }
+
+    #[tokio::test]
+    async fn discovers_the_host_responder() {
+        let socket = UdpSocket::bind("127.0.0.1:0").await.unwrap();
+        let port = socket.local_addr().unwrap().port();
+        tokio::spawn(answer_discovery(socket, host_advert(4321)));
+
+        let options = DiscoveryOptions {
+            broadcast: format!("127.0.0.1:{}", port),
+            timeout_ms: 300,
+        };
+        let nodes = discover_nodes(&options).await.unwrap();
+        assert_eq!(nodes.len(), 1);
+        assert_eq!(nodes[0].address, "127.0.0.1:4321".parse().unwrap());
+        assert!(nodes[0].supports(2025, 7));
+        assert!(!nodes[0].supports(2024, 8));
+    }
+
+    #[tokio::test]
+    async fn pre_parsed_upload_gets_the_text_answer() {
+        let lines: Vec<String> = ["1", "2", "3", "2024"].map(String::from).to_vec();
+        let input = EncodedInput::parse(2024, 22, &lines).unwrap();
+        let state = Mutex::new(NodeState::default());
+        for mut upload in [
+            ChunkedUpload::new(2024, 22, 'B', &lines, ChunkOptions::default()),
+            ChunkedUpload::binary(&input, 'B', ChunkOptions::default()),
+        ] {
+            let (mut host, mut device) = tokio::io::duplex(256);
+            let (answer, _) = tokio::join!(
+                async {
+                    let answer = upload.run(&mut host).await;
+                    drop(host);
+                    answer
+                },
+                serve_session(&mut device, &state, None)
+            );
+            assert_eq!(answer.unwrap(), "23");
+        }
+    }
+
+    #[test]
+    fn partial_transfers_are_capped_and_expire() {
+        let partial = |next_seq| PartialTransfer {
+            data: Vec::new(),
+            next_seq,
+        };
+        let mut state = NodeState::default();
+        for id in 0..MAX_PARTIAL_TRANSFERS as u32 + 1 {
+            state.keep(id, partial(id));
+        }
+        assert_eq!(state.transfers.len(), MAX_PARTIAL_TRANSFERS);
+        assert!(state.resume(0).is_none());
+        assert_eq!(state.resume(1).unwrap().next_seq, 1);
+
+        state.transfers.get_mut(&2).unwrap().0 = Instant::now();
+        assert!(state.resume(2).is_none());
+        state.transfers.get_mut(&3).unwrap().0 = Instant::now();
+        state.keep(9, partial(9));
+        assert_eq!(state.transfers.len(), 2);
+    }
+
+    #[tokio::test]
+    async fn oversized_start_gets_an_error() {
+        let state = Mutex::new(NodeState::default());
+        for (total_len, chunk_count) in [(u32::MAX, u32::MAX), (70_000, 1)] {
+            let start = StartInfo {
+                version: PROTOCOL_VERSION,
+                year: 2024,
+                day: 22,
+                part: 'A',
+                transfer_id: 7,
+                total_len,
+                chunk_count,
+                window: 4,
+                encoding: InputEncoding::Text,
+            };
+            let (mut host, mut device) = tokio::io::duplex(256);
+            let frame = Frame::new(FrameKind::Start, 0, start.encode().to_vec());
+            write_frame(&mut host, &frame).await.unwrap();
+            serve_session(&mut device, &state, None).await.unwrap();
+            let reply = read_frame(&mut host).await.unwrap().unwrap();
+            assert_eq!(reply.kind, FrameKind::Error);
+            assert!(String::from_utf8_lossy(&reply.payload).contains("byte limit"));
+        }
+    }
+
+    #[tokio::test]
+    async fn non_utf8_text_gets_an_error() {
+        let data = b"1\n\xff\n".to_vec();
+        let start = StartInfo {
+            version: PROTOCOL_VERSION,
+            year: 2024,
+            day: 22,
+            part: 'A',
+            transfer_id: 8,
+            total_len: data.len() as u32,
+            chunk_count: 1,
+            window: 4,
+            encoding: InputEncoding::Text,
+        };
+        let state = Mutex::new(NodeState::default());
+        let (mut host, mut device) = tokio::io::duplex(4096);
+        let (reply, served) = tokio::join!(
+            async {
+                let start = Frame::new(FrameKind::Start, 0, start.encode().to_vec());
+                write_frame(&mut host, &start).await.unwrap();
+                write_frame(&mut host, &Frame::new(FrameKind::Chunk, 0, data))
+                    .await
+                    .unwrap();
+                loop {
+                    let frame = read_frame(&mut host).await.unwrap().unwrap();
+                    if matches!(frame.kind, FrameKind::Result | FrameKind::Error) {
+                        drop(host);
+                        break frame;
+                    }
+                }
+            },
+            serve_session(&mut device, &state, None)
+        );
+        served.unwrap();
+        assert_eq!(reply.kind, FrameKind::Error);
+        assert!(String::from_utf8_lossy(&reply.payload).contains("UTF-8"));
+    }
+}
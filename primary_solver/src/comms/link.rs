@@ -0,0 +1,34 @@
+use std::fmt;
+
+/// Frame and error counters for one upload, kept across reconnects.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct LinkStats {
+    pub frames_sent: u32,
+    pub frames_received: u32,
+    /// Frames from the node that failed their CRC.
+    pub crc_errors: u32,
+    /// Naks from the node, i.e. frames of ours that arrived corrupted.
+    pub naks_received: u32,
+    /// Frames we sent more than once.
+    pub retransmits: u32,
+}
+
+impl LinkStats {
+    pub fn is_clean(&self) -> bool {
+        self.crc_errors == 0 && self.naks_received == 0 && self.retransmits == 0
+    }
+}
+
+impl fmt::Display for LinkStats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} frames sent, {} received, {} CRC errors, {} naks from node, {} retransmits",
+            self.frames_sent,
+            self.frames_received,
+            self.crc_errors,
+            self.naks_received,
+            self.retransmits
+        )
+    }
+}
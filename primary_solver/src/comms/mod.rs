@@ -1 +1,15 @@
-pub mod pico_sender;
\ No newline at end of file
+pub mod auth;
+pub mod chunked;
+pub mod config;
+pub mod coordinator;
+pub mod cross_check;
+pub mod device_log;
+pub mod discovery;
+pub mod error;
+pub mod frame;
+pub mod host_node;
+pub mod link;
+pub mod pico_sender;
+pub mod retry;
+pub mod transcript;
+pub mod transport;
\ No newline at end of file
@@ -1,71 +1,89 @@
-use tokio::io::{self, AsyncReadExt, AsyncWriteExt};
+use tokio::io::{AsyncRead, AsyncReadExt, AsyncWriteExt};
 use tokio::net::TcpStream;
-use tokio::time::timeout;
+use tokio::time::{sleep, timeout, Duration};
 
-#[allow(dead_code)]
-pub async fn send_data_to_pico(lines: &Vec<String>) -> io::Result<()> {
-    let host = "10.0.0.126";
-    let port = 1234;
-    let address = format!("{}:{}", host, port);
-    // println!(
-    //     "Connecting to {}:{} to send {} lines",
-    //     host,
-    //     port,
-    //     lines.len()
-    // );
+use crate::comms::chunked::upload_chunked;
+use crate::comms::config::CommsConfig;
+use crate::comms::error::{CommsError, CommsResult};
+use crate::comms::transport::{TcpTransport, Transport};
 
-    let mut stream = match timeout(Duration::from_secs(5), TcpStream::connect(&address)).await {
-        Ok(Ok(stream)) => {
-            // println!("Successfully connected to the server!");
-            stream
-        }
-        Ok(Err(e)) => {
-            eprintln!("Connection failed: {}", e);
-            return Err(e);
-        }
-        Err(_) => {
-            eprintln!("Connection timed out.");
-            return Err(io::Error::new(
-                io::ErrorKind::TimedOut,
-                "Connection timed out",
-            ));
-        }
-    };
+pub const PICO_ADDRESS: &str = "10.0.0.126:1234";
 
-    use tokio::time::{sleep, Duration};
+/// One-shot `LEN:` upload for firmware that predates the framed protocol, to the configured (or
+/// discovered) node. Nothing here is checksummed; prefer `solve_on_node`/`upload_chunked`.
+pub async fn send_data_to_pico(config: &CommsConfig, lines: &[String]) -> CommsResult<()> {
+    send_data_via(&TcpTransport::new(config.node_address()), lines).await
+}
 
-    async fn read_ack() {
-        sleep(Duration::from_millis(80)).await;
-    }
+/// `send_data_to_pico` over any transport, e.g. the Pico's USB serial port.
+pub async fn send_data_via<T: Transport>(transport: &T, lines: &[String]) -> CommsResult<()> {
+    let mut stream = transport.open().await?;
 
     //combine lines into a single string
     let lines = lines.join("\n");
     let transmission_size = lines.len();
     // println!("Transmission size: {}", transmission_size);
     stream.write_all(format!("LEN:{}", transmission_size).as_bytes()).await?;
-        read_ack().await;
+    read_ack().await;
 
     //send the data
     stream.write_all(lines.as_bytes()).await?;
 
-    let mut buffer = [0; 4096];
     println!("Waiting for final response...");
-    let n = match stream.read(&mut buffer).await {
-        Ok(0) => {
-            eprintln!("Server closed the connection.");
-            return Err(io::Error::new(
-                io::ErrorKind::ConnectionReset,
-                "Server closed the connection",
-            ));
-        },
-        Ok(n) => n,
-        Err(e) => {
-            eprintln!("Failed to read data from the server: {}", e);
-            return Err(e);
-        }
-    };
-    println!("Received: {}", String::from_utf8_lossy(&buffer[..n]));
+    let reply = read_reply(&mut stream).await?;
+    println!("Received: {}", reply);
 
     // println!("Data sent successfully!");
     Ok(())
-}
\ No newline at end of file
+}
+
+/// Sends one puzzle part to the configured node over the framed (CRC-checked) protocol and
+/// returns the answer it replies with.
+pub async fn solve_on_node(
+    config: &CommsConfig,
+    year: u16,
+    day: u8,
+    part: char,
+    lines: &[String],
+) -> CommsResult<String> {
+    let reply = upload_chunked(config, year, day, part, lines).await?;
+    Ok(answer_from_reply(&reply))
+}
+
+/// Pulls the answer out of a node reply, which is either the bare answer or `Result: <answer>`.
+pub fn answer_from_reply(reply: &str) -> String {
+    let reply = reply.trim();
+    match reply.rfind("Result:") {
+        Some(pos) => reply[pos + "Result:".len()..].trim().to_string(),
+        None => reply.to_string(),
+    }
+}
+
+pub async fn connect(address: &str) -> CommsResult<TcpStream> {
+    // println!("Connecting to {}", address);
+    match timeout(Duration::from_secs(5), TcpStream::connect(address)).await {
+        Ok(Ok(stream)) => {
+            // println!("Successfully connected to the server!");
+            Ok(stream)
+        }
+        // Refused means the node isn't there (yet); a bad address won't get any better.
+        Ok(Err(e)) => Err(e.into()),
+        Err(_) => Err(CommsError::TimedOut(format!("connecting to {}", address))),
+    }
+}
+
+async fn read_ack() {
+    sleep(Duration::from_millis(80)).await;
+}
+
+async fn read_reply<S: AsyncRead + Unpin>(stream: &mut S) -> CommsResult<String> {
+    let mut buffer = [0; 4096];
+    let n = stream.read(&mut buffer).await?;
+    if n == 0 {
+        return Err(CommsError::Disconnected(std::io::Error::new(
+            std::io::ErrorKind::ConnectionReset,
+            "Server closed the connection",
+        )));
+    }
+    Ok(String::from_utf8_lossy(&buffer[..n]).to_string())
+}
@@ -0,0 +1,79 @@
+use serde::Deserialize;
+use tokio::time::Duration;
+
+use crate::comms::error::CommsError;
+
+/// Exponential backoff for transient comms errors. The defaults ride out a Pico reboot
+/// (a few seconds of refused connections) without a person there to restart the run.
+#[derive(Debug, Clone, Copy, Deserialize)]
+#[serde(default)]
+pub struct RetryPolicy {
+    /// Total attempts, including the first one. 1 means never retry.
+    pub max_attempts: u32,
+    pub initial_backoff_ms: u64,
+    pub max_backoff_ms: u64,
+    pub multiplier: f64,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        RetryPolicy {
+            max_attempts: 8,
+            initial_backoff_ms: 500,
+            max_backoff_ms: 30_000,
+            multiplier: 2.0,
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// Delay before retry number `retry` (1 for the first retry).
+    pub fn backoff(&self, retry: u32) -> Duration {
+        let factor = self
+            .multiplier
+            .max(1.0)
+            .powi(retry.saturating_sub(1) as i32);
+        let ms = (self.initial_backoff_ms as f64 * factor).min(self.max_backoff_ms as f64);
+        Duration::from_millis(ms as u64)
+    }
+
+    /// Whether to try again after attempt number `attempt` (1-based) failed with `e`, and if so
+    /// how long to wait first.
+    pub fn next_delay(&self, attempt: u32, e: &CommsError) -> Option<Duration> {
+        if e.is_transient() && attempt < self.max_attempts {
+            Some(self.backoff(attempt))
+        } else {
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tokio::io;
+
+    #[test]
+    fn backoff_doubles_up_to_the_cap() {
+        let policy = RetryPolicy {
+            max_attempts: 10,
+            initial_backoff_ms: 100,
+            max_backoff_ms: 1000,
+            multiplier: 2.0,
+        };
+        let delays: Vec<u64> = (1..=6)
+            .map(|r| policy.backoff(r).as_millis() as u64)
+            .collect();
+        assert_eq!(delays, vec![100, 200, 400, 800, 1000, 1000]);
+    }
+
+    #[test]
+    fn only_transient_errors_are_retried() {
+        let policy = RetryPolicy::default();
+        let refused = CommsError::from(io::Error::from(io::ErrorKind::ConnectionRefused));
+        assert!(policy.next_delay(1, &refused).is_some());
+        assert!(policy.next_delay(policy.max_attempts, &refused).is_none());
+        let device = CommsError::Device("unsupported day".to_string());
+        assert!(policy.next_delay(1, &device).is_none());
+    }
+}
@@ -0,0 +1,531 @@
+use std::collections::VecDeque;
+use std::fs::{File, OpenOptions};
+use std::io::{BufRead, BufReader, BufWriter, Write};
+use std::path::Path;
+use std::pin::Pin;
+use std::task::{Context, Poll, ready};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use pico_protocol::auth::TRAILER_LEN;
+use serde::{Deserialize, Serialize};
+use tokio::io::{self, AsyncRead, AsyncWrite, ReadBuf};
+
+use crate::comms::chunked::ChunkedUpload;
+use crate::comms::error::{CommsError, CommsResult};
+use crate::comms::frame::{CRC_LEN, Frame, FrameKind, HEADER_LEN, MAX_PAYLOAD, StartInfo};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Direction {
+    /// A new connection to `address`; the frames after it belong to that connection.
+    Connect,
+    Sent,
+    Received,
+}
+
+/// One line of a transcript file (JSON lines, appended to across runs).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Entry {
+    /// Wall clock time in milliseconds since the Unix epoch.
+    pub t_ms: u64,
+    pub dir: Direction,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub address: Option<String>,
+    /// Frame kind and seq, if the frame passed its CRC. Only there for reading the file by eye.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub kind: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub seq: Option<u32>,
+    /// The frame exactly as it went over the wire, in hex, corruption and all.
+    #[serde(default)]
+    pub bytes: String,
+}
+
+impl Entry {
+    fn now(dir: Direction) -> Self {
+        let t_ms = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |t| t.as_millis() as u64);
+        Entry {
+            t_ms,
+            dir,
+            address: None,
+            kind: None,
+            seq: None,
+            bytes: String::new(),
+        }
+    }
+
+    fn frame(dir: Direction, bytes: &[u8]) -> Self {
+        let frame = Frame::decode(bytes);
+        Entry {
+            kind: frame.as_ref().map(|f| format!("{:?}", f.kind)),
+            seq: frame.map(|f| f.seq),
+            bytes: to_hex(bytes),
+            ..Entry::now(dir)
+        }
+    }
+
+    pub fn frame_bytes(&self) -> Option<Vec<u8>> {
+        from_hex(&self.bytes)
+    }
+}
+
+fn to_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+fn from_hex(text: &str) -> Option<Vec<u8>> {
+    if !text.len().is_multiple_of(2) {
+        return None;
+    }
+    (0..text.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(text.get(i..i + 2)?, 16).ok())
+        .collect()
+}
+
+/// Cuts a byte stream back into frames using the length in each header.
+#[derive(Default)]
+struct FrameSplitter {
+    buffer: Vec<u8>,
+}
+
+impl FrameSplitter {
+    fn push(&mut self, bytes: &[u8]) -> Vec<Vec<u8>> {
+        self.buffer.extend_from_slice(bytes);
+        let mut frames = Vec::new();
+        while self.buffer.len() >= HEADER_LEN {
+            let len = u32::from_le_bytes([
+                self.buffer[5],
+                self.buffer[6],
+                self.buffer[7],
+                self.buffer[8],
+            ]) as usize;
+            // A garbled length ends the connection anyway, so keep whatever arrived as one blob.
+            let total = if len > MAX_PAYLOAD {
+                self.buffer.len()
+            } else {
+                HEADER_LEN + len + CRC_LEN
+            };
+            if self.buffer.len() < total {
+                break;
+            }
+            frames.push(self.buffer.drain(..total).collect());
+        }
+        frames
+    }
+}
+
+/// Appends every frame that crosses a recorded stream to a transcript file.
+pub struct TranscriptWriter {
+    file: BufWriter<File>,
+}
+
+impl TranscriptWriter {
+    pub fn open(path: &Path) -> io::Result<Self> {
+        let file = OpenOptions::new().create(true).append(true).open(path)?;
+        Ok(TranscriptWriter {
+            file: BufWriter::new(file),
+        })
+    }
+
+    fn write(&mut self, entry: &Entry) {
+        let line = serde_json::to_string(entry).expect("transcript entries always serialize");
+        // Flushed per frame, so the transcript survives the host being killed mid-upload.
+        let written = writeln!(self.file, "{}", line).and_then(|_| self.file.flush());
+        if let Err(e) = written {
+            eprintln!("Couldn't write transcript: {}", e);
+        }
+    }
+
+    /// Marks a new connection and wraps its stream so the frames on it are recorded.
+    pub fn record<S>(&mut self, address: &str, stream: S) -> Recorded<'_, S> {
+        self.write(&Entry {
+            address: Some(address.to_string()),
+            ..Entry::now(Direction::Connect)
+        });
+        Recorded {
+            inner: stream,
+            transcript: self,
+            sent: FrameSplitter::default(),
+            received: FrameSplitter::default(),
+        }
+    }
+}
+
+pub struct Recorded<'a, S> {
+    inner: S,
+    transcript: &'a mut TranscriptWriter,
+    sent: FrameSplitter,
+    received: FrameSplitter,
+}
+
+impl<S: AsyncRead + Unpin> AsyncRead for Recorded<'_, S> {
+    fn poll_read(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut ReadBuf<'_>,
+    ) -> Poll<io::Result<()>> {
+        let this = self.get_mut();
+        let before = buf.filled().len();
+        ready!(Pin::new(&mut this.inner).poll_read(cx, buf))?;
+        for frame in this.received.push(&buf.filled()[before..]) {
+            this.transcript
+                .write(&Entry::frame(Direction::Received, &frame));
+        }
+        Poll::Ready(Ok(()))
+    }
+}
+
+impl<S: AsyncWrite + Unpin> AsyncWrite for Recorded<'_, S> {
+    fn poll_write(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &[u8],
+    ) -> Poll<io::Result<usize>> {
+        let this = self.get_mut();
+        let n = ready!(Pin::new(&mut this.inner).poll_write(cx, buf))?;
+        for frame in this.sent.push(&buf[..n]) {
+            this.transcript
+                .write(&Entry::frame(Direction::Sent, &frame));
+        }
+        Poll::Ready(Ok(n))
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        Pin::new(&mut self.get_mut().inner).poll_flush(cx)
+    }
+
+    fn poll_shutdown(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        Pin::new(&mut self.get_mut().inner).poll_shutdown(cx)
+    }
+}
+
+pub fn load(path: &Path) -> io::Result<Vec<Entry>> {
+    let mut entries = Vec::new();
+    for (i, line) in BufReader::new(File::open(path)?).lines().enumerate() {
+        let line = line?;
+        if line.trim().is_empty() {
+            continue;
+        }
+        let entry = serde_json::from_str(&line).map_err(|e| {
+            io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("{} line {}: {}", path.display(), i + 1, e),
+            )
+        })?;
+        entries.push(entry);
+    }
+    Ok(entries)
+}
+
+/// One recorded connection.
+#[derive(Debug, Default)]
+struct Session {
+    address: String,
+    sent: Vec<Vec<u8>>,
+    received: Vec<u8>,
+}
+
+impl Session {
+    fn start(&self) -> Option<StartInfo> {
+        self.sent
+            .iter()
+            .filter_map(|bytes| Frame::decode(bytes))
+            .find(|frame| frame.kind == FrameKind::Start)
+            .and_then(|frame| StartInfo::decode(&frame.payload))
+    }
+
+    /// The same connection as if it had never authenticated: the handshake dropped and the tag
+    /// taken off every sealed frame. A replay can't redo the handshake, since the nonces are new
+    /// every time, so it checks the rest of the conversation instead.
+    fn without_auth(self) -> Session {
+        let authenticated = self
+            .sent
+            .first()
+            .and_then(|bytes| Frame::decode(bytes))
+            .is_some_and(|frame| frame.kind == FrameKind::Hello);
+        if !authenticated {
+            return self;
+        }
+        // The host seals everything after its Auth frame, which it may send more than once.
+        let mut sealed = false;
+        let mut sent = Vec::new();
+        for bytes in self.sent {
+            match Frame::decode(&bytes) {
+                Some(frame) if matches!(frame.kind, FrameKind::Hello | FrameKind::Auth) => {
+                    sealed |= frame.kind == FrameKind::Auth;
+                }
+                Some(frame) if sealed => sent.push(untagged(frame)),
+                // Naks for a corrupt Challenge.
+                _ if !sealed => {}
+                _ => sent.push(bytes),
+            }
+        }
+        // The node seals everything after its Challenge, bar the plain Nak for a lost Auth.
+        let mut sealed = false;
+        let mut received = Vec::new();
+        let mut splitter = FrameSplitter::default();
+        for bytes in splitter.push(&self.received) {
+            match Frame::decode(&bytes) {
+                Some(frame) if frame.kind == FrameKind::Challenge => sealed = true,
+                Some(frame) if sealed && !frame.payload.is_empty() => {
+                    received.extend(untagged(frame));
+                }
+                _ if !sealed => {}
+                _ => received.extend(bytes),
+            }
+        }
+        received.extend(splitter.buffer);
+        Session {
+            address: self.address,
+            sent,
+            received,
+        }
+    }
+}
+
+/// Re-encodes a sealed frame without its counter and tag.
+fn untagged(mut frame: Frame) -> Vec<u8> {
+    let len = frame.payload.len().saturating_sub(TRAILER_LEN);
+    frame.payload.truncate(len);
+    Frame::new(frame.kind, frame.seq, frame.payload).encode()
+}
+
+/// Splits the entries into connections, grouped by the transfer they carried (in the order
+/// the transfers first appear), so retries of one upload replay together. Authenticated
+/// connections come back without their handshake and tags.
+fn transfers(entries: &[Entry]) -> Vec<Vec<Session>> {
+    let mut sessions: Vec<Session> = Vec::new();
+    for entry in entries {
+        match entry.dir {
+            Direction::Connect => sessions.push(Session {
+                address: entry.address.clone().unwrap_or_default(),
+                ..Session::default()
+            }),
+            dir => {
+                let (Some(session), Some(bytes)) = (sessions.last_mut(), entry.frame_bytes())
+                else {
+                    continue;
+                };
+                if dir == Direction::Sent {
+                    session.sent.push(bytes);
+                } else {
+                    session.received.extend_from_slice(&bytes);
+                }
+            }
+        }
+    }
+
+    let mut transfers: Vec<(Option<u32>, Vec<Session>)> = Vec::new();
+    for session in sessions.into_iter().map(Session::without_auth) {
+        let id = session.start().map(|start| start.transfer_id);
+        match transfers
+            .iter_mut()
+            .find(|(other, _)| id.is_some() && *other == id)
+        {
+            Some((_, group)) => group.push(session),
+            None => transfers.push((id, vec![session])),
+        }
+    }
+    transfers.into_iter().map(|(_, group)| group).collect()
+}
+
+/// Plays the device side of a recorded session back: reads get the bytes the device sent, in
+/// order, and then end of file. What the host writes is checked against the recording.
+pub struct ReplayStream {
+    incoming: VecDeque<u8>,
+    expected: VecDeque<Vec<u8>>,
+    written: FrameSplitter,
+    frames_written: usize,
+    /// The first frame where the host did something other than what was recorded.
+    pub divergence: Option<String>,
+}
+
+impl ReplayStream {
+    fn new(session: &Session) -> Self {
+        ReplayStream {
+            incoming: session.received.iter().copied().collect(),
+            expected: session.sent.iter().cloned().collect(),
+            written: FrameSplitter::default(),
+            frames_written: 0,
+            divergence: None,
+        }
+    }
+}
+
+impl AsyncRead for ReplayStream {
+    fn poll_read(
+        self: Pin<&mut Self>,
+        _cx: &mut Context<'_>,
+        buf: &mut ReadBuf<'_>,
+    ) -> Poll<io::Result<()>> {
+        let this = self.get_mut();
+        let n = buf.remaining().min(this.incoming.len());
+        let bytes: Vec<u8> = this.incoming.drain(..n).collect();
+        buf.put_slice(&bytes);
+        Poll::Ready(Ok(()))
+    }
+}
+
+impl AsyncWrite for ReplayStream {
+    fn poll_write(
+        self: Pin<&mut Self>,
+        _cx: &mut Context<'_>,
+        buf: &[u8],
+    ) -> Poll<io::Result<usize>> {
+        let this = self.get_mut();
+        for frame in this.written.push(buf) {
+            this.frames_written += 1;
+            let expected = this.expected.pop_front();
+            if this.divergence.is_none() && expected.as_ref() != Some(&frame) {
+                this.divergence = Some(format!(
+                    "frame {}: host sent {}, recording has {}",
+                    this.frames_written,
+                    describe(Some(&frame)),
+                    describe(expected.as_ref())
+                ));
+            }
+        }
+        Poll::Ready(Ok(buf.len()))
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn poll_shutdown(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        Poll::Ready(Ok(()))
+    }
+}
+
+fn describe(bytes: Option<&Vec<u8>>) -> String {
+    match bytes {
+        None => "nothing".to_string(),
+        Some(bytes) => match Frame::decode(bytes) {
+            Some(frame) => format!("{:?} {} ({} bytes)", frame.kind, frame.seq, bytes.len()),
+            None => format!("a corrupt frame ({} bytes)", bytes.len()),
+        },
+    }
+}
+
+/// Rebuilds the upload from what the host sent: the `Start` info and every chunk it recorded.
+fn upload_from(sessions: &[Session]) -> CommsResult<ChunkedUpload> {
+    let start = sessions.iter().find_map(Session::start).ok_or_else(|| {
+        CommsError::ProtocolViolation("Transcript has no Start frame".to_string())
+    })?;
+    let mut chunks = vec![Vec::new(); start.chunk_count as usize];
+    let mut recorded = vec![false; chunks.len()];
+    for frame in sessions
+        .iter()
+        .flat_map(|session| &session.sent)
+        .filter_map(|bytes| Frame::decode(bytes))
+        .filter(|frame| frame.kind == FrameKind::Chunk)
+    {
+        let seq = frame.seq as usize;
+        if seq < chunks.len() && !recorded[seq] {
+            recorded[seq] = true;
+            chunks[seq] = frame.payload;
+        }
+    }
+    Ok(ChunkedUpload::from_parts(start, chunks))
+}
+
+/// Replays every transfer in a transcript through the real upload code, one connection at a
+/// time (without the retry delays), and returns each transfer's final result.
+pub async fn replay(path: &Path) -> io::Result<Vec<CommsResult<String>>> {
+    let entries = load(path)?;
+    let mut results = Vec::new();
+    for sessions in transfers(&entries) {
+        let mut upload = match upload_from(&sessions) {
+            Ok(upload) => upload,
+            Err(e) => {
+                results.push(Err(e));
+                continue;
+            }
+        };
+        let mut result = Err(CommsError::ProtocolViolation(
+            "Transcript has no connections".to_string(),
+        ));
+        for (i, session) in sessions.iter().enumerate() {
+            let mut stream = ReplayStream::new(session);
+            result = upload.run(&mut stream).await;
+            match &result {
+                Ok(answer) => println!("Connection {} to {}: {}", i + 1, session.address, answer),
+                Err(e) => println!("Connection {} to {}: {}", i + 1, session.address, e),
+            }
+            if let Some(divergence) = &stream.divergence {
+                println!("  Host diverged from the recording at {}", divergence);
+            }
+            if result.is_ok() {
+                break;
+            }
+        }
+        results.push(result);
+    }
+    Ok(results)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::comms::chunked::upload_chunked;
+    use crate::comms::config::CommsConfig;
+    use crate::comms::host_node::serve_uploads;
+    use tokio::net::TcpListener;
+
+    #[test]
+    fn splitter_reassembles_frames_from_pieces() {
+        let first = Frame::new(FrameKind::Chunk, 1, b"12,34".to_vec()).encode();
+        let second = Frame::empty(FrameKind::Ack, 1).encode();
+        let stream = [first.clone(), second.clone()].concat();
+        let mut splitter = FrameSplitter::default();
+        let mut frames = Vec::new();
+        for piece in stream.chunks(4) {
+            frames.extend(splitter.push(piece));
+        }
+        assert_eq!(frames, vec![first, second]);
+        assert_eq!(from_hex(&to_hex(&stream)), Some(stream));
+    }
+
+    /// Records one upload to a host node, with `secret` on both ends if given, and replays it.
+    async fn record_and_replay(name: &str, secret: Option<&str>) {
+        let path = std::env::temp_dir().join(format!("{}-{}.jsonl", name, std::process::id()));
+        let _ = std::fs::remove_file(&path);
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let config = CommsConfig {
+            address: Some(listener.local_addr().unwrap().to_string()),
+            transcript: Some(path.display().to_string()),
+            shared_secret: secret.map(String::from),
+            ..CommsConfig::default()
+        };
+        let key = secret.map(|secret| secret.as_bytes().to_vec());
+        tokio::spawn(serve_uploads(listener, key));
+        let lines: Vec<String> = ["1", "10", "100", "2024"].map(String::from).to_vec();
+        let answer = upload_chunked(&config, 2024, 22, 'A', &lines)
+            .await
+            .unwrap();
+
+        let entries = load(&path).unwrap();
+        assert_eq!(entries[0].dir, Direction::Connect);
+        let first = if secret.is_some() { "Hello" } else { "Start" };
+        assert_eq!(entries[1].kind.as_deref(), Some(first));
+        assert_eq!(entries.last().unwrap().kind.as_deref(), Some("Result"));
+
+        let results = replay(&path).await.unwrap();
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].as_ref().unwrap(), &answer);
+    }
+
+    #[tokio::test]
+    async fn recorded_upload_replays_to_the_same_answer() {
+        record_and_replay("transcript", None).await;
+    }
+
+    #[tokio::test]
+    async fn authenticated_upload_replays_without_its_tags() {
+        record_and_replay("transcript-auth", Some("workshop")).await;
+    }
+}
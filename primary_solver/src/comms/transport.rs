@@ -0,0 +1,198 @@
+use serde::Deserialize;
+#[cfg(test)]
+use tokio::io::DuplexStream;
+use tokio::io::{AsyncRead, AsyncWrite};
+use tokio::net::TcpStream;
+#[cfg(test)]
+use tokio::sync::mpsc;
+use tokio_serial::{SerialPortBuilderExt, SerialStream};
+
+#[cfg(test)]
+use crate::comms::error::CommsError;
+use crate::comms::error::CommsResult;
+use crate::comms::pico_sender::connect;
+
+/// A way of reaching a node. Each `open` is a fresh connection; the protocol code only needs a
+/// byte stream, so anything that reads and writes will do.
+pub trait Transport {
+    type Stream: AsyncRead + AsyncWrite + Unpin + Send;
+
+    fn open(&self) -> impl Future<Output = CommsResult<Self::Stream>> + Send;
+
+    /// Where this goes, for logs and transcripts.
+    fn describe(&self) -> String;
+}
+
+/// The Pico's Wi-Fi link (or a host node).
+pub struct TcpTransport {
+    pub address: String,
+}
+
+impl TcpTransport {
+    pub fn new(address: &str) -> Self {
+        TcpTransport {
+            address: address.to_string(),
+        }
+    }
+}
+
+impl Transport for TcpTransport {
+    type Stream = TcpStream;
+
+    async fn open(&self) -> CommsResult<TcpStream> {
+        connect(&self.address).await
+    }
+
+    fn describe(&self) -> String {
+        self.address.clone()
+    }
+}
+
+/// Serial port settings, e.g. `{ "path": "COM5" }` or `{ "path": "/dev/ttyACM0", "baud": 921600 }`.
+#[derive(Debug, Clone, Deserialize)]
+pub struct SerialOptions {
+    pub path: String,
+    #[serde(default = "default_baud")]
+    pub baud: u32,
+}
+
+fn default_baud() -> u32 {
+    115_200
+}
+
+/// The Pico's USB CDC serial port, for when the workshop Wi-Fi is playing up. The baud rate
+/// means nothing to USB CDC but real UARTs care.
+pub struct SerialTransport {
+    pub options: SerialOptions,
+}
+
+impl SerialTransport {
+    pub fn new(options: &SerialOptions) -> Self {
+        SerialTransport {
+            options: options.clone(),
+        }
+    }
+}
+
+impl Transport for SerialTransport {
+    type Stream = SerialStream;
+
+    async fn open(&self) -> CommsResult<SerialStream> {
+        tokio_serial::new(&self.options.path, self.options.baud)
+            .open_native_async()
+            .map_err(|e| std::io::Error::from(e).into())
+    }
+
+    fn describe(&self) -> String {
+        format!("{} @ {} baud", self.options.path, self.options.baud)
+    }
+}
+
+/// An in-memory pipe, for tests. Every `open` makes a new duplex pair and hands the node end to
+/// whoever holds the receiver.
+#[cfg(test)]
+pub struct MemoryTransport {
+    nodes: mpsc::UnboundedSender<DuplexStream>,
+    buffer: usize,
+}
+
+#[cfg(test)]
+impl MemoryTransport {
+    pub fn new(buffer: usize) -> (Self, mpsc::UnboundedReceiver<DuplexStream>) {
+        let (nodes, incoming) = mpsc::unbounded_channel();
+        (MemoryTransport { nodes, buffer }, incoming)
+    }
+}
+
+#[cfg(test)]
+impl Transport for MemoryTransport {
+    type Stream = DuplexStream;
+
+    async fn open(&self) -> CommsResult<DuplexStream> {
+        let (host, node) = tokio::io::duplex(self.buffer);
+        self.nodes.send(node).map_err(|_| {
+            CommsError::Unreachable(std::io::Error::new(
+                std::io::ErrorKind::ConnectionRefused,
+                "Nobody is serving the in-memory transport",
+            ))
+        })?;
+        Ok(host)
+    }
+
+    fn describe(&self) -> String {
+        "in-memory".to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::comms::chunked::upload_via;
+    use crate::comms::config::CommsConfig;
+    use crate::comms::host_node::{NodeState, serve_session};
+    use aoc_solvers::solver::solve_puzzle;
+    use std::sync::{Arc, Mutex};
+
+    fn lines() -> Vec<String> {
+        ["1", "10", "100", "2024"].map(String::from).to_vec()
+    }
+
+    async fn expected() -> String {
+        solve_puzzle(2024, 22, 'A', &lines())
+            .await
+            .unwrap()
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn memory_transport_reaches_a_host_node() {
+        let (transport, mut incoming) = MemoryTransport::new(256);
+        tokio::spawn(async move {
+            let state = Arc::new(Mutex::new(NodeState::default()));
+            while let Some(mut stream) = incoming.recv().await {
+                let _ = serve_session(&mut stream, &state, None).await;
+            }
+        });
+        let config = CommsConfig::default();
+        let answer = upload_via(&transport, &config, 2024, 22, 'A', &lines())
+            .await
+            .unwrap();
+        assert_eq!(answer, expected().await);
+    }
+
+    #[tokio::test]
+    async fn memory_transport_with_no_node_is_unreachable() {
+        let (transport, incoming) = MemoryTransport::new(256);
+        drop(incoming);
+        assert!(matches!(
+            transport.open().await,
+            Err(CommsError::Unreachable(_))
+        ));
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn serial_transport_over_a_pty() {
+        use tokio_serial::SerialPort;
+
+        // The host opens the pty's slave side by path, like a real /dev/ttyACM0; the master
+        // side plays the Pico.
+        let (mut master, slave) = SerialStream::pair().unwrap();
+        let options = SerialOptions {
+            path: slave.name().unwrap(),
+            baud: default_baud(),
+        };
+        let transport = SerialTransport::new(&options);
+        let node = tokio::spawn(async move {
+            let state = Mutex::new(NodeState::default());
+            let _ = serve_session(&mut master, &state, None).await;
+        });
+        let config = CommsConfig::default();
+        let answer = upload_via(&transport, &config, 2024, 22, 'A', &lines())
+            .await
+            .unwrap();
+        drop(slave);
+        node.abort();
+        assert_eq!(answer, expected().await);
+    }
+}
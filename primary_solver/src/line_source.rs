@@ -0,0 +1,92 @@
+use std::io;
+use std::path::PathBuf;
+
+use aoc_solvers::LineSolver;
+use tokio::fs::File;
+use tokio::io::{AsyncBufRead, AsyncBufReadExt, BufReader};
+use tokio::net::TcpStream;
+
+/// Where a streamed solve reads its input from. Each line is handed to the solvers as it
+/// arrives and then dropped, so the input is never held in memory as a whole.
+// Picked by editing `stream_input` in main.rs, so the variants aren't all constructed.
+#[allow(dead_code)]
+#[derive(Debug, Clone)]
+pub enum LineSource {
+    File(PathBuf),
+    Stdin,
+    /// Connects to `host:port` and reads until the other end closes, e.g. `nc -l 7000 < file.txt`.
+    Tcp(String),
+}
+
+impl LineSource {
+    async fn open(&self) -> io::Result<Box<dyn AsyncBufRead + Unpin + Send>> {
+        Ok(match self {
+            LineSource::File(path) => Box::new(BufReader::new(File::open(path).await?)),
+            LineSource::Stdin => Box::new(BufReader::new(tokio::io::stdin())),
+            LineSource::Tcp(address) => {
+                Box::new(BufReader::new(TcpStream::connect(address).await?))
+            }
+        })
+    }
+}
+
+/// Reads `source` once and gives every line to each of `solvers`, so both parts can share one
+/// pass over stdin or a socket. Answers come back in the same order as the solvers.
+pub async fn solve_streamed(
+    source: &LineSource,
+    solvers: &mut [Box<dyn LineSolver>],
+) -> io::Result<Vec<String>> {
+    feed(source.open().await?, solvers).await
+}
+
+async fn feed<R: AsyncBufRead + Unpin>(
+    mut reader: R,
+    solvers: &mut [Box<dyn LineSolver>],
+) -> io::Result<Vec<String>> {
+    // One buffer for the whole input; only the current line is ever in it.
+    let mut buffer = String::new();
+    while reader.read_line(&mut buffer).await? != 0 {
+        let line = buffer.strip_suffix('\n').unwrap_or(&buffer);
+        let line = line.strip_suffix('\r').unwrap_or(line);
+        for solver in solvers.iter_mut() {
+            solver.line(line)?;
+        }
+        buffer.clear();
+    }
+    solvers.iter_mut().map(|solver| solver.finish()).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_solvers::line_solver;
+    use tokio::io::AsyncWriteExt;
+    use tokio::net::TcpListener;
+
+    const DIAL: &str = "L68\r\nL30\r\nR48\r\nL5\r\nR60\r\nL55\r\nL1\r\nL99\r\nR14\r\nL82\r\n";
+
+    fn both_parts(year: u16, day: u8) -> Vec<Box<dyn LineSolver>> {
+        ['A', 'B']
+            .map(|part| line_solver(year, day, part).unwrap())
+            .into()
+    }
+
+    #[tokio::test]
+    async fn one_pass_feeds_both_parts() {
+        let answers = feed(DIAL.as_bytes(), &mut both_parts(2025, 1)).await;
+        assert_eq!(answers.unwrap(), ["3", "6"]);
+    }
+
+    #[tokio::test]
+    async fn reads_from_a_socket_until_it_closes() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let source = LineSource::Tcp(listener.local_addr().unwrap().to_string());
+        let sender = tokio::spawn(async move {
+            let (mut stream, _) = listener.accept().await.unwrap();
+            stream.write_all(b"1\n2\n3\n2024").await.unwrap();
+        });
+        let answers = solve_streamed(&source, &mut both_parts(2024, 22)).await;
+        sender.await.unwrap();
+        assert_eq!(answers.unwrap(), ["37990510", "23"]);
+    }
+}
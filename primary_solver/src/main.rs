@@ -1,16 +1,24 @@
-use std::fs;
 use std::path::Path;
-mod aoc2024;
-mod aoc2025;
 mod comms;
-mod solver;
+mod line_source;
 
 #[allow(unused_imports)]
 use comms::pico_sender::send_data_to_pico;
+use comms::pico_sender::send_data_via;
+use comms::chunked::upload_chunked;
+use comms::config::CommsConfig;
+use comms::coordinator::{run_sharded, shard_nodes};
+use comms::cross_check::cross_check;
+use comms::discovery::resolve_node_address;
+use comms::host_node::run_host_node;
+use comms::transcript::replay;
+use comms::transport::SerialTransport;
+use line_source::{LineSource, solve_streamed};
 
 use std::time::Instant;
 
-use crate::solver::DaySolver;
+use aoc_solvers::solver::DaySolver;
+use aoc_solvers::{ByteLines, aoc2025, get_input_for_puzzle, line_solver, read_input_for_puzzle};
 
 #[allow(unreachable_code)]
 #[tokio::main]
@@ -19,23 +27,94 @@ async fn main() {
     let part = 1;
     let sample = false;
     let transmit_to_pico = false;
-    if let Some(input_lines) =
-        get_input_for_puzzle(day_solver.get_day(), day_solver.get_year(), sample)
+    // Stream the input in acknowledged chunks instead of one big write.
+    let chunked_upload = false;
+    // Solve on the host and on the node, then compare answers and timings.
+    let cross_check_node = false;
+    // Split the input across every available node and combine their answers.
+    let shard_across_nodes = false;
+    // Node address, chunking and retry policy, from primary_solver/comms.json if present.
+    let mut comms_config = CommsConfig::load();
+    // Stand in for a Pico on this machine instead of solving anything.
+    let run_as_node = false;
+    if run_as_node {
+        let key = comms_config.shared_secret.clone().map(String::into_bytes);
+        if let Err(e) = run_host_node(comms_config.node_port, key).await {
+            println!("Host node stopped: {:?}", e);
+        }
+        return;
+    }
+    // Feed a recorded transcript (see "transcript" in comms.json) back through the upload code.
+    let replay_transcript: Option<&str> = None;
+    if let Some(path) = replay_transcript {
+        match replay(Path::new(path)).await {
+            Ok(results) => {
+                for result in results {
+                    println!("Replayed transfer: {:?}", result);
+                }
+            }
+            Err(e) => println!("Couldn't replay {}: {:?}", path, e),
+        }
+        return;
+    }
+    // Feed the input to the day's line solvers as it is read instead of loading it first, e.g.
+    // `Some(LineSource::Stdin)`. Only for the days in `STREAMABLE_DAYS`.
+    let stream_input: Option<LineSource> = None;
+    if let Some(source) = stream_input {
+        let (year, day) = (day_solver.get_year(), day_solver.get_day());
+        let parts: Vec<char> = [(1, 'A'), (2, 'B')]
+            .into_iter()
+            .filter(|&(mask, _)| part & mask != 0)
+            .map(|(_, part_char)| part_char)
+            .collect();
+        let Some(mut solvers) = parts
+            .iter()
+            .map(|&part_char| line_solver(year, day, part_char))
+            .collect::<Option<Vec<_>>>()
+        else {
+            println!("Day {} of {} can't be solved a line at a time", day, year);
+            return;
+        };
+        let start_time = Instant::now();
+        match solve_streamed(&source, &mut solvers).await {
+            Ok(answers) => {
+                for (part_char, answer) in parts.iter().zip(answers) {
+                    println!("Part {}: {}", part_char, answer);
+                }
+            }
+            Err(e) => println!("Couldn't stream input from {:?}: {:?}", source, e),
+        }
+        println!("Time taken: {:.2?}", start_time.elapsed());
+        return;
+    }
+    if transmit_to_pico || cross_check_node {
+        resolve_node_address(&mut comms_config, day_solver.get_year(), day_solver.get_day())
+            .await;
+    }
+    if let Some(input_bytes) =
+        read_input_for_puzzle(day_solver.get_day(), day_solver.get_year(), sample)
     {
+        let input = match ByteLines::new(&input_bytes) {
+            Ok(input) => input,
+            Err(e) => {
+                println!("Couldn't read input for puzzle {}: {}", day_solver.get_day(), e);
+                return;
+            }
+        };
         let day = day_solver.get_day();
         println!();
         println!("-----------------------------------");
         let full_time_start = Instant::now();
         if part & 1 != 0 {
             let start_time = Instant::now();
-            let _result = day_solver.solve_a(&input_lines).await;
+            let _result = day_solver.solve_a_bytes(&input).await;
             let duration = start_time.elapsed();
             println!("Time taken: {:.2?}", duration);
         }
         println!("-----------------------------------");
         if part & 2 != 0 {
             let start_time = Instant::now();
-            let _result = day_solver.solve_b(&input_lines).await;
+            let _result = day_solver.solve_b_bytes(&input).await;
             let duration = start_time.elapsed();
             println!("Time taken: {:.2?}", duration);
         }
@@ -56,26 +135,79 @@ async fn main() {
                     return;
                 }
             };
-        let result = send_data_to_pico(&somelines).await;
+        // The one-shot upload has no handshake, so a shared secret means chunked.
+        let result = if chunked_upload || comms_config.shared_secret.is_some() {
+            let pico_part = if part & 1 != 0 { 'A' } else { 'B' };
+            upload_chunked(
+                &comms_config,
+                day_solver.get_year(),
+                day_solver.get_day(),
+                pico_part,
+                &somelines,
+            )
+            .await
+            .map(|answer| println!("Received: {}", answer))
+        } else if let Some(serial) = &comms_config.serial {
+            send_data_via(&SerialTransport::new(serial), &somelines).await
+        } else {
+            send_data_to_pico(&comms_config, &somelines).await
+        };
         let duration = start_time.elapsed();
         match result {
             Ok(_) => println!("Pico recieved and returned results succesfully in {:.2?}", duration),
             Err(e) => println!("Error sending data to Pico: {:?}", e),
         }
     }
-}
-
-fn get_input_for_puzzle(day: u8, year: u16, sample: bool) -> Option<Vec<String>> {
-    let daystring = if day < 10 {
-        format!("day0{}", day)
-    } else {
-        format!("day{}", day)
-    };
-    let file_name = if sample { "sample.txt" } else { "file.txt" };
-    let path_str = format!("primary_solver/inputs/{}/{}/{}", year, daystring, file_name);
-    let input_path = Path::new(&path_str);
-    // println!("{:?}", input_path);
-    fs::read_to_string(input_path)
-        .ok()
-        .map(|content| content.lines().map(String::from).collect())
+    if cross_check_node {
+        println!("-----------------------------------");
+        println!("Cross-checking against {}", comms_config.node_address());
+        let somelines =
+            match get_input_for_puzzle(day_solver.get_day(), day_solver.get_year(), sample) {
+                Some(lines) => lines,
+                None => {
+                    println!("Input file not found for puzzle {}", day_solver.get_day());
+                    return;
+                }
+            };
+        match cross_check(&day_solver, part, &somelines, &comms_config).await {
+            Ok(checks) => {
+                for check in &checks {
+                    check.print();
+                }
+                if checks.iter().any(|c| !c.matches()) {
+                    println!("Cross-check FAILED");
+                }
+            }
+            Err(e) => println!("Error cross-checking with node: {:?}", e),
+        }
+    }
+    if shard_across_nodes {
+        println!("-----------------------------------");
+        let somelines =
+            match get_input_for_puzzle(day_solver.get_day(), day_solver.get_year(), sample) {
+                Some(lines) => lines,
+                None => {
+                    println!("Input file not found for puzzle {}", day_solver.get_day());
+                    return;
+                }
+            };
+        let (year, day) = (day_solver.get_year(), day_solver.get_day());
+        let nodes = shard_nodes(&comms_config, year, day).await;
+        for (mask, part_char) in [(1, 'A'), (2, 'B')] {
+            if part & mask == 0 {
+                continue;
+            }
+            let start_time = Instant::now();
+            match run_sharded(&comms_config, &nodes, year, day, part_char, &somelines).await {
+                Ok(answer) => println!(
+                    "Part {} across {} nodes: {} in {:.2?}",
+                    part_char,
+                    nodes.len(),
+                    answer,
+                    start_time.elapsed()
+                ),
+                Err(e) => println!("Error sharding part {}: {:?}", part_char, e),
+            }
+        }
+    }
 }